@@ -0,0 +1,50 @@
+//! The day03 scanner against the regex [`baseline`] it replaced, on memory the size of a real
+//! input.
+use aoc::aoc_bench;
+use aoc::util::rng::Rng;
+use aoc::year2024::day03::*;
+
+mod baseline;
+
+/// Corrupted memory the size of a real input, with instructions among random noise.
+fn memory() -> String {
+    let mut rng = Rng::new(2024);
+    let noise = b"mul(,)don't'[]{}<>!@#$%^&*-+ ?what()select123";
+    let mut memory = String::new();
+
+    while memory.len() < 20_000 {
+        match rng.range(0..=9) {
+            0 => memory.push_str(&format!(
+                "mul({},{})",
+                rng.range(0..=999),
+                rng.range(0..=999)
+            )),
+            1 => memory.push_str(if rng.range(0..=1) == 0 {
+                "do()"
+            } else {
+                "don't()"
+            }),
+            _ => memory.push(noise[rng.range(0..=noise.len() as u64 - 1) as usize] as char),
+        }
+    }
+    memory
+}
+
+aoc_bench! {
+    regex_part1 => {
+        let memory = memory();
+        move || baseline::part1(&memory)
+    },
+    scanner_part1 => {
+        let memory = memory();
+        move || part1(&memory)
+    },
+    regex_part2 => {
+        let memory = memory();
+        move || baseline::part2(&memory)
+    },
+    scanner_part2 => {
+        let memory = memory();
+        move || part2(&memory)
+    },
+}
@@ -0,0 +1,51 @@
+//! Harness for the inner function microbenchmarks registered with `aoc_bench!`.
+//!
+//! Each benchmark is warmed up, then executed in batches until the time budget is spent.
+//! An optional argument filters benchmarks by substring of their name. Benchmarks that need
+//! dev-dependencies, such as the regex solution day03 replaced, live next to this harness.
+use aoc::runner::bench::benches;
+use aoc::util::ansi::*;
+use std::env::args;
+use std::time::{Duration, Instant};
+
+mod day03;
+
+const WARMUP: Duration = Duration::from_millis(100);
+const BUDGET: Duration = Duration::from_secs(1);
+
+fn main() {
+    // Cargo passes `--bench` to custom harnesses, so skip flags.
+    let filter = args().skip(1).find(|arg| !arg.starts_with("--"));
+
+    for bench in benches().into_iter().chain(day03::benches()) {
+        if filter
+            .as_ref()
+            .is_some_and(|f| !bench.name.contains(f.as_str()))
+        {
+            continue;
+        }
+
+        let mut routine = (bench.setup)();
+
+        let instant = Instant::now();
+        while instant.elapsed() < WARMUP {
+            routine();
+        }
+
+        let mut iterations: u32 = 0;
+        let instant = Instant::now();
+        while instant.elapsed() < BUDGET {
+            for _ in 0..100 {
+                routine();
+            }
+            iterations += 100;
+        }
+        let per_iteration = instant.elapsed() / iterations;
+
+        println!("{BOLD}{YELLOW}{}{RESET}", bench.name);
+        println!(
+            "    {} ns/iter ({iterations} iterations)",
+            per_iteration.as_nanos()
+        );
+    }
+}
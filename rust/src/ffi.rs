@@ -0,0 +1,90 @@
+//! C-compatible entry points, enabled with the `ffi` feature.
+//!
+//! Allows solutions to be called from other languages without spawning the binary. Strings
+//! returned to the caller are allocated by Rust and must be released with [`aoc_free_string`],
+//! never with the caller's own `free`.
+//!
+//! ```none
+//!   char *part1, *part2;
+//!   if (aoc_solve(2024, 1, input, input_len, &part1, &part2) == AOC_OK) {
+//!       printf("%s %s\n", part1, part2);
+//!       aoc_free_string(part1);
+//!       aoc_free_string(part2);
+//!   }
+//! ```
+use crate::runner::run::{solve, RunError};
+use std::ffi::{c_char, CString};
+use std::ptr;
+use std::slice;
+
+/// Both answers were written to the output pointers.
+pub const AOC_OK: i32 = 0;
+/// A pointer argument was null.
+pub const AOC_NULL_POINTER: i32 = 1;
+/// The input was not valid UTF-8.
+pub const AOC_INVALID_INPUT: i32 = 2;
+/// No solution is registered for the requested year and day.
+pub const AOC_NOT_FOUND: i32 = 3;
+/// The solution failed to produce answers.
+pub const AOC_FAILED: i32 = 4;
+
+/// Solves a single day, writing both answers as NUL-terminated strings.
+///
+/// On success the output pointers receive ownership of newly allocated strings which must be
+/// released with [`aoc_free_string`]. On failure they are set to null.
+///
+/// # Returns
+/// One of the `AOC_*` status codes.
+///
+/// # Safety
+/// * `input_ptr` must point to `len` readable bytes, which are only borrowed for the duration
+///   of the call.
+/// * `out_part1` and `out_part2` must be valid for writes of a single pointer.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    year: u32,
+    day: u32,
+    input_ptr: *const u8,
+    len: usize,
+    out_part1: *mut *mut c_char,
+    out_part2: *mut *mut c_char,
+) -> i32 {
+    if input_ptr.is_null() || out_part1.is_null() || out_part2.is_null() {
+        return AOC_NULL_POINTER;
+    }
+
+    *out_part1 = ptr::null_mut();
+    *out_part2 = ptr::null_mut();
+
+    let bytes = slice::from_raw_parts(input_ptr, len);
+    let Ok(input) = std::str::from_utf8(bytes) else {
+        return AOC_INVALID_INPUT;
+    };
+
+    match solve(year, day, input.to_string()).outcome {
+        Ok((part1, part2)) => match (
+            CString::new(part1.to_string()),
+            CString::new(part2.to_string()),
+        ) {
+            (Ok(part1), Ok(part2)) => {
+                *out_part1 = part1.into_raw();
+                *out_part2 = part2.into_raw();
+                AOC_OK
+            }
+            _ => AOC_FAILED,
+        },
+        Err(RunError::NotFound { .. }) => AOC_NOT_FOUND,
+        Err(_) => AOC_FAILED,
+    }
+}
+
+/// Releases a string previously returned by [`aoc_solve`]. Null pointers are ignored.
+///
+/// # Safety
+/// `value` must be null or a pointer obtained from [`aoc_solve`] that has not been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_free_string(value: *mut c_char) {
+    if !value.is_null() {
+        drop(CString::from_raw(value));
+    }
+}
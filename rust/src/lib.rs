@@ -1,14 +1,57 @@
-pub mod util {
-    pub mod ansi;
-    pub mod conversions;
-    pub mod direction;
-    pub mod grid;
-    pub mod grid_iterator;
-    pub mod integer;
-    pub mod parse;
-    pub mod point;
+/// # Helpers shared by every solution, kept in the separate `aoc-util` crate.
+pub use aoc_util as util;
+
+/// # Solver registry and execution API shared by the binary and other frontends.
+pub mod runner {
+    pub mod baseline;
+    pub mod bench;
+    pub mod cache;
+    pub mod calendar;
+    pub mod cli;
+    /// # Handlers of the `aoc` subcommands, printing their results to the terminal.
+    pub mod command {
+        pub mod cross_check;
+        pub mod list;
+        pub mod output;
+        pub mod replay;
+        pub mod run;
+        pub mod samples;
+        pub mod scaffold;
+        pub mod scrub;
+        pub mod serve;
+        pub mod stats;
+        pub mod today;
+    }
+    pub mod cross_check;
+    pub mod download;
+    pub mod export;
+    pub mod html;
+    pub mod memory;
+    pub mod notify;
+    pub mod progress;
+    pub mod registry;
+    pub mod report;
+    pub mod run;
+    pub mod samples;
+    pub mod scaffold;
+    pub mod scrub;
+    pub mod serve;
+    pub mod settings;
+    pub mod stats;
+    pub mod status;
+    pub mod timing;
+    pub mod verify;
 }
 
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+pub use runner::registry::{Answer, Phases};
+pub use runner::run::{
+    run, solve, solve_part, solve_part_with, solve_with, DayResult, Filter, PartResult, RunError,
+    RunOptions,
+};
+
 /// # Locate the Chief Historian in time for the big Christmas sleigh launch.
 pub mod year2024 {
     pub mod day01;
@@ -1,113 +1,52 @@
+use aoc::runner::cli::{self, Command};
+use aoc::runner::command::*;
+use aoc::runner::settings::Settings;
 use aoc::util::ansi::*;
-use aoc::util::parse::*;
-use aoc::*;
+use aoc::util::config::Config;
+use aoc::util::debugger;
 use std::env::args;
-use std::fs::read_to_string;
-use std::iter::empty;
-use std::path::{Path, PathBuf};
-use std::time::{Duration, Instant};
+
+/// Optional configuration file in the working directory.
+const CONFIG: &str = "aoc.toml";
 
 fn main() {
-    // Parse command line options
-    let (year, day) = match args().nth(1) {
-        Some(arg) => {
-            let str = arg.as_str();
-            let mut iter: ParseUnsigned<'_, u32> = str.iter_unsigned();
-            (iter.next(), iter.next())
+    let config = Config::load(CONFIG).unwrap_or_else(|error| {
+        eprintln!("{BOLD}{RED}Invalid {CONFIG}: {error}{RESET}");
+        std::process::exit(1);
+    });
+    let settings = Settings::from_config(&config).unwrap_or_else(|error| {
+        eprintln!("{BOLD}{RED}Invalid {CONFIG}: {error}{RESET}");
+        std::process::exit(1);
+    });
+
+    let options = match cli::parse_with(args().skip(1), &settings) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{BOLD}{RED}{error}{RESET}");
+            eprintln!("Run {BOLD}{WHITE}aoc --help{RESET} for usage");
+            std::process::exit(2);
         }
-        None => (None, None),
     };
 
-    // Filter solutions
-    let solutions = empty()
-        .chain(year2024())
-        .filter(|solution| year.map_or(true, |y| y == solution.year))
-        .filter(|solution| day.map_or(true, |d| d == solution.day));
-
-    // Pretty print output and timing for each solution
-    let mut solved = 0;
-    let mut duration = Duration::ZERO;
-
-    for Solution {
-        year,
-        day,
-        path,
-        wrapper,
-    } in solutions
-    {
-        if let Ok(data) = read_to_string(&path) {
-            let instant = Instant::now();
-            let (part1, part2) = wrapper(data);
-            let elapsed = instant.elapsed();
-
-            solved += 1;
-            duration += elapsed;
-
-            println!("{BOLD}{YELLOW}{year} Day {day:02}{RESET}");
-            println!("    Part 1: {part1}");
-            println!("    Part 2: {part2}");
-            println!("    Elapsed: {} μs", elapsed.as_micros());
-        } else {
-            eprintln!("{BOLD}{RED}{year} Day {day:02}{RESET}");
-            eprintln!("    Missing input!");
-            eprintln!(
-                "    Place input file in {BOLD}{WHITE}{}{RESET}",
-                path.display()
-            );
-        }
+    if options.help {
+        print!("{}", cli::help());
+        return;
+    }
+    if options.step {
+        debugger::enable();
+    }
+    let run = run::run_options(&options, &settings);
+
+    match options.command {
+        Command::Serve => serve::command(&options, &run),
+        Command::Scrub => scrub::command(&options, &run),
+        Command::CrossCheck => cross_check::command(&options, &config, &run),
+        Command::Scaffold => scaffold::command(&options),
+        Command::Samples => samples::command(&options, &config, &run),
+        Command::Stats => stats::command(&options),
+        Command::Replay => replay::command(&options),
+        Command::List => list::command(&options, &run),
+        Command::Today => today::command(&options, &config, &settings, &run),
+        Command::Run => run::command(&options, &config, &settings, &run),
     }
-
-    // Print totals
-    println!("{BOLD}{RED}Solved: {solved}{RESET}");
-    println!("{BOLD}{GREEN}Duration: {} ms{RESET}", duration.as_millis());
-}
-
-struct Solution {
-    year: u32,
-    day: u32,
-    path: PathBuf,
-    wrapper: fn(String) -> (String, String),
-}
-
-macro_rules! solution {
-    ($year:tt, $day:tt) => {{
-        let year = stringify!($year);
-        let day = stringify!($day);
-        let path = Path::new("input")
-            .join(year)
-            .join(day)
-            .with_extension("txt");
-
-        let wrapper = |data: String| {
-            use $year::$day::*;
-
-            let input = parse(&data);
-            let part1 = part1(&input);
-            let part2 = part2(&input);
-
-            (part1.to_string(), part2.to_string())
-        };
-
-        Solution {
-            year: year.unsigned(),
-            day: day.unsigned(),
-            path,
-            wrapper,
-        }
-    }};
-}
-
-fn year2024() -> Vec<Solution> {
-    vec![
-        solution!(year2024, day01),
-        solution!(year2024, day02),
-        solution!(year2024, day03),
-        solution!(year2024, day04),
-        solution!(year2024, day05),
-        solution!(year2024, day06),
-        solution!(year2024, day07),
-        solution!(year2024, day08),
-        solution!(year2024, day09),
-        solution!(year2024, day10),
-    ]
 }
@@ -0,0 +1,160 @@
+//! Timing regressions against a saved baseline, for `--baseline`.
+//!
+//! A baseline is a copy of the timings recorded by an earlier run, in the format of
+//! [`TIMINGS`](crate::runner::stats::TIMINGS). Save one before a refactoring and compare every
+//! run after it:
+//!
+//! ```none
+//!   cp timings.toml baseline.toml
+//!   cargo run --release -- 2024 --baseline baseline.toml
+//! ```
+//!
+//! Each day with a baseline timing shows how its elapsed time changed. Days more than
+//! [`THRESHOLD`] percent slower are regressions, unless they lost less than [`NOISE`], as a few
+//! microseconds of jitter make fast days swing by large percentages. The threshold can be
+//! changed with `regression` in the `[run]` section of `aoc.toml`.
+use crate::runner::stats::{entries, recorded};
+use crate::util::config::{Config, ConfigError};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// Percentage a day may slow down by before it counts as a regression.
+pub const THRESHOLD: u32 = 10;
+
+/// Slowdowns below this are never regressions, whatever their percentage.
+pub const NOISE: Duration = Duration::from_micros(50);
+
+/// Elapsed time of each day in a saved run.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Baseline {
+    timings: HashMap<(u32, u32), Duration>,
+}
+
+impl Baseline {
+    /// Reads the timings recorded in a store.
+    pub fn from_config(store: &Config) -> Baseline {
+        let timings = recorded(store)
+            .into_iter()
+            .map(|(year, day, elapsed)| ((year, day), elapsed))
+            .collect();
+        Baseline { timings }
+    }
+
+    /// Reads a saved timings file. Unlike the timing store itself, the file must exist and every
+    /// timing in it must be a number, as comparing against a partly read baseline would hide
+    /// the days that were skipped.
+    pub fn load(path: impl AsRef<Path>) -> Result<Baseline, BaselineError> {
+        let path = path.as_ref();
+        let file = path.display().to_string();
+        let text = fs::read_to_string(path).map_err(|error| BaselineError::Io {
+            path: file.clone(),
+            error: error.to_string(),
+        })?;
+        let store = Config::parse(&text).map_err(|error| BaselineError::Config {
+            path: file.clone(),
+            error,
+        })?;
+
+        let mut timings = HashMap::new();
+        for (section, key, timing) in entries(&store) {
+            let Some((year, day, elapsed)) = timing else {
+                return Err(BaselineError::Invalid {
+                    path: file,
+                    key: format!("{section}.{key}"),
+                });
+            };
+            timings.insert((year, day), elapsed);
+        }
+        Ok(Baseline { timings })
+    }
+
+    /// The elapsed time of a day in the baseline, if it was solved there.
+    pub fn get(&self, year: u32, day: u32) -> Option<Duration> {
+        self.timings.get(&(year, day)).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.timings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.timings.is_empty()
+    }
+}
+
+/// Reasons a baseline could not be loaded, each naming the file.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum BaselineError {
+    /// The file could not be read.
+    Io { path: String, error: String },
+    /// The file is not a valid timings file.
+    Config { path: String, error: ConfigError },
+    /// A timing in the file is not a number of microseconds, `key` being its section and key.
+    Invalid { path: String, key: String },
+}
+
+impl fmt::Display for BaselineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BaselineError::Io { path, error } => {
+                write!(f, "Unable to read baseline {path}: {error}")
+            }
+            BaselineError::Config { path, error } => write!(f, "Invalid baseline {path}: {error}"),
+            BaselineError::Invalid { path, key } => {
+                write!(f, "Invalid baseline {path}: {key} is not a timing")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BaselineError {}
+
+/// How a day's elapsed time compares with its baseline.
+///
+/// # Fields
+/// - `before`: The elapsed time in the baseline.
+/// - `after`: The elapsed time of this run.
+/// - `regressed`: Whether the day slowed down by more than the threshold and [`NOISE`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Change {
+    pub before: Duration,
+    pub after: Duration,
+    pub regressed: bool,
+}
+
+impl Change {
+    /// Compares an elapsed time with the baseline one.
+    ///
+    /// # Arguments
+    /// * `before` - The elapsed time in the baseline.
+    /// * `after` - The elapsed time of this run.
+    /// * `threshold` - The percentage above which a slowdown is a regression.
+    pub fn new(before: Duration, after: Duration, threshold: u32) -> Self {
+        let mut change = Change {
+            before,
+            after,
+            regressed: false,
+        };
+        change.regressed =
+            after.saturating_sub(before) >= NOISE && change.percent() > threshold as f64;
+        change
+    }
+
+    /// The change relative to the baseline, positive when slower. A day taking no time in the
+    /// baseline changes by an infinite percentage once it takes any.
+    pub fn percent(&self) -> f64 {
+        let (before, after) = (self.before.as_secs_f64(), self.after.as_secs_f64());
+        if before == 0.0 {
+            return if after == 0.0 { 0.0 } else { f64::INFINITY };
+        }
+        (after - before) / before * 100.0
+    }
+}
+
+/// Formats a percentage with its sign, such as `+23.4%` or `-5.0%`.
+pub fn format_percent(percent: f64) -> String {
+    format!("{percent:+.1}%")
+}
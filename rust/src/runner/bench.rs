@@ -0,0 +1,221 @@
+//! Registrations for microbenchmarks of inner helper functions.
+//!
+//! End-to-end timings hide which helper dominates a day. Modules opt in with the [`aoc_bench!`]
+//! macro, which generates a `benches()` function returning one [`Bench`] per entry. Each entry
+//! is a setup expression evaluating to a closure; the setup runs once and only the closure is
+//! timed. Because the closures are defined inside the day module they can call private helpers.
+//!
+//! ```ignore
+//! aoc_bench! {
+//!     is_valid => {
+//!         let values = vec![81, 40, 27];
+//!         move || is_valid(&values, 2, 3267, false)
+//!     },
+//! }
+//! ```
+//!
+//! Run them with `cargo bench --bench inner [filter]`.
+//!
+//! [`aoc_bench!`]: crate::aoc_bench
+
+/// A named microbenchmark. `setup` prepares the input and returns the routine to time.
+pub struct Bench {
+    pub name: &'static str,
+    pub setup: fn() -> Box<dyn FnMut()>,
+}
+
+#[macro_export]
+macro_rules! aoc_bench {
+    ($($name:ident => $setup:expr),* $(,)?) => {
+        /// Microbenchmarks of this module's inner functions, collected by the bench harness.
+        pub fn benches() -> Vec<$crate::runner::bench::Bench> {
+            vec![$($crate::runner::bench::Bench {
+                name: concat!(module_path!(), "::", stringify!($name)),
+                setup: || {
+                    #[allow(unused_mut)]
+                    let mut routine = $setup;
+                    Box::new(move || {
+                        std::hint::black_box(routine());
+                    })
+                },
+            }),*]
+        }
+    };
+}
+
+/// Returns every registered microbenchmark.
+pub fn benches() -> Vec<Bench> {
+    use crate::year2024::*;
+
+    [
+        day04::benches(),
+        day07::benches(),
+        layout::benches(),
+        lookup::benches(),
+        probing::benches(),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Row-major [`Grid`](crate::util::grid::Grid) against
+/// [`MortonGrid`](crate::util::morton::MortonGrid) on a grid larger than most L1 and L2
+/// caches, for a flood fill that spreads in every direction and a scan that only reads along
+/// rows.
+mod layout {
+    use crate::util::direction::ORTHOGONAL;
+    use crate::util::grid::Grid;
+    use crate::util::morton::MortonGrid;
+    use crate::util::point::Point;
+    use crate::util::rng::Rng;
+
+    const SIDE: i32 = 512;
+
+    /// A maze of roughly one wall in four cells.
+    fn maze() -> Grid<char> {
+        let mut rng = Rng::new(2024);
+        let data = (0..SIDE)
+            .map(|_| {
+                (0..SIDE)
+                    .map(|_| if rng.range(0..=3) == 0 { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        Grid::new(data, SIDE)
+    }
+
+    /// Counts the cells reachable from the center, using `get` and `set` to read and mark cells.
+    fn flood<G>(
+        grid: &mut G,
+        get: fn(&G, &Point) -> Option<char>,
+        set: fn(&mut G, &Point, char),
+    ) -> usize {
+        let mut stack = vec![Point::new(SIDE / 2, SIDE / 2)];
+        let mut count = 0;
+
+        while let Some(point) = stack.pop() {
+            if get(grid, &point) != Some('.') {
+                continue;
+            }
+            set(grid, &point, 'o');
+            count += 1;
+            stack.extend(ORTHOGONAL.map(|direction| point.add(&Point::from(direction))));
+        }
+
+        count
+    }
+
+    fn walls<G>(grid: &G, get: fn(&G, &Point) -> Option<char>) -> usize {
+        (0..SIDE)
+            .flat_map(|y| (0..SIDE).map(move |x| Point::new(x, y)))
+            .filter(|point| get(grid, point) == Some('#'))
+            .count()
+    }
+
+    aoc_bench! {
+        flood_row_major => {
+            let grid = maze();
+            move || flood(&mut grid.clone(), Grid::get_value, Grid::set_value)
+        },
+        flood_morton => {
+            let grid = MortonGrid::from(&maze());
+            move || flood(&mut grid.clone(), MortonGrid::get_value, MortonGrid::set_value)
+        },
+        scan_row_major => {
+            let grid = maze();
+            move || walls(&grid, Grid::get_value)
+        },
+        scan_morton => {
+            let grid = MortonGrid::from(&maze());
+            move || walls(&grid, MortonGrid::get_value)
+        },
+    }
+}
+
+/// [`FastMap`](crate::util::hash::FastMap) against
+/// [`PerfectIndex`](crate::util::perfect::PerfectIndex) translating three letter wire names to
+/// dense indices, as graph days do for every edge while parsing.
+mod lookup {
+    use crate::util::hash::*;
+    use crate::util::perfect::PerfectIndex;
+    use crate::util::rng::Rng;
+
+    const NAMES: usize = 300;
+    const LOOKUPS: usize = 10_000;
+
+    /// Distinct names, then a stream of lookups drawn from them.
+    fn wires() -> (Vec<String>, Vec<String>) {
+        let mut rng = Rng::new(2024);
+        let mut names = FastSet::new();
+        while names.len() < NAMES {
+            let name: String = (0..3)
+                .map(|_| (b'a' + rng.range(0..=25) as u8) as char)
+                .collect();
+            names.insert(name);
+        }
+        let names: Vec<String> = names.into_iter().collect();
+        let lookups = (0..LOOKUPS)
+            .map(|_| names[rng.range(0..=NAMES as u64 - 1) as usize].clone())
+            .collect();
+        (names, lookups)
+    }
+
+    aoc_bench! {
+        fast_map => {
+            let (names, lookups) = wires();
+            let map: FastMap<String, usize> =
+                names.into_iter().enumerate().map(|(i, name)| (name, i)).collect();
+            move || lookups.iter().map(|name| map[name]).sum::<usize>()
+        },
+        perfect_index => {
+            let (names, lookups) = wires();
+            let index = PerfectIndex::new(&names);
+            move || lookups.iter().map(|name| index.index(name).unwrap()).sum::<usize>()
+        },
+    }
+}
+
+/// [`FastMap`](crate::util::hash::FastMap) against [`U64Map`] at a low and a high load factor,
+/// counting distinct states in a stream where about a third of the states were seen before,
+/// like the visited set of a search. States pack two coordinates into the high and low half of
+/// a `u64`, and every map is sized up front so only probing is timed.
+mod probing {
+    use crate::util::hash::*;
+    use crate::util::rng::Rng;
+
+    const STATES: usize = 100_000;
+
+    fn states() -> Vec<u64> {
+        let mut rng = Rng::new(2024);
+        (0..STATES)
+            .map(|_| (rng.range(0..=300) << 32) | rng.range(0..=300))
+            .collect()
+    }
+
+    fn distinct(states: &[u64], load_factor: f64) -> usize {
+        let mut seen = U64Map::with_load_factor(STATES, load_factor);
+        states
+            .iter()
+            .filter(|&&state| seen.insert(state, ()).is_none())
+            .count()
+    }
+
+    aoc_bench! {
+        fast_map => {
+            let states = states();
+            move || {
+                let mut seen = FastMap::with_capacity(STATES);
+                states.iter().filter(|&&state| seen.insert(state, ()).is_none()).count()
+            }
+        },
+        u64_map_half => {
+            let states = states();
+            move || distinct(&states, 0.5)
+        },
+        u64_map_seven_eighths => {
+            let states = states();
+            move || distinct(&states, 0.875)
+        },
+    }
+}
@@ -0,0 +1,148 @@
+//! Answers of earlier runs, so that full runs only solve the days that changed.
+//!
+//! After solving a day the runner stores its answers in `cache.toml`, together with a
+//! fingerprint of the runner itself and a hash of the input:
+//!
+//! ```toml
+//! [cache.2024.06]
+//! build = 1c2ffa0d6a18e1b4
+//! input = 9e0f5d2b7a41c803
+//! part1 = 41
+//! part2 = 6
+//! elapsed_us = 1830
+//! parse_us = 40
+//! solve_us = 1790
+//! ```
+//!
+//! The next run reports those answers without solving the day again, as long as both still
+//! match. Which sources a day depends on is not tracked. Instead the build fingerprint covers
+//! the size and modification time of the executable, so every rebuild invalidates the whole
+//! cache. Days timing each part on its own also store `part1_us` and `part2_us`.
+use crate::runner::registry::{Answer, Phases};
+use crate::util::config::Config;
+use crate::util::hash::fnv1a;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, UNIX_EPOCH};
+
+/// Default location of the cache.
+pub const CACHE: &str = "cache.toml";
+
+/// Section prefix for the answers of a day.
+const SECTION: &str = "cache.";
+
+/// The answers and timings stored for a day.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Entry {
+    pub answers: (Answer, Answer),
+    pub elapsed: Duration,
+    pub phases: Phases,
+}
+
+/// Stored answers that are valid for one build of the runner.
+///
+/// # Fields
+/// - `build`: Fingerprint of the build, entries of other builds are ignored.
+/// - `store`: The contents of the cache file.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Cache {
+    build: String,
+    store: Config,
+}
+
+impl Cache {
+    pub fn new(build: impl Into<String>, store: Config) -> Self {
+        Cache {
+            build: build.into(),
+            store,
+        }
+    }
+
+    /// Loads the cache file for the running executable. A missing or invalid file gives an
+    /// empty cache.
+    ///
+    /// # Returns
+    /// * The cache, or `None` if the executable cannot be fingerprinted.
+    pub fn open(path: impl AsRef<Path>) -> Option<Self> {
+        let build = build()?;
+        Some(Cache::new(build, Config::load(path).unwrap_or_default()))
+    }
+
+    /// Finds the answers of a day, if they were stored by this build for the same input. An
+    /// entry with timings that are not numbers is a miss like any other invalid cache.
+    ///
+    /// # Arguments
+    /// * `input` - The [`fnv1a`] hash of the input.
+    pub fn get(&self, year: u32, day: u32, input: u64) -> Option<Entry> {
+        let section = section(year, day);
+        let field = |key: &str| self.store.get(&section, key);
+        // Outer `None` for a missing key, inner `None` for a value that is not a number
+        let micros = |key: &str| field(key).map(|us| us.parse().ok().map(Duration::from_micros));
+
+        if field("build")? != self.build || field("input")? != format!("{input:016x}") {
+            return None;
+        }
+        let parts = match (micros("part1_us"), micros("part2_us")) {
+            (Some(part1), Some(part2)) => Some([part1?, part2?]),
+            _ => None,
+        };
+        Some(Entry {
+            answers: (Answer::from(field("part1")?), Answer::from(field("part2")?)),
+            elapsed: micros("elapsed_us")??,
+            phases: Phases {
+                parse: micros("parse_us")??,
+                parts,
+                solve: micros("solve_us")??,
+            },
+        })
+    }
+
+    /// Stores the answers of a day for this build, replacing any earlier ones.
+    pub fn insert(&mut self, year: u32, day: u32, input: u64, entry: &Entry) {
+        let section = section(year, day);
+        let micros = |duration: Duration| duration.as_micros().to_string();
+        for key in ["part1_us", "part2_us"] {
+            self.store.remove(&section, key);
+        }
+
+        self.store.insert(&section, "build", &self.build);
+        self.store
+            .insert(&section, "input", &format!("{input:016x}"));
+        self.store
+            .insert(&section, "part1", &entry.answers.0.to_string());
+        self.store
+            .insert(&section, "part2", &entry.answers.1.to_string());
+        self.store
+            .insert(&section, "elapsed_us", &micros(entry.elapsed));
+        self.store
+            .insert(&section, "parse_us", &micros(entry.phases.parse));
+        self.store
+            .insert(&section, "solve_us", &micros(entry.phases.solve));
+        if let Some([part1, part2]) = entry.phases.parts {
+            self.store.insert(&section, "part1_us", &micros(part1));
+            self.store.insert(&section, "part2_us", &micros(part2));
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.store.save(path)
+    }
+}
+
+/// Fingerprints the running executable by its path, size and modification time.
+pub fn build() -> Option<String> {
+    let path = std::env::current_exe().ok()?;
+    let metadata = path.metadata().ok()?;
+    let modified = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+    let identity = format!(
+        "{}:{}:{}",
+        path.display(),
+        metadata.len(),
+        modified.as_nanos()
+    );
+    Some(format!("{:016x}", fnv1a(identity.as_bytes())))
+}
+
+fn section(year: u32, day: u32) -> String {
+    format!("{SECTION}{year}.{day:02}")
+}
@@ -0,0 +1,102 @@
+//! Which puzzle is out right now, for the `today` command.
+//!
+//! Puzzles unlock at midnight US Eastern Standard Time (UTC-5), one per day from the first of
+//! December. Events up to 2024 have 25 days; from 2025 on they have 12. Outside the event the
+//! next puzzle is day 1 of the coming December.
+//!
+//! Dates are computed from the Unix time with the days from civil algorithm, see
+//! <https://howardhinnant.github.io/date_algorithms.html>, so no time zone database is needed.
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Offset of Eastern Standard Time from UTC, in seconds.
+pub const UNLOCK_OFFSET: i64 = -5 * 3600;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// The puzzle of the moment.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Today {
+    /// The puzzle of the current Eastern date is out.
+    Unlocked { year: u32, day: u32 },
+    /// No puzzle unlocked today. The next one is `year` and `day`, `remaining` from now.
+    Locked {
+        year: u32,
+        day: u32,
+        remaining: Duration,
+    },
+}
+
+/// Number of puzzles in the event of a year.
+pub fn days_in(year: u32) -> u32 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+/// Resolves the puzzle that is out at `now`, or the next one to unlock.
+pub fn today(now: SystemTime) -> Today {
+    let unix = match now.duration_since(UNIX_EPOCH) {
+        Ok(elapsed) => elapsed.as_secs() as i64,
+        Err(error) => -(error.duration().as_secs() as i64),
+    };
+    let eastern = unix + UNLOCK_OFFSET;
+    let (year, month, day) = civil_from_days(eastern.div_euclid(SECONDS_PER_DAY));
+
+    if month == 12 && day <= days_in(year) {
+        return Today::Unlocked { year, day };
+    }
+
+    let next = if month == 12 { year + 1 } else { year };
+    let unlock = days_from_civil(next, 12, 1) * SECONDS_PER_DAY;
+    Today::Locked {
+        year: next,
+        day: 1,
+        remaining: Duration::from_secs((unlock - eastern) as u64),
+    }
+}
+
+/// Formats the time left until an unlock, such as `45d 03:12:09` or `00:04:59`.
+pub fn format_remaining(remaining: Duration) -> String {
+    let seconds = remaining.as_secs();
+    let (days, hours) = (seconds / 86_400, seconds / 3600 % 24);
+    let clock = format!("{hours:02}:{:02}:{:02}", seconds / 60 % 60, seconds % 60);
+
+    if days > 0 {
+        format!("{days}d {clock}")
+    } else {
+        clock
+    }
+}
+
+/// Converts days since 1970-01-01 into a year, month and day.
+pub fn civil_from_days(days: i64) -> (u32, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year as u32, month, day)
+}
+
+/// Converts a year, month and day into days since 1970-01-01.
+pub fn days_from_civil(year: u32, month: u32, day: u32) -> i64 {
+    let year = year as i64 - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let shifted_month = (month as i64 + 9) % 12;
+    let day_of_year = (153 * shifted_month + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
@@ -0,0 +1,604 @@
+//! Command line parsing for the runner binary.
+//!
+//! Commands and flags are declared in the [`COMMANDS`] and [`FLAGS`] tables, which drive both
+//! parsing and the generated [`help`] text, so adding an option is a single table entry plus
+//! a field in [`Options`].
+//!
+//! Flags may appear anywhere and take their value either as the next argument or after `=`.
+//! Everything else is positional: an optional command name first, then the arguments of the
+//! command. Without a command, the numbers in the positional arguments select the year, day and
+//! part to run, so `2024.06`, `2024 6` and `2024/6/2` all work.
+//!
+//! Defaults from `aoc.toml` are applied with [`parse_with`], see
+//! [`settings`](crate::runner::settings). With a default
+//! year, a single number below [`FIRST_YEAR`] selects a day of that year, so `aoc 6` works too.
+use crate::runner::registry::EXAMPLE_SET;
+use crate::runner::run::Filter;
+use crate::runner::settings::Settings;
+use crate::util::parse::*;
+use std::fmt;
+use std::time::Duration;
+
+/// What the binary should do.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Command {
+    /// Solve the selected days and print their answers.
+    Run,
+    /// Answer solve requests over HTTP.
+    Serve,
+    /// Print a synthetic version of a real input.
+    Scrub,
+    /// Compare answers with external implementations.
+    CrossCheck,
+    /// Create and register a new day.
+    Scaffold,
+    /// Extract the examples of a puzzle description.
+    Samples,
+    /// Summarise recorded timings.
+    Stats,
+    /// Show a recorded run without solving.
+    Replay,
+    /// Show which days have inputs, answers and tests.
+    List,
+    /// Run or scaffold the puzzle unlocked today.
+    Today,
+}
+
+/// How the results of a run are printed.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum Output {
+    /// Answers and timings for people.
+    #[default]
+    Text,
+    /// One row of phase timings per day, see [`export`](crate::runner::export).
+    Csv,
+}
+
+/// Extra file written after a run.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Report {
+    /// Standalone page with a timing chart, see [`html`](crate::runner::html).
+    Html,
+}
+
+/// A subcommand with its usage line for the help text.
+pub struct Subcommand {
+    pub command: Command,
+    pub name: &'static str,
+    pub usage: &'static str,
+    pub help: &'static str,
+}
+
+/// A recognised flag. Flags with a `value` placeholder consume an argument.
+pub struct Flag {
+    pub name: &'static str,
+    pub value: Option<&'static str>,
+    pub help: &'static str,
+}
+
+/// The first Advent of Code, smaller numbers are never years.
+pub const FIRST_YEAR: u32 = 2015;
+
+pub const COMMANDS: &[Subcommand] = &[
+    Subcommand {
+        command: Command::Serve,
+        name: "serve",
+        usage: "[address]",
+        help: "Answer solve requests over HTTP, on 127.0.0.1:8080 by default",
+    },
+    Subcommand {
+        command: Command::Scrub,
+        name: "scrub",
+        usage: "<year> <day> [seed]",
+        help: "Print a synthetic version of a real input",
+    },
+    Subcommand {
+        command: Command::CrossCheck,
+        name: "cross-check",
+        usage: "[year[.day]]",
+        help: "Compare answers with the commands configured in aoc.toml",
+    },
+    Subcommand {
+        command: Command::Scaffold,
+        name: "scaffold",
+        usage: "<year> <day>",
+        help: "Create stubs for a new day and register them",
+    },
+    Subcommand {
+        command: Command::Samples,
+        name: "samples",
+        usage: "<year> <day>",
+        help: "Save the examples of a puzzle description as input sets sample1, sample2, ...",
+    },
+    Subcommand {
+        command: Command::Stats,
+        name: "stats",
+        usage: "[year]",
+        help: "Show the timings recorded by previous runs",
+    },
+    Subcommand {
+        command: Command::Replay,
+        name: "replay",
+        usage: "<report.json>",
+        help: "Show a recorded run without solving, stepping through frames with --step",
+    },
+    Subcommand {
+        command: Command::List,
+        name: "list",
+        usage: "[year[.day]]",
+        help: "Show which registered days have inputs, known answers and tests",
+    },
+    Subcommand {
+        command: Command::Today,
+        name: "today",
+        usage: "",
+        help: "Run or scaffold today's puzzle, or show how long until the next one unlocks",
+    },
+];
+
+pub const FLAGS: &[Flag] = &[
+    Flag {
+        name: "--part",
+        value: Some("1|2"),
+        help: "Parse and solve only one part",
+    },
+    Flag {
+        name: "--input",
+        value: Some("path"),
+        help: "Solve the selected day with this input file instead of the default",
+    },
+    Flag {
+        name: "--input-set",
+        value: Some("name"),
+        help: "Solve with dayDD/name.txt or dayDD-name.txt, checking answers of the same name",
+    },
+    Flag {
+        name: "--example",
+        value: None,
+        help: "Short for --input-set example",
+    },
+    Flag {
+        name: "--output",
+        value: Some("text|csv"),
+        help: "Print answers as text or phase timings as CSV rows",
+    },
+    Flag {
+        name: "--report",
+        value: Some("html"),
+        help: "Also write the timings of the run to report.html",
+    },
+    Flag {
+        name: "--redact",
+        value: None,
+        help: "Show answers as short hashes, in the output and report.html",
+    },
+    Flag {
+        name: "--runs",
+        value: Some("n"),
+        help: "Solve each day n times, reporting the fastest run",
+    },
+    Flag {
+        name: "--timeout",
+        value: Some("ms"),
+        help: "Give up on a day after this long, leaving it running in the background",
+    },
+    Flag {
+        name: "--slower-than",
+        value: Some("ms"),
+        help: "Only print days taking longer than this, plus any with wrong answers or errors",
+    },
+    Flag {
+        name: "--baseline",
+        value: Some("timings.toml"),
+        help: "Compare timings with a saved copy of timings.toml, flagging slower days",
+    },
+    Flag {
+        name: "--bench",
+        value: None,
+        help: "Time each day over many runs instead of printing answers",
+    },
+    Flag {
+        name: "--no-cache",
+        value: None,
+        help: "Solve every day again instead of reusing the answers of unchanged days",
+    },
+    Flag {
+        name: "--parallel",
+        value: None,
+        help: "Run days on all cores, printing results in order",
+    },
+    Flag {
+        name: "--strict",
+        value: None,
+        help: "Exit with a distinct code for missing inputs, panics and wrong answers",
+    },
+    Flag {
+        name: "--per-year",
+        value: None,
+        help: "Summarise stats per year against the one second goal",
+    },
+    Flag {
+        name: "--notify",
+        value: None,
+        help: "Ring the bell and run [notify] command from aoc.toml when done",
+    },
+    Flag {
+        name: "--record",
+        value: Some("report.json"),
+        help: "Save answers and visualisation frames for replay",
+    },
+    Flag {
+        name: "--max-frames",
+        value: Some("n"),
+        help: "Frames kept per day by --record, sampled evenly over the run",
+    },
+    Flag {
+        name: "--step",
+        value: None,
+        help: "Pause at every visualisation frame",
+    },
+    Flag {
+        name: "--help",
+        value: None,
+        help: "Print this help",
+    },
+];
+
+/// Parsed command line.
+///
+/// # Fields
+/// - `command`: The selected command, [`Command::Run`] unless named first.
+/// - `arguments`: Positional arguments following the command name.
+/// - `filter`: Year and day selected by the positional numbers of `run` and `cross-check`.
+/// - `part`: Part selected by `--part` or a third positional number.
+/// - `input`: Input file given to `--input`, replacing the default location of the day.
+/// - `input_set`: Set given to `--input-set`, or `example` with `--example`.
+/// - `output`: Format selected by `--output`.
+/// - `report`: Extra file selected by `--report`.
+/// - `runs`: Number of runs given to `--runs`, one by default.
+/// - `timeout`: Limit given to `--timeout`, above zero.
+/// - `slower_than`: Threshold given to `--slower-than`, in fractional milliseconds.
+/// - `baseline`: Timings file given to `--baseline`.
+/// - `record`: Report file given to `--record`.
+/// - `max_frames`: Frame budget given to `--max-frames`, at least two.
+/// - Remaining fields: Whether each boolean flag was given.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Options {
+    pub command: Command,
+    pub arguments: Vec<String>,
+    pub filter: Filter,
+    pub part: Option<u32>,
+    pub input: Option<String>,
+    pub input_set: Option<String>,
+    pub output: Output,
+    pub report: Option<Report>,
+    pub redact: bool,
+    pub runs: u32,
+    pub timeout: Option<Duration>,
+    pub slower_than: Option<Duration>,
+    pub baseline: Option<String>,
+    pub bench: bool,
+    pub no_cache: bool,
+    pub parallel: bool,
+    pub strict: bool,
+    pub per_year: bool,
+    pub notify: bool,
+    pub record: Option<String>,
+    pub max_frames: Option<usize>,
+    pub step: bool,
+    pub help: bool,
+}
+
+/// Reasons the command line could not be understood.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum CliError {
+    /// A flag that is not in [`FLAGS`].
+    UnknownFlag(String),
+    /// A flag that needs a value was last on the command line.
+    MissingValue(&'static str),
+    /// A flag got a value it does not accept.
+    InvalidValue { flag: &'static str, value: String },
+    /// A positional argument that is neither a command nor a number.
+    UnexpectedArgument(String),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::UnknownFlag(flag) => write!(f, "Unknown flag {flag}"),
+            CliError::MissingValue(flag) => write!(f, "Missing value for {flag}"),
+            CliError::InvalidValue { flag, value } => {
+                write!(f, "Invalid value {value:?} for {flag}")
+            }
+            CliError::UnexpectedArgument(argument) => write!(f, "Unexpected argument {argument:?}"),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+/// Parses the arguments following the program name.
+pub fn parse<I>(args: I) -> Result<Options, CliError>
+where
+    I: IntoIterator<Item = String>,
+{
+    parse_with(args, &Settings::default())
+}
+
+/// Parses the arguments following the program name, falling back to the defaults of `aoc.toml`
+/// for anything they leave out.
+pub fn parse_with<I>(args: I, defaults: &Settings) -> Result<Options, CliError>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut flags: Vec<(&'static str, Option<String>)> = Vec::new();
+    let mut positional = Vec::new();
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            positional.extend(args.by_ref());
+            break;
+        }
+        if !arg.starts_with('-') || arg == "-" {
+            positional.push(arg);
+            continue;
+        }
+
+        let (name, inline) = match arg.split_once('=') {
+            Some((name, value)) => (name, Some(value.to_string())),
+            None => (arg.as_str(), None),
+        };
+        let name = if name == "-h" { "--help" } else { name };
+        let flag = FLAGS
+            .iter()
+            .find(|flag| flag.name == name)
+            .ok_or_else(|| CliError::UnknownFlag(name.to_string()))?;
+
+        let value = match (flag.value, inline) {
+            (Some(_), Some(value)) => Some(value),
+            (Some(_), None) => Some(args.next().ok_or(CliError::MissingValue(flag.name))?),
+            (None, Some(value)) => {
+                return Err(CliError::InvalidValue {
+                    flag: flag.name,
+                    value,
+                })
+            }
+            (None, None) => None,
+        };
+        flags.push((flag.name, value));
+    }
+
+    let has = |name: &str| flags.iter().any(|(flag, _)| *flag == name);
+    let value = |name: &str| {
+        flags
+            .iter()
+            .rev()
+            .find(|(flag, _)| *flag == name)
+            .and_then(|(_, value)| value.clone())
+    };
+
+    let command = match positional.first() {
+        Some(first) => match COMMANDS.iter().find(|command| command.name == first) {
+            Some(subcommand) => {
+                positional.remove(0);
+                subcommand.command
+            }
+            None => Command::Run,
+        },
+        None => Command::Run,
+    };
+
+    let (filter, mut part) = match command {
+        Command::Run | Command::CrossCheck | Command::Stats | Command::List => {
+            selection(&positional, defaults.year)?
+        }
+        Command::Serve
+        | Command::Scrub
+        | Command::Scaffold
+        | Command::Samples
+        | Command::Replay
+        | Command::Today => (Filter::default(), None),
+    };
+    if let Some(value) = value("--part") {
+        part = Some(part_number(&value).ok_or(CliError::InvalidValue {
+            flag: "--part",
+            value,
+        })?);
+    }
+    let output = match value("--output").as_deref() {
+        None => defaults.output.unwrap_or_default(),
+        Some("text") => Output::Text,
+        Some("csv") => Output::Csv,
+        Some(other) => {
+            return Err(CliError::InvalidValue {
+                flag: "--output",
+                value: other.to_string(),
+            })
+        }
+    };
+    let report = match value("--report").as_deref() {
+        None => None,
+        Some("html") => Some(Report::Html),
+        Some(other) => {
+            return Err(CliError::InvalidValue {
+                flag: "--report",
+                value: other.to_string(),
+            })
+        }
+    };
+    let runs = match value("--runs") {
+        Some(value) => {
+            value
+                .parse()
+                .ok()
+                .filter(|&n: &u32| n > 0)
+                .ok_or(CliError::InvalidValue {
+                    flag: "--runs",
+                    value,
+                })?
+        }
+        None => 1,
+    };
+    let timeout = match value("--timeout") {
+        Some(value) => Some(
+            value
+                .parse()
+                .ok()
+                .filter(|&ms: &u64| ms > 0)
+                .map(Duration::from_millis)
+                .ok_or(CliError::InvalidValue {
+                    flag: "--timeout",
+                    value,
+                })?,
+        ),
+        None => None,
+    };
+    let slower_than = match value("--slower-than") {
+        Some(value) => Some(
+            value
+                .parse()
+                .ok()
+                .filter(|ms: &f64| ms.is_finite() && *ms >= 0.0)
+                .map(|ms| Duration::from_secs_f64(ms / 1000.0))
+                .ok_or(CliError::InvalidValue {
+                    flag: "--slower-than",
+                    value,
+                })?,
+        ),
+        None => None,
+    };
+    // Set names become file names, so they are kept to a single plain path component
+    let input_set = match (value("--input-set"), has("--example")) {
+        (Some(set), false)
+            if !set.is_empty()
+                && set
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-') =>
+        {
+            Some(set)
+        }
+        (Some(value), _) => {
+            return Err(CliError::InvalidValue {
+                flag: "--input-set",
+                value,
+            })
+        }
+        (None, example) => example.then(|| EXAMPLE_SET.to_string()),
+    };
+    let max_frames = match value("--max-frames") {
+        Some(value) => Some(value.parse().ok().filter(|&n: &usize| n >= 2).ok_or(
+            CliError::InvalidValue {
+                flag: "--max-frames",
+                value,
+            },
+        )?),
+        None => None,
+    };
+
+    Ok(Options {
+        command,
+        arguments: positional,
+        filter,
+        part,
+        input: value("--input"),
+        input_set,
+        output,
+        report,
+        redact: has("--redact"),
+        runs,
+        timeout,
+        slower_than,
+        baseline: value("--baseline"),
+        bench: has("--bench"),
+        no_cache: has("--no-cache"),
+        parallel: has("--parallel"),
+        strict: has("--strict"),
+        per_year: has("--per-year"),
+        notify: has("--notify"),
+        record: value("--record"),
+        max_frames,
+        step: has("--step"),
+        help: has("--help"),
+    })
+}
+
+/// Generates the help text from [`COMMANDS`] and [`FLAGS`].
+pub fn help() -> String {
+    let mut rows = vec![("Commands:", String::new(), "")];
+    for command in COMMANDS {
+        rows.push((
+            "",
+            format!("{} {}", command.name, command.usage),
+            command.help,
+        ));
+    }
+    rows.push(("Flags:", String::new(), ""));
+    for flag in FLAGS {
+        let usage = match flag.value {
+            Some(value) => format!("{} <{value}>", flag.name),
+            None => flag.name.to_string(),
+        };
+        rows.push(("", usage, flag.help));
+    }
+
+    let width = rows
+        .iter()
+        .map(|(_, usage, _)| usage.len())
+        .max()
+        .unwrap_or(0);
+    let mut text = String::from("Usage: aoc [command] [year[.day[.part]]] [flags]\n");
+
+    for (heading, usage, help) in rows {
+        if heading.is_empty() {
+            text.push_str(&format!("    {usage:width$}    {help}\n"));
+        } else {
+            text.push_str(&format!("\n{heading}\n"));
+        }
+    }
+
+    text
+}
+
+/// Reads the year, day and part from the positional arguments, using `year` when they do not
+/// name one.
+fn selection(positional: &[String], year: Option<u32>) -> Result<(Filter, Option<u32>), CliError> {
+    if let Some(word) = positional.iter().find(|arg| {
+        !arg.chars().any(|c| c.is_ascii_digit()) || arg.chars().any(|c| c.is_alphabetic())
+    }) {
+        return Err(CliError::UnexpectedArgument(word.clone()));
+    }
+
+    let joined = positional.join(" ");
+    let mut numbers: Vec<u32> = joined.as_str().iter_unsigned().collect();
+    if let Some(year) = year {
+        if numbers.first().is_none_or(|&first| first < FIRST_YEAR) {
+            numbers.insert(0, year);
+        }
+    }
+    if numbers.len() > 3 {
+        return Err(CliError::UnexpectedArgument(joined));
+    }
+
+    let part = match numbers.get(2) {
+        Some(&part) => Some(
+            part_number(&part.to_string()).ok_or(CliError::InvalidValue {
+                flag: "part",
+                value: part.to_string(),
+            })?,
+        ),
+        None => None,
+    };
+    Ok((
+        Filter::new(numbers.first().copied(), numbers.get(1).copied()),
+        part,
+    ))
+}
+
+fn part_number(value: &str) -> Option<u32> {
+    match value {
+        "1" => Some(1),
+        "2" => Some(2),
+        _ => None,
+    }
+}
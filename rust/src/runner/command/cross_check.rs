@@ -0,0 +1,41 @@
+//! `aoc cross-check`, comparing answers with the external implementations in `aoc.toml`.
+use crate::runner::cli::Options;
+use crate::runner::cross_check::cross_check;
+use crate::runner::run::RunOptions;
+use crate::util::ansi::*;
+use crate::util::config::Config;
+
+/// Compares answers with external implementations configured in aoc.toml.
+pub fn command(options: &Options, config: &Config, run: &RunOptions) {
+    let checks = cross_check(config, &options.filter, run).unwrap_or_else(|error| {
+        eprintln!("{BOLD}{RED}{error}{RESET}");
+        std::process::exit(2);
+    });
+
+    let mut failures = 0;
+    for check in checks {
+        println!(
+            "{BOLD}{YELLOW}{} Day {:02}{RESET} {}",
+            check.year, check.day, check.command
+        );
+        match check.outcome {
+            Ok(mismatches) if mismatches.is_empty() => println!("    {GREEN}Match{RESET}"),
+            Ok(mismatches) => {
+                failures += 1;
+                for mismatch in mismatches {
+                    println!(
+                        "    {RED}Part {}: expected {} got {}{RESET}",
+                        mismatch.part, mismatch.expected, mismatch.actual
+                    );
+                }
+            }
+            Err(error) => {
+                failures += 1;
+                eprintln!("    {RED}{error}{RESET}");
+            }
+        }
+    }
+    if failures > 0 {
+        std::process::exit(1);
+    }
+}
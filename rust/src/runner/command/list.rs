@@ -0,0 +1,36 @@
+//! `aoc list`, showing which registered days are missing inputs, answers or tests.
+use crate::runner::cli::Options;
+use crate::runner::run::RunOptions;
+use crate::runner::status::list;
+use crate::util::ansi::*;
+use std::path::Path;
+
+/// Prints which registered days are missing inputs, answers or tests.
+pub fn command(options: &Options, run: &RunOptions) {
+    let days = list(&options.filter, run, Path::new("."));
+    let mark = |present: bool| {
+        if present {
+            format!("{GREEN}✓{RESET}")
+        } else {
+            format!("{RED}✗{RESET}")
+        }
+    };
+
+    for status in &days {
+        println!(
+            "{YELLOW}{} Day {:02}{RESET}    input {}    answers {}    tests {}{}",
+            status.year,
+            status.day,
+            mark(status.input),
+            mark(status.answers),
+            mark(status.tests),
+            status
+                .name
+                .map(|name| format!("    {name}"))
+                .unwrap_or_default()
+        );
+    }
+
+    let complete = days.iter().filter(|status| status.is_complete()).count();
+    println!("{BOLD}{GREEN}Complete: {complete}/{}{RESET}", days.len());
+}
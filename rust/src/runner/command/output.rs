@@ -0,0 +1,114 @@
+//! Printing of answers, verdicts and timings shared by the commands that run days.
+use crate::runner::baseline::{format_percent, Change};
+use crate::runner::cli::Options;
+use crate::runner::registry::{Answer, Phases};
+use crate::runner::verify::{check, expected};
+use crate::util::ansi::*;
+use std::time::Duration;
+
+/// Checks both answers of a day against the known ones in `answers/`, `None` when unknown.
+///
+/// Known answers only apply to the default inputs, so `verify` is false for custom ones.
+pub fn verdicts(
+    options: &Options,
+    year: u32,
+    day: u32,
+    answers: &(Answer, Answer),
+    verify: bool,
+) -> [Option<bool>; 2] {
+    match expected(year, day, options.input_set.as_deref()).filter(|_| verify) {
+        Some(known) => check(&known, answers).map(Some),
+        None => [None, None],
+    }
+}
+
+/// Replaces both answers by their hashes with `--redact`.
+pub fn redact(options: &Options, answers: (Answer, Answer)) -> (Answer, Answer) {
+    if options.redact {
+        (answers.0.redacted(), answers.1.redacted())
+    } else {
+        answers
+    }
+}
+
+/// The known answers of a day as text, blank when unknown.
+pub fn expected_text(options: &Options, year: u32, day: u32) -> [String; 2] {
+    expected(year, day, options.input_set.as_deref()).map_or_else(Default::default, |known| {
+        let (part1, part2) = redact(options, known);
+        [part1.to_string(), part2.to_string()]
+    })
+}
+
+/// Prints both answers of a day, marked with their [`verdicts`], and the time of each phase.
+pub fn print_answers(
+    year: u32,
+    day: u32,
+    answers: &(Answer, Answer),
+    elapsed: Duration,
+    phases: &Phases,
+    [correct1, correct2]: [Option<bool>; 2],
+) {
+    let [time1, time2] = match phases.parts {
+        Some(parts) => parts.map(|part| format!(" ({} μs)", part.as_micros())),
+        None => [String::new(), String::new()],
+    };
+
+    println!("{BOLD}{YELLOW}{year} Day {day:02}{RESET}");
+    println!("    Parse: {} μs", phases.parse.as_micros());
+    println!("    Part 1: {}{}{time1}", answers.0, verdict(correct1));
+    println!("    Part 2: {}{}{time2}", answers.1, verdict(correct2));
+    if phases.parts.is_none() {
+        println!("    Solve: {} μs", phases.solve.as_micros());
+    }
+    println!("    Elapsed: {} μs", elapsed.as_micros());
+}
+
+/// Checks if a day took long enough to be printed with `--slower-than`.
+pub fn slower(options: &Options, elapsed: Duration) -> bool {
+    options.slower_than.is_none_or(|limit| elapsed > limit)
+}
+
+/// Mentions the days left out by `--slower-than`.
+pub fn print_hidden(options: &Options, hidden: usize) {
+    if let Some(limit) = options.slower_than.filter(|_| hidden > 0) {
+        println!(
+            "{BOLD}{GREEN}Hidden: {hidden} within {} ms{RESET}",
+            limit.as_secs_f64() * 1000.0
+        );
+    }
+}
+
+/// Shows how the time of a day compares with `--baseline`, in red for a regression.
+pub fn print_change(change: Option<Change>) {
+    if let Some(change) = change {
+        let color = if change.regressed { RED } else { "" };
+        println!(
+            "    Baseline: {} μs ({color}{}{RESET})",
+            change.before.as_micros(),
+            format_percent(change.percent())
+        );
+    }
+}
+
+/// Counts the days `--baseline` flagged as slower.
+pub fn print_regressions(options: &Options, threshold: u32, regressions: usize) {
+    let Some(path) = &options.baseline else {
+        return;
+    };
+    if regressions > 0 {
+        println!(
+            "{BOLD}{RED}Regressions: {regressions} more than {threshold}% slower than {path}{RESET}"
+        );
+    } else {
+        println!("{BOLD}{GREEN}No regressions against {path}{RESET}");
+    }
+}
+
+/// Marks an answer checked against the known one in `answers/`.
+pub fn verdict(correct: Option<bool>) -> String {
+    match correct {
+        Some(true) => format!(" {GREEN}✓{RESET}"),
+        Some(false) => format!(" {RED}✗{RESET}"),
+        None => String::new(),
+    }
+}
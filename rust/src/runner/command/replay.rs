@@ -0,0 +1,58 @@
+//! `aoc replay`, printing a run recorded with `--record`.
+use crate::runner::cli::Options;
+use crate::runner::command::output::{print_answers, redact, verdicts};
+use crate::runner::report::{self, DayReport};
+use crate::util::ansi::*;
+use crate::util::debugger;
+use std::time::Duration;
+
+/// Prints a recorded run, stepping through its frames with `--step`.
+pub fn command(options: &Options) {
+    let Some(path) = options.arguments.first() else {
+        eprintln!("{BOLD}{RED}Usage: aoc replay <report.json>{RESET}");
+        std::process::exit(2);
+    };
+    let reports = match report::load(path) {
+        Ok(reports) => reports,
+        Err(error) => {
+            eprintln!("{BOLD}{RED}{error}{RESET}");
+            std::process::exit(1);
+        }
+    };
+
+    let mut duration = Duration::ZERO;
+    for DayReport {
+        year,
+        day,
+        elapsed,
+        phases,
+        outcome,
+        frames,
+    } in reports
+    {
+        for frame in &frames {
+            debugger::tick(&frame.label, || frame.image.clone());
+        }
+
+        match outcome {
+            Ok(answers) => {
+                duration += elapsed;
+                let verdicts = verdicts(options, year, day, &answers, true);
+                let answers = redact(options, answers);
+                print_answers(year, day, &answers, elapsed, &phases, verdicts);
+                if !frames.is_empty() {
+                    println!("    Frames: {}", frames.len());
+                }
+            }
+            Err(error) => {
+                eprintln!("{BOLD}{RED}{year} Day {day:02}{RESET}");
+                eprintln!("    {error}");
+            }
+        }
+    }
+
+    println!(
+        "{BOLD}{GREEN}Recorded duration: {} ms{RESET}",
+        duration.as_millis()
+    );
+}
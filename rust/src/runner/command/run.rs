@@ -0,0 +1,558 @@
+//! `aoc` without a subcommand, solving the selected days and printing answers and timings.
+//!
+//! Days are run in one of three ways: benchmarked with `--bench`, one part only with `--part`, or
+//! both parts with their answers checked. Each way prints its days through [`each_day`], which
+//! reports failures the same way for all of them and keeps the counts in a [`Tally`].
+use crate::runner::baseline::{Baseline, Change, THRESHOLD};
+use crate::runner::cache::{Cache, CACHE};
+use crate::runner::cli::{Options, Output, Report};
+use crate::runner::command::output::*;
+use crate::runner::download::{ensure, session, SESSION_VAR};
+use crate::runner::export::{csv_row, CSV_HEADER};
+use crate::runner::html::{self, PAGE};
+use crate::runner::memory::format_bytes;
+use crate::runner::notify::{message, notify};
+use crate::runner::progress::Bar;
+use crate::runner::registry::{Phases, INPUT_DIR};
+use crate::runner::report::{self, DayReport};
+use crate::runner::run::*;
+use crate::runner::settings::Settings;
+use crate::runner::stats::{record, TIMINGS};
+use crate::runner::timing::{bench, bench_input, DayTiming};
+use crate::runner::verify::expected;
+use crate::util::ansi::*;
+use crate::util::config::Config;
+use crate::util::debugger;
+use std::fs::read_to_string;
+use std::io::{stderr, IsTerminal};
+use std::iter::once;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Untimed runs before measuring in `--bench` mode.
+const BENCH_WARMUP: u32 = 3;
+/// Timed runs per day in `--bench` mode, unless configured in aoc.toml.
+const BENCH_RUNS: u32 = 25;
+/// Unchanged cells kept around the moving part of recorded frames.
+const FOCUS_MARGIN: i32 = 2;
+
+/// How days are run, from `aoc.toml` and the command line.
+pub fn run_options(options: &Options, settings: &Settings) -> RunOptions {
+    RunOptions {
+        timeout: options.timeout,
+        runs: options.runs,
+        cache: None,
+        input_dir: settings
+            .input
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(INPUT_DIR)),
+        input_set: options.input_set.clone(),
+    }
+}
+
+/// What the three ways of running days share.
+struct Context<'a> {
+    options: &'a Options,
+    config: &'a Config,
+    run: &'a RunOptions,
+    /// The day and contents given to `--input`.
+    custom: Option<(u32, u32, Result<String, RunError>)>,
+    baseline: Option<Baseline>,
+    /// Percentage above the baseline that counts as a regression.
+    threshold: u32,
+    bar: Option<Bar>,
+    /// Whether answers are checked against the known ones, which only apply to registered inputs.
+    verify: bool,
+    /// Whether the default inputs are run, which are downloaded when missing and kept in stats.
+    real: bool,
+}
+
+impl Context<'_> {
+    /// How the time of a day compares with `--baseline`, if it has one.
+    fn compare(&self, year: u32, day: u32, elapsed: Duration) -> Option<Change> {
+        let before = self.baseline.as_ref()?.get(year, day)?;
+        Some(Change::new(before, elapsed, self.threshold))
+    }
+}
+
+/// Counts kept over the days of a run, whichever way they are run.
+#[derive(Default)]
+struct Tally {
+    /// Days that produced answers, or timings with `--bench`.
+    solved: usize,
+    /// Days left out by `--slower-than`.
+    hidden: usize,
+    /// Days `--baseline` flagged as slower.
+    regressions: usize,
+    /// Answers that differ from the known ones.
+    mismatches: usize,
+    /// The highest [`RunError::exit_code`] among the days that failed.
+    failure: i32,
+    /// Total time of the solved days.
+    duration: Duration,
+}
+
+impl Tally {
+    /// Rings the bell and runs the configured notification command with `--notify`.
+    fn notify(&self, options: &Options, config: &Config) {
+        if !options.notify {
+            return;
+        }
+        let message = message(self.solved, self.mismatches, self.duration);
+        if let Err(error) = notify(config, &message) {
+            eprintln!("{BOLD}{RED}Unable to notify: {error}{RESET}");
+        }
+    }
+
+    /// Exits with a failing status if the run went wrong.
+    ///
+    /// Wrong answers always fail the run. With `--strict` so do days that could not produce
+    /// answers, exiting with the highest [`RunError::exit_code`] among them.
+    fn conclude(&self, options: &Options) {
+        if options.strict && self.failure > 0 {
+            std::process::exit(self.failure);
+        }
+        if self.mismatches > 0 {
+            std::process::exit(EXIT_MISMATCH);
+        }
+    }
+}
+
+/// Solves the selected days, printing answers and timings.
+pub fn command(options: &Options, config: &Config, settings: &Settings, run: &RunOptions) {
+    let filter = options.filter;
+    let custom = options
+        .input
+        .as_ref()
+        .map(|path| custom_input(&filter, Path::new(path)));
+    let verify = custom.is_none();
+    // Other input sets are checked against their own answers, but neither downloaded nor kept
+    // in stats
+    let real = verify && options.input_set.is_none();
+    let baseline = options
+        .baseline
+        .as_ref()
+        .map(|path| match Baseline::load(path) {
+            Ok(baseline) => baseline,
+            Err(error) => {
+                eprintln!("{BOLD}{RED}{error}{RESET}");
+                std::process::exit(2);
+            }
+        });
+
+    // Download missing inputs when a session token is available
+    let session = session(config, std::env::var(SESSION_VAR).ok());
+    if let Some(session) = session.filter(|_| real) {
+        for solution in run
+            .solutions()
+            .iter()
+            .filter(|solution| filter.matches(solution))
+        {
+            match ensure(solution, Some(&session)) {
+                Ok(true) => println!("{BOLD}{GREEN}Downloaded {}{RESET}", solution.path.display()),
+                Ok(false) => (),
+                Err(error) => eprintln!(
+                    "{BOLD}{RED}{} Day {:02}: {error}{RESET}",
+                    solution.year, solution.day
+                ),
+            }
+        }
+    }
+
+    // Draw a progress bar for slow days, unless days run side by side or are being stepped through
+    let bar =
+        (stderr().is_terminal() && !options.parallel && !debugger::is_enabled()).then(Bar::show);
+
+    let context = Context {
+        options,
+        config,
+        run,
+        custom,
+        baseline,
+        threshold: settings.regression.unwrap_or(THRESHOLD),
+        bar,
+        verify,
+        real,
+    };
+    if options.bench {
+        bench_days(context, settings.bench_runs.unwrap_or(BENCH_RUNS));
+    } else if let Some(part) = options.part {
+        part_days(context, part);
+    } else {
+        all_days(context);
+    }
+}
+
+/// Repeatedly times each solution instead of printing answers.
+fn bench_days(context: Context, runs: u32) {
+    let (options, config) = (context.options, context.config);
+    let timings: Box<dyn Iterator<Item = DayTiming>> = match &context.custom {
+        Some((year, day, Ok(data))) => Box::new(once(bench_input(
+            *year,
+            *day,
+            data,
+            BENCH_WARMUP,
+            runs,
+            context.run,
+        ))),
+        Some((year, day, Err(error))) => Box::new(once(DayTiming {
+            year: *year,
+            day: *day,
+            outcome: Err(error.clone()),
+        })),
+        None => Box::new(bench(&options.filter, BENCH_WARMUP, runs, context.run)),
+    };
+
+    let mut tally = Tally::default();
+    let days = timings.map(|DayTiming { year, day, outcome }| {
+        (year, day, outcome.map(|timing| (timing.mean, timing)))
+    });
+    each_day(
+        days,
+        &context,
+        &mut tally,
+        |tally, year, day, mean, timing| {
+            let change = context.compare(year, day, mean);
+            let regressed = change.is_some_and(|change| change.regressed);
+            tally.regressions += usize::from(regressed);
+            if !regressed && !slower(options, mean) {
+                tally.hidden += 1;
+                return;
+            }
+            println!(
+                "{BOLD}{YELLOW}{year} Day {day:02}{RESET} ({} runs)",
+                timing.runs
+            );
+            println!("    Min: {} μs", timing.min.as_micros());
+            println!("    Median: {} μs", timing.median.as_micros());
+            println!("    Mean: {} μs", timing.mean.as_micros());
+            println!("    Stddev: {} μs", timing.stddev.as_micros());
+            print_change(change);
+        },
+    );
+
+    print_hidden(options, tally.hidden);
+    print_regressions(options, context.threshold, tally.regressions);
+    tally.notify(options, config);
+    tally.conclude(options);
+}
+
+/// Runs only the requested part.
+fn part_days(mut context: Context, part: u32) {
+    let (options, config) = (context.options, context.config);
+    let results: Box<dyn Iterator<Item = PartResult>> = match context.custom.take() {
+        Some((year, day, data)) => {
+            let instant = Instant::now();
+            let outcome = data.and_then(|data| solve_part_with(year, day, part, data, context.run));
+            Box::new(once(PartResult {
+                year,
+                day,
+                part,
+                elapsed: instant.elapsed(),
+                outcome,
+            }))
+        }
+        None => Box::new(iter_part(&options.filter, part, context.run)),
+    };
+
+    let mut tally = Tally::default();
+    let days = results.map(
+        |PartResult {
+             year,
+             day,
+             elapsed,
+             outcome,
+             ..
+         }| { (year, day, outcome.map(|answer| (elapsed, answer))) },
+    );
+    each_day(
+        days,
+        &context,
+        &mut tally,
+        |tally, year, day, elapsed, answer| {
+            let known =
+                expected(year, day, context.run.input_set.as_deref()).filter(|_| context.verify);
+            let correct = known.map(|(part1, part2)| {
+                let known = if part == 1 { part1 } else { part2 };
+                known.matches(&answer)
+            });
+            tally.mismatches += usize::from(correct == Some(false));
+            if correct != Some(false) && !slower(options, elapsed) {
+                tally.hidden += 1;
+                return;
+            }
+
+            let answer = if options.redact {
+                answer.redacted()
+            } else {
+                answer
+            };
+            println!("{BOLD}{YELLOW}{year} Day {day:02}{RESET}");
+            println!("    Part {part}: {answer}{}", verdict(correct));
+            if options.input_set.is_some() && correct == Some(false) {
+                let [part1, part2] = expected_text(options, year, day);
+                let known = if part == 1 { part1 } else { part2 };
+                println!("    Expected: {known}");
+            }
+            println!("    Elapsed: {} μs", elapsed.as_micros());
+        },
+    );
+
+    println!("{BOLD}{RED}Solved: {}{RESET}", tally.solved);
+    println!(
+        "{BOLD}{GREEN}Duration: {} ms{RESET}",
+        tally.duration.as_millis()
+    );
+    print_hidden(options, tally.hidden);
+    tally.notify(options, config);
+    tally.conclude(options);
+}
+
+/// Pretty prints output and timing for each solution.
+fn all_days(mut context: Context) {
+    let (options, config) = (context.options, context.config);
+    let mut cached = 0;
+    let mut timings = Vec::new();
+    let wall_clock = Instant::now();
+
+    // Stepping through or recording visualisations needs one day at a time
+    let mut reports = Vec::new();
+    if options.record.is_some() {
+        if let Some(max_frames) = options.max_frames {
+            debugger::set_max_frames(max_frames);
+        }
+        debugger::record(true);
+    }
+    let visualised = debugger::is_enabled() || debugger::is_recording();
+    let parallel = options.parallel && !visualised;
+    // Answers of unchanged days are reused, unless the run measures or records them
+    let cache = (!options.no_cache && !visualised && options.runs <= 1)
+        .then(|| Cache::open(CACHE))
+        .flatten()
+        .map(|cache| Arc::new(Mutex::new(cache)));
+    let run = &RunOptions {
+        runs: if visualised { 1 } else { options.runs },
+        cache: cache.clone(),
+        ..context.run.clone()
+    };
+    let results: Box<dyn Iterator<Item = DayResult>> = match context.custom.take() {
+        Some((year, day, data)) => Box::new(once(match data {
+            Ok(data) => solve_with(year, day, data, run),
+            Err(error) => DayResult {
+                year,
+                day,
+                elapsed: Duration::ZERO,
+                phases: Phases::default(),
+                outcome: Err(error),
+                samples: Vec::new(),
+                peak_memory: None,
+                cached: false,
+            },
+        })),
+        None if parallel => {
+            let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+            Box::new(iter_parallel(&options.filter, threads, run))
+        }
+        None => Box::new(iter(&options.filter, run)),
+    };
+
+    let csv = options.output == Output::Csv;
+    if csv {
+        println!("{CSV_HEADER}");
+    }
+
+    let mut tally = Tally::default();
+    let days = results.map(|result| {
+        if options.record.is_some() || options.report.is_some() {
+            let frames = report::focused(debugger::take_frames(), FOCUS_MARGIN);
+            reports.push(DayReport::new(&result, frames));
+        }
+        let outcome = result.outcome.clone();
+        let (year, day, elapsed) = (result.year, result.day, result.elapsed);
+        (
+            year,
+            day,
+            outcome.map(|answers| (elapsed, (answers, result))),
+        )
+    });
+    each_day(
+        days,
+        &context,
+        &mut tally,
+        |tally, year, day, elapsed, (answers, result)| {
+            let DayResult {
+                phases,
+                samples,
+                peak_memory,
+                cached: from_cache,
+                ..
+            } = result;
+            // Cached timings are already recorded, possibly from a slower build, so they are not
+            // compared with the baseline either
+            let change = if from_cache {
+                cached += 1;
+                None
+            } else {
+                timings.push((year, day, elapsed));
+                context.compare(year, day, elapsed)
+            };
+            let regressed = change.is_some_and(|change| change.regressed);
+            tally.regressions += usize::from(regressed);
+
+            // CSV rows only carry timings, so their answers are not checked
+            let verdicts = verdicts(options, year, day, &answers, context.verify && !csv);
+            let wrong = verdicts.iter().filter(|&&v| v == Some(false)).count();
+            tally.mismatches += wrong;
+            if wrong == 0 && !regressed && !slower(options, elapsed) {
+                tally.hidden += 1;
+                return;
+            }
+
+            if csv {
+                println!("{}", csv_row(year, day, &phases));
+                return;
+            }
+            let answers = redact(options, answers);
+            print_answers(year, day, &answers, elapsed, &phases, verdicts);
+            print_change(change);
+            if options.input_set.is_some() && wrong > 0 {
+                let [part1, part2] = expected_text(options, year, day);
+                println!("    Expected: {part1} and {part2}");
+            }
+            if samples.len() > 1 {
+                let slowest = samples.iter().max().copied().unwrap_or_default();
+                println!(
+                    "    Best of {} runs, slowest {} μs",
+                    samples.len(),
+                    slowest.as_micros()
+                );
+            }
+            if let Some(peak) = peak_memory {
+                println!("    Peak heap: {}", format_bytes(peak));
+            }
+            if from_cache {
+                println!("    Cached from an earlier run");
+            }
+        },
+    );
+
+    if let Some(path) = &options.record {
+        match report::save(path, &reports) {
+            Ok(()) => eprintln!("{BOLD}{GREEN}Recorded {path}{RESET}"),
+            Err(error) => eprintln!("{BOLD}{RED}Unable to save {path}: {error}{RESET}"),
+        }
+    }
+    if options.report == Some(Report::Html) {
+        let reports: Vec<DayReport> = if options.redact {
+            reports.iter().map(DayReport::redacted).collect()
+        } else {
+            reports
+        };
+        match html::save(PAGE, &reports) {
+            Ok(()) => eprintln!("{BOLD}{GREEN}Wrote {PAGE}{RESET}"),
+            Err(error) => eprintln!("{BOLD}{RED}Unable to save {PAGE}: {error}{RESET}"),
+        }
+    }
+
+    if let Some(cache) = cache {
+        if let Err(error) = cache.lock().unwrap().save(CACHE) {
+            eprintln!("{BOLD}{RED}Unable to save {CACHE}: {error}{RESET}");
+        }
+    }
+
+    // Keep the latest timings of the default inputs for the stats command
+    if !context.real {
+        timings.clear();
+    }
+    let mut store = Config::load(TIMINGS).unwrap_or_default();
+    timings
+        .iter()
+        .for_each(|&(year, day, elapsed)| record(&mut store, year, day, elapsed));
+    if !timings.is_empty() {
+        if let Err(error) = store.save(TIMINGS) {
+            eprintln!("{BOLD}{RED}Unable to save {TIMINGS}: {error}{RESET}");
+        }
+    }
+
+    // Print totals
+    tally.notify(options, config);
+    if csv {
+        tally.conclude(options);
+        return;
+    }
+    println!("{BOLD}{RED}Solved: {}{RESET}", tally.solved);
+    println!(
+        "{BOLD}{GREEN}Duration: {} ms{RESET}",
+        tally.duration.as_millis()
+    );
+    print_hidden(options, tally.hidden);
+    print_regressions(options, context.threshold, tally.regressions);
+    if cached > 0 {
+        println!("{BOLD}{GREEN}Cached: {cached}, solve them again with --no-cache{RESET}");
+    }
+    if parallel {
+        println!(
+            "{BOLD}{GREEN}Wall clock: {} ms{RESET}",
+            wall_clock.elapsed().as_millis()
+        );
+    }
+    if tally.mismatches > 0 {
+        println!("{BOLD}{RED}Wrong answers: {}{RESET}", tally.mismatches);
+    }
+    tally.conclude(options);
+}
+
+/// Prints each day of a run as it finishes, the loop shared by the three ways of running days.
+///
+/// Each item holds the year and day, then either the time counted towards the total along with
+/// what `show` prints, or the error of a day that failed. `show` is only called for days that
+/// succeeded, with that time, and counts its own hidden days, regressions and mismatches.
+fn each_day<T>(
+    days: impl Iterator<Item = (u32, u32, Result<(Duration, T), RunError>)>,
+    context: &Context,
+    tally: &mut Tally,
+    mut show: impl FnMut(&mut Tally, u32, u32, Duration, T),
+) {
+    for (year, day, outcome) in days {
+        if let Some(bar) = &context.bar {
+            bar.clear();
+        }
+        match outcome {
+            Ok((elapsed, value)) => {
+                tally.solved += 1;
+                tally.duration += elapsed;
+                show(tally, year, day, elapsed, value);
+            }
+            Err(error) => {
+                tally.failure = tally.failure.max(error.exit_code());
+                eprintln!("{BOLD}{RED}{year} Day {day:02}{RESET}");
+                match error {
+                    RunError::MissingInput(path) => {
+                        eprintln!("    Missing input!");
+                        eprintln!(
+                            "    Place input file in {BOLD}{WHITE}{}{RESET}",
+                            path.display()
+                        );
+                        if context.real {
+                            eprintln!(
+                                "    or set {BOLD}{WHITE}{SESSION_VAR}{RESET} to download it"
+                            );
+                        }
+                    }
+                    error => eprintln!("    {error}"),
+                }
+            }
+        }
+    }
+}
+
+/// Reads the file given to `--input`, which replaces the input of the single selected day.
+fn custom_input(filter: &Filter, path: &Path) -> (u32, u32, Result<String, RunError>) {
+    let (Some(year), Some(day)) = (filter.year, filter.day) else {
+        eprintln!("{BOLD}{RED}--input needs a single day, e.g. aoc 2024.6 --input <path>{RESET}");
+        std::process::exit(2);
+    };
+    let data = read_to_string(path).map_err(|_| RunError::MissingInput(path.to_path_buf()));
+    (year, day, data)
+}
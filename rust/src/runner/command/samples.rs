@@ -0,0 +1,79 @@
+//! `aoc samples`, saving the examples of a puzzle description as input sets.
+use crate::runner::cli::Options;
+use crate::runner::download::{fetch_puzzle, puzzle_path, session, SESSION_VAR};
+use crate::runner::run::RunOptions;
+use crate::runner::samples;
+use crate::util::ansi::*;
+use crate::util::config::Config;
+use crate::util::parse::*;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::path::Path;
+
+/// Saves the examples of a puzzle description as input sets, downloading the page if needed.
+pub fn command(options: &Options, config: &Config, run: &RunOptions) {
+    let numbers: Vec<u32> = options
+        .arguments
+        .join(" ")
+        .as_str()
+        .iter_unsigned()
+        .collect();
+    let [year, day] = numbers[..] else {
+        eprintln!("{BOLD}{RED}Usage: aoc samples <year> <day>{RESET}");
+        std::process::exit(2);
+    };
+
+    // Download the page again while it only shows part one, in case it has been solved since
+    let path = puzzle_path(year, day);
+    let cached = read_to_string(&path).ok();
+    let html = match session(config, std::env::var(SESSION_VAR).ok()) {
+        Some(session)
+            if cached
+                .as_ref()
+                .is_none_or(|html| html.matches("<article").count() < 2) =>
+        {
+            match fetch_puzzle(year, day, &session) {
+                Ok(html) => {
+                    let saved = path
+                        .parent()
+                        .map_or(Ok(()), create_dir_all)
+                        .and_then(|()| write(&path, &html));
+                    if let Err(error) = saved {
+                        eprintln!(
+                            "{BOLD}{RED}Unable to save {}: {error}{RESET}",
+                            path.display()
+                        );
+                    }
+                    Some(html)
+                }
+                Err(error) => {
+                    eprintln!("{BOLD}{RED}{error}{RESET}");
+                    cached
+                }
+            }
+        }
+        _ => cached,
+    };
+    let Some(html) = html else {
+        eprintln!(
+            "{BOLD}{RED}No description in {}, set {SESSION_VAR} to download it{RESET}",
+            path.display()
+        );
+        std::process::exit(1);
+    };
+
+    let year_dir = format!("year{year}");
+    let inputs = run.input_dir.join(&year_dir);
+    let answers = Path::new("answers").join(&year_dir);
+    match samples::save(&html, &inputs, &answers, day) {
+        Ok(paths) if paths.is_empty() => println!("{YELLOW}No new examples found{RESET}"),
+        Ok(paths) => {
+            for path in paths {
+                println!("{GREEN}Wrote {}{RESET}", path.display());
+            }
+        }
+        Err(error) => {
+            eprintln!("{BOLD}{RED}Unable to save examples: {error}{RESET}");
+            std::process::exit(1);
+        }
+    }
+}
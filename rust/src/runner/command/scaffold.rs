@@ -0,0 +1,36 @@
+//! `aoc scaffold`, creating and registering the files of a new day.
+use crate::runner::cli::Options;
+use crate::runner::scaffold::scaffold;
+use crate::util::ansi::*;
+use crate::util::parse::*;
+use std::path::Path;
+
+/// Creates and registers the files for a new day.
+pub fn command(options: &Options) {
+    let numbers: Vec<u32> = options
+        .arguments
+        .join(" ")
+        .as_str()
+        .iter_unsigned()
+        .collect();
+    let [year, day] = numbers[..] else {
+        eprintln!("{BOLD}{RED}Usage: aoc scaffold <year> <day>{RESET}");
+        std::process::exit(2);
+    };
+    scaffold_day(year, day);
+}
+
+/// Scaffolds a day in the working directory, printing the files written.
+pub fn scaffold_day(year: u32, day: u32) {
+    match scaffold(Path::new("."), year, day) {
+        Ok(paths) => {
+            for path in paths {
+                println!("{GREEN}Wrote {}{RESET}", path.display());
+            }
+        }
+        Err(error) => {
+            eprintln!("{BOLD}{RED}{error}{RESET}");
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,33 @@
+//! `aoc scrub`, printing a synthetic version of a real input.
+use crate::runner::cli::Options;
+use crate::runner::run::RunOptions;
+use crate::runner::scrub::scrub;
+use crate::util::ansi::*;
+use crate::util::parse::*;
+use crate::util::rng::Rng;
+use std::fs::read_to_string;
+
+/// Prints a synthetic version of a real input to stdout.
+pub fn command(options: &Options, run: &RunOptions) {
+    let number = |index: usize| -> Option<u64> {
+        options
+            .arguments
+            .get(index)
+            .and_then(|arg| arg.as_str().iter_unsigned().next())
+    };
+    let (year, day) = (number(0), number(1));
+    let mut rng = match number(2) {
+        Some(seed) => Rng::new(seed),
+        None => Rng::from_time(),
+    };
+
+    let solution = run
+        .solutions()
+        .into_iter()
+        .find(|solution| Some(solution.year as u64) == year && Some(solution.day as u64) == day);
+    match solution.map(|solution| read_to_string(&solution.path)) {
+        Some(Ok(data)) => print!("{}", scrub(&data, &mut rng)),
+        Some(Err(_)) => eprintln!("{BOLD}{RED}Missing input!{RESET}"),
+        None => eprintln!("{BOLD}{RED}Usage: aoc scrub <year> <day> [seed]{RESET}"),
+    }
+}
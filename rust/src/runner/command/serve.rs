@@ -0,0 +1,17 @@
+//! `aoc serve`, answering solve requests over HTTP until interrupted.
+use crate::runner::cli::Options;
+use crate::runner::run::RunOptions;
+use crate::runner::serve::serve;
+use crate::util::ansi::*;
+
+/// Long running server mode.
+pub fn command(options: &Options, run: &RunOptions) {
+    let address = options
+        .arguments
+        .first()
+        .map_or("127.0.0.1:8080", String::as_str);
+    println!("{BOLD}{GREEN}Listening on {address}{RESET}");
+    if let Err(error) = serve(address, run) {
+        eprintln!("{BOLD}{RED}Unable to serve on {address}: {error}{RESET}");
+    }
+}
@@ -0,0 +1,48 @@
+//! `aoc stats`, printing the timings recorded by previous runs.
+use crate::runner::cli::Options;
+use crate::runner::registry::solutions;
+use crate::runner::stats::{per_year, recorded, GOAL, TIMINGS};
+use crate::util::ansi::*;
+use crate::util::config::Config;
+
+/// Prints the timings recorded by previous runs, per day or per year.
+pub fn command(options: &Options) {
+    let store = Config::load(TIMINGS).unwrap_or_default();
+    let solutions: Vec<_> = solutions()
+        .into_iter()
+        .filter(|solution| options.filter.matches(solution))
+        .collect();
+
+    if options.per_year {
+        for stats in per_year(&solutions, &store) {
+            let color = if stats.meets_goal() { GREEN } else { RED };
+            println!("{BOLD}{YELLOW}{}{RESET}", stats.year);
+            println!("    Solved: {}/{}", stats.solved, stats.registered);
+            if let Some(timing) = stats.timing {
+                println!("    Mean: {} μs", timing.mean.as_micros());
+                println!("    Median: {} μs", timing.median.as_micros());
+            }
+            println!(
+                "    Total: {color}{:.3} ms ({:.1}% of {} ms goal){RESET}",
+                stats.total.as_secs_f64() * 1000.0,
+                stats.percent_of_goal(),
+                GOAL.as_millis()
+            );
+        }
+        return;
+    }
+
+    let timings = recorded(&store);
+    for solution in solutions {
+        let (year, day) = (solution.year, solution.day);
+        match timings.iter().find(|&&(y, d, _)| y == year && d == day) {
+            Some((_, _, elapsed)) => {
+                println!(
+                    "{YELLOW}{year} Day {day:02}{RESET} {} μs",
+                    elapsed.as_micros()
+                )
+            }
+            None => println!("{YELLOW}{year} Day {day:02}{RESET} {RED}not run{RESET}"),
+        }
+    }
+}
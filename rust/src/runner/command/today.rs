@@ -0,0 +1,39 @@
+//! `aoc today`, running or scaffolding the puzzle unlocked today.
+use crate::runner::calendar::{format_remaining, today, Today};
+use crate::runner::cli::{Command, Options};
+use crate::runner::command::{run, scaffold::scaffold_day};
+use crate::runner::registry::solutions;
+use crate::runner::run::{Filter, RunOptions};
+use crate::runner::settings::Settings;
+use crate::util::ansi::*;
+use crate::util::config::Config;
+use std::time::SystemTime;
+
+/// Runs the puzzle unlocked today, scaffolding it first if it has no solution yet.
+pub fn command(options: &Options, config: &Config, settings: &Settings, run_options: &RunOptions) {
+    match today(SystemTime::now()) {
+        Today::Locked {
+            year,
+            day,
+            remaining,
+        } => println!(
+            "{BOLD}{YELLOW}{year} Day {day:02}{RESET} unlocks in {}",
+            format_remaining(remaining)
+        ),
+        Today::Unlocked { year, day } => {
+            let registered = solutions()
+                .iter()
+                .any(|solution| solution.year == year && solution.day == day);
+            if registered {
+                let options = Options {
+                    command: Command::Run,
+                    filter: Filter::new(Some(year), Some(day)),
+                    ..options.clone()
+                };
+                run::command(&options, config, settings, run_options);
+            } else {
+                scaffold_day(year, day);
+            }
+        }
+    }
+}
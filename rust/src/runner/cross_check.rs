@@ -0,0 +1,193 @@
+//! Compares the crate's answers with other implementations of the same puzzle.
+//!
+//! External commands are registered per day in `aoc.toml`:
+//!
+//! ```toml
+//! [cross-check.2024]
+//! 01 = "../2024/rust/01/target/release/day01"
+//! 03 = "python3 ../2024/python/day03.py {input}"
+//! ```
+//!
+//! Each command is run with `sh -c`, receiving the puzzle input on stdin, with `{input}`
+//! replaced by the path of the input file. The last two non empty lines of its output are taken
+//! as the answers to part one and two. Lines such as `Part 1: 123` are accepted, as only the
+//! text after the last colon is compared.
+use crate::runner::registry::Answer;
+use crate::runner::run::{solve_with, Filter, RunError, RunOptions};
+use crate::util::config::Config;
+use std::fmt;
+use std::fs::read_to_string;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Section prefix for cross-check commands in `aoc.toml`.
+const SECTION: &str = "cross-check.";
+
+/// The result of comparing one external command against the crate.
+///
+/// # Fields
+/// - `year`, `day`: Identify the solution.
+/// - `command`: The configured command line, before substituting `{input}`.
+/// - `outcome`: The parts whose answers differ, empty when both agree.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CrossCheck {
+    pub year: u32,
+    pub day: u32,
+    pub command: String,
+    pub outcome: Result<Vec<Mismatch>, CrossCheckError>,
+}
+
+/// A part where the external command disagrees with the crate.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Mismatch {
+    pub part: u32,
+    pub expected: String,
+    pub actual: String,
+}
+
+/// Reasons a comparison could not be made.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum CrossCheckError {
+    /// The crate itself could not solve the day.
+    Run(RunError),
+    /// The command could not be started or exited unsuccessfully.
+    Command(String),
+    /// The command printed fewer than two answers.
+    Output(String),
+    /// A key of `aoc.toml` does not name a year and day, given as `section.key`.
+    Config(String),
+}
+
+impl fmt::Display for CrossCheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrossCheckError::Run(error) => write!(f, "{error}"),
+            CrossCheckError::Command(message) => write!(f, "Command failed: {message}"),
+            CrossCheckError::Output(output) => write!(f, "Unrecognized output: {output:?}"),
+            CrossCheckError::Config(key) => {
+                write!(f, "Invalid cross-check key {key}, expected a year and day")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CrossCheckError {}
+
+/// Returns the `(year, day, command)` entries configured in `aoc.toml`.
+///
+/// # Errors
+/// Returns [`CrossCheckError::Config`] naming the first key that is not a year and day number.
+pub fn commands(config: &Config) -> Result<Vec<(u32, u32, String)>, CrossCheckError> {
+    let mut commands = Vec::new();
+
+    for name in config.sections() {
+        let Some(year) = name.strip_prefix(SECTION) else {
+            continue;
+        };
+        for (day, command) in config.section(name) {
+            let (Ok(year), Ok(day)) = (year.parse(), day.parse()) else {
+                return Err(CrossCheckError::Config(format!("{name}.{day}")));
+            };
+            commands.push((year, day, command.to_string()));
+        }
+    }
+
+    commands.sort_by_key(|&(year, day, _)| (year, day));
+    Ok(commands)
+}
+
+/// Runs every configured command selected by the filter and compares its answers, on the inputs
+/// the options point to.
+///
+/// # Errors
+/// Returns [`CrossCheckError::Config`] before running anything if a key is invalid.
+pub fn cross_check<'a>(
+    config: &Config,
+    filter: &'a Filter,
+    options: &'a RunOptions,
+) -> Result<impl Iterator<Item = CrossCheck> + 'a, CrossCheckError> {
+    let checks = commands(config)?
+        .into_iter()
+        .filter(|&(year, day, _)| {
+            filter.year.is_none_or(|y| y == year) && filter.day.is_none_or(|d| d == day)
+        })
+        .map(|(year, day, command)| {
+            let outcome = check(year, day, &command, options);
+            CrossCheck {
+                year,
+                day,
+                command,
+                outcome,
+            }
+        });
+    Ok(checks)
+}
+
+/// Extracts the answers to both parts from the output of an external command.
+pub fn answers(output: &str) -> Option<(String, String)> {
+    let mut lines = output
+        .lines()
+        .map(|line| line.rsplit(':').next().unwrap_or_default().trim())
+        .filter(|line| !line.is_empty());
+    let part2 = lines.next_back()?;
+    let part1 = lines.next_back()?;
+    Some((part1.to_string(), part2.to_string()))
+}
+
+fn check(
+    year: u32,
+    day: u32,
+    command: &str,
+    options: &RunOptions,
+) -> Result<Vec<Mismatch>, CrossCheckError> {
+    let solution = options
+        .solutions()
+        .into_iter()
+        .find(|solution| solution.year == year && solution.day == day)
+        .ok_or(CrossCheckError::Run(RunError::NotFound { year, day }))?;
+    let data = read_to_string(&solution.path)
+        .map_err(|_| CrossCheckError::Run(RunError::MissingInput(solution.path.clone())))?;
+
+    let output = external(command, &solution.path.display().to_string(), &data)?;
+    let (part1, part2) = answers(&output).ok_or(CrossCheckError::Output(output.clone()))?;
+    let (expected1, expected2) = solve_with(year, day, data, options)
+        .outcome
+        .map_err(CrossCheckError::Run)?;
+
+    let mismatches = [(1, expected1, part1), (2, expected2, part2)]
+        .into_iter()
+        .filter(|(_, expected, actual)| !expected.matches(&Answer::from(actual.as_str())))
+        .map(|(part, expected, actual)| Mismatch {
+            part,
+            expected: expected.to_string(),
+            actual,
+        })
+        .collect();
+    Ok(mismatches)
+}
+
+/// Runs a shell command with the input on stdin, returning its stdout.
+fn external(command: &str, path: &str, data: &str) -> Result<String, CrossCheckError> {
+    let failed = |error: std::io::Error| CrossCheckError::Command(error.to_string());
+
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(command.replace("{input}", path))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .map_err(failed)?;
+
+    // Commands that read the file themselves may close stdin early, which is not an error.
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = stdin.write_all(data.as_bytes());
+    }
+
+    let output = child.wait_with_output().map_err(failed)?;
+    if !output.status.success() {
+        return Err(CrossCheckError::Command(output.status.to_string()));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
@@ -0,0 +1,143 @@
+//! Fetches missing puzzle inputs, and the puzzle descriptions, from adventofcode.com.
+//!
+//! Inputs are personal, so requests need the `session` cookie of a logged in browser. It is read
+//! from the `AOC_SESSION` environment variable, or else from `aoc.toml`:
+//!
+//! ```toml
+//! [download]
+//! session = "53616c7465645f5f..."
+//! ```
+//!
+//! The download itself is delegated to `curl`, as the standard library has no TLS support. The
+//! cookie header is passed on stdin so the token does not show up in the process list.
+use crate::runner::registry::Solution;
+use crate::util::config::Config;
+use std::fmt;
+use std::fs::{create_dir_all, write};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Environment variable holding the session token.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Reasons an input could not be downloaded.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum DownloadError {
+    /// Neither `AOC_SESSION` nor `aoc.toml` provide a session token.
+    NoSession,
+    /// `curl` could not be started or the server refused the request.
+    Request(String),
+    /// The input was downloaded but could not be saved.
+    Write(PathBuf, String),
+}
+
+impl fmt::Display for DownloadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DownloadError::NoSession => {
+                write!(
+                    f,
+                    "No session token, set {SESSION_VAR} or [download] session"
+                )
+            }
+            DownloadError::Request(message) => write!(f, "Download failed: {message}"),
+            DownloadError::Write(path, message) => {
+                write!(f, "Unable to write {}: {message}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for DownloadError {}
+
+/// Picks the session token, preferring the environment over the config file.
+///
+/// # Arguments
+/// * `config` - The contents of `aoc.toml`.
+/// * `env` - The value of `AOC_SESSION`, if set.
+pub fn session(config: &Config, env: Option<String>) -> Option<String> {
+    env.or_else(|| config.get("download", "session").map(str::to_string))
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty())
+}
+
+/// The address of the input for a day.
+pub fn url(year: u32, day: u32) -> String {
+    format!("https://adventofcode.com/{year}/day/{day}/input")
+}
+
+/// The address of the description of a day.
+pub fn puzzle_url(year: u32, day: u32) -> String {
+    format!("https://adventofcode.com/{year}/day/{day}")
+}
+
+/// Where the description of a day is kept once downloaded.
+pub fn puzzle_path(year: u32, day: u32) -> PathBuf {
+    Path::new("puzzles")
+        .join(format!("year{year}"))
+        .join(format!("day{day:02}.html"))
+}
+
+/// Downloads the input for a day.
+///
+/// # Returns
+/// * The input text, or the reason the request failed.
+pub fn fetch(year: u32, day: u32, session: &str) -> Result<String, DownloadError> {
+    get(&url(year, day), session)
+}
+
+/// Downloads the description page of a day. It only includes part two once the account of the
+/// session has solved part one.
+pub fn fetch_puzzle(year: u32, day: u32, session: &str) -> Result<String, DownloadError> {
+    get(&puzzle_url(year, day), session)
+}
+
+fn get(url: &str, session: &str) -> Result<String, DownloadError> {
+    let failed = |error: std::io::Error| DownloadError::Request(error.to_string());
+
+    let mut child = Command::new("curl")
+        .args(["--silent", "--show-error", "--fail", "--header", "@-"])
+        .args(["--user-agent", "github.com/dawidpereira/advent-of-code"])
+        .arg(url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(failed)?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        writeln!(stdin, "Cookie: session={session}").map_err(failed)?;
+    }
+
+    let output = child.wait_with_output().map_err(failed)?;
+    if !output.status.success() {
+        let message = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(DownloadError::Request(message));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Downloads the input of a solution to its expected path, creating directories as needed.
+///
+/// Does nothing when the file already exists.
+///
+/// # Returns
+/// * `true` if the input was downloaded, `false` if it was already present.
+pub fn ensure(solution: &Solution, session: Option<&str>) -> Result<bool, DownloadError> {
+    if solution.path.exists() {
+        return Ok(false);
+    }
+
+    let session = session.ok_or(DownloadError::NoSession)?;
+    let data = fetch(solution.year, solution.day, session)?;
+    let failed =
+        |error: std::io::Error| DownloadError::Write(solution.path.clone(), error.to_string());
+
+    if let Some(parent) = solution.path.parent() {
+        create_dir_all(parent).map_err(failed)?;
+    }
+    write(&solution.path, data).map_err(failed)?;
+    Ok(true)
+}
@@ -0,0 +1,32 @@
+//! Machine readable output of run results.
+//!
+//! With `--output csv` the runner prints one row per solved day instead of the usual text, so
+//! timings can be imported into a spreadsheet and compared across commits:
+//!
+//! ```none
+//!   year,day,parse_us,part1_us,part2_us,total_us
+//!   2024,1,52,9,14,75
+//!   2024,6,40,,,18302
+//! ```
+//!
+//! Days solving both parts together leave the part columns empty, as only their combined time
+//! is known.
+use crate::runner::registry::Phases;
+use std::time::Duration;
+
+pub const CSV_HEADER: &str = "year,day,parse_us,part1_us,part2_us,total_us";
+
+/// Formats the phase timings of a day as a row matching [`CSV_HEADER`].
+pub fn csv_row(year: u32, day: u32, phases: &Phases) -> String {
+    let micros = |duration: Duration| duration.as_micros().to_string();
+    let [part1, part2] = match phases.parts {
+        Some(parts) => parts.map(micros),
+        None => [String::new(), String::new()],
+    };
+
+    format!(
+        "{year},{day},{},{part1},{part2},{}",
+        micros(phases.parse),
+        micros(phases.total())
+    )
+}
@@ -0,0 +1,177 @@
+//! Standalone HTML page summarising a run, for sharing timings without extra tooling.
+//!
+//! With `--report html` the runner writes [`PAGE`] next to the usual output. The page has no
+//! external scripts, fonts or stylesheets, so it can be attached to an issue or opened offline.
+//! It shows a bar chart of the time each day took, split into parsing and solving, followed by a
+//! table with the answers, phase timings and totals. Failed days appear in the table with their
+//! error but are left out of the chart.
+use crate::runner::report::DayReport;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// File written by `--report html`.
+pub const PAGE: &str = "report.html";
+
+/// Width of the widest bar in the chart, in pixels.
+const BAR_WIDTH: u32 = 600;
+/// Height of a single bar, including the gap below it.
+const ROW_HEIGHT: u32 = 22;
+/// Space left of the bars for the day labels.
+const LABEL_WIDTH: u32 = 110;
+
+const STYLE: &str = "\
+body{font-family:sans-serif;margin:2em;color:#222}\
+table{border-collapse:collapse;margin-top:1.5em}\
+th,td{padding:4px 12px;border-bottom:1px solid #ddd;text-align:right}\
+th:first-child,td:first-child{text-align:left}\
+td.answer{font-family:monospace;white-space:pre}\
+td.error{color:#b00;text-align:left}\
+tfoot td{font-weight:bold}\
+.parse{fill:#f0a830}.solve{fill:#3a7dc9}\
+.legend span{display:inline-block;width:12px;height:12px;margin:0 4px 0 12px}";
+
+/// Renders the days of a run as a complete HTML document.
+pub fn to_html(reports: &[DayReport]) -> String {
+    let solved: Vec<&DayReport> = reports.iter().filter(|day| day.outcome.is_ok()).collect();
+    let total: Duration = solved.iter().map(|day| day.elapsed).sum();
+
+    let mut html = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+    html.push_str("<meta charset=\"utf-8\">\n<title>Advent of Code timings</title>\n");
+    html.push_str(&format!("<style>{STYLE}</style>\n</head>\n<body>\n"));
+    html.push_str("<h1>Advent of Code timings</h1>\n");
+    html.push_str(&format!(
+        "<p>Solved {} of {} days in {:.3} ms.</p>\n",
+        solved.len(),
+        reports.len(),
+        total.as_secs_f64() * 1000.0
+    ));
+
+    if !solved.is_empty() {
+        html.push_str("<p class=\"legend\"><span style=\"background:#f0a830\"></span>Parse");
+        html.push_str("<span style=\"background:#3a7dc9\"></span>Solve</p>\n");
+        html.push_str(&chart(&solved));
+    }
+    html.push_str(&table(reports, solved.len(), total));
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// Writes the page for a run to a file.
+pub fn save(path: impl AsRef<Path>, reports: &[DayReport]) -> std::io::Result<()> {
+    fs::write(path, to_html(reports))
+}
+
+/// Escapes the characters with a special meaning in HTML text and attributes.
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Horizontal bars scaled to the slowest day, each split into parse and solve time.
+fn chart(solved: &[&DayReport]) -> String {
+    let slowest = solved
+        .iter()
+        .map(|day| day.elapsed)
+        .max()
+        .unwrap_or_default();
+    let scale = |duration: Duration| {
+        if slowest.is_zero() {
+            0.0
+        } else {
+            duration.as_secs_f64() / slowest.as_secs_f64() * BAR_WIDTH as f64
+        }
+    };
+
+    let width = LABEL_WIDTH + BAR_WIDTH + 120;
+    let height = ROW_HEIGHT * solved.len() as u32;
+    let mut svg = String::new();
+    svg.push_str(&format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         font-size=\"12\">\n"
+    ));
+
+    for (row, day) in solved.iter().enumerate() {
+        let y = row as u32 * ROW_HEIGHT;
+        let parse = scale(day.phases.parse);
+        let solve = scale(day.phases.solve);
+        let end = LABEL_WIDTH as f64 + parse + solve;
+
+        svg.push_str(&format!(
+            "<text x=\"0\" y=\"{}\">{} Day {:02}</text>\n",
+            y + 14,
+            day.year,
+            day.day
+        ));
+        svg.push_str(&format!(
+            "<rect class=\"parse\" x=\"{LABEL_WIDTH}\" y=\"{y}\" width=\"{parse:.1}\" \
+             height=\"{}\"><title>Parse {} μs</title></rect>\n",
+            ROW_HEIGHT - 4,
+            day.phases.parse.as_micros()
+        ));
+        svg.push_str(&format!(
+            "<rect class=\"solve\" x=\"{:.1}\" y=\"{y}\" width=\"{solve:.1}\" \
+             height=\"{}\"><title>Solve {} μs</title></rect>\n",
+            LABEL_WIDTH as f64 + parse,
+            ROW_HEIGHT - 4,
+            day.phases.solve.as_micros()
+        ));
+        svg.push_str(&format!(
+            "<text x=\"{:.1}\" y=\"{}\">{} μs</text>\n",
+            end + 6.0,
+            y + 14,
+            day.elapsed.as_micros()
+        ));
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// One row per day with the answers or error, then the totals of the solved days.
+fn table(reports: &[DayReport], solved: usize, total: Duration) -> String {
+    let micros = |duration: Duration| duration.as_micros().to_string();
+    let mut table = String::from("<table>\n<thead><tr><th>Day</th><th>Part 1</th>");
+    table.push_str("<th>Part 2</th><th>Parse μs</th><th>Solve μs</th><th>Total μs</th>");
+    table.push_str("</tr></thead>\n<tbody>\n");
+
+    for day in reports {
+        table.push_str(&format!("<tr><td>{} Day {:02}</td>", day.year, day.day));
+        match &day.outcome {
+            Ok((part1, part2)) => {
+                table.push_str(&format!(
+                    "<td class=\"answer\">{}</td><td class=\"answer\">{}</td>\
+                     <td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                    escape(&part1.to_string()),
+                    escape(&part2.to_string()),
+                    micros(day.phases.parse),
+                    micros(day.phases.solve),
+                    micros(day.elapsed)
+                ));
+            }
+            Err(error) => {
+                table.push_str(&format!(
+                    "<td class=\"error\" colspan=\"5\">{}</td></tr>\n",
+                    escape(error)
+                ));
+            }
+        }
+    }
+
+    table.push_str(&format!(
+        "</tbody>\n<tfoot><tr><td>Solved {solved}</td><td colspan=\"4\"></td>\
+         <td>{}</td></tr></tfoot>\n</table>\n",
+        micros(total)
+    ));
+    table
+}
@@ -0,0 +1,50 @@
+//! Notifications when a run finishes, so brute force days can run in the background.
+//!
+//! With `--notify` the runner rings the terminal bell once it is done. A command can be added in
+//! `aoc.toml`, run with `sh -c` and receiving a short summary of the run as `$1`:
+//!
+//! ```toml
+//! [notify]
+//! command = "notify-send 'Advent of Code' \"$1\""
+//! ```
+use crate::util::config::Config;
+use std::io::{self, Write};
+use std::process::Command;
+use std::time::Duration;
+
+/// The ASCII bell, which most terminals turn into a sound or an urgent window hint.
+pub const BELL: &str = "\x07";
+
+/// The notification command configured in `aoc.toml`, if any.
+pub fn command(config: &Config) -> Option<&str> {
+    config.get("notify", "command")
+}
+
+/// Summarises a finished run in one line.
+pub fn message(solved: usize, mismatches: usize, duration: Duration) -> String {
+    let mut message = format!("Solved {solved} in {} ms", duration.as_millis());
+    if mismatches > 0 {
+        message.push_str(&format!(", {mismatches} wrong"));
+    }
+    message
+}
+
+/// Rings the bell on stderr, then runs the configured command and waits for it.
+///
+/// # Errors
+/// Returns an error if the command cannot be started.
+pub fn notify(config: &Config, message: &str) -> io::Result<()> {
+    let mut stderr = io::stderr();
+    stderr.write_all(BELL.as_bytes())?;
+    stderr.flush()?;
+
+    if let Some(command) = command(config) {
+        Command::new("sh")
+            .arg("-c")
+            .arg(command)
+            .arg("sh")
+            .arg(message)
+            .status()?;
+    }
+    Ok(())
+}
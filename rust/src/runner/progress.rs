@@ -0,0 +1,108 @@
+//! Live progress bar for days reporting their progress through [`progress`].
+//!
+//! While days run, [`Bar::show`] polls the reported progress from a background thread and
+//! redraws a single line on stderr. The bar only appears once a day has been reporting for
+//! [`DELAY`], so fast days do not flicker, and [`Bar::clear`] erases it before results are
+//! printed.
+//!
+//! [`progress`]: crate::util::progress
+use crate::util::ansi::*;
+use crate::util::progress;
+use std::io::{stderr, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// How long a day reports progress before the bar appears.
+pub const DELAY: Duration = Duration::from_millis(300);
+/// Time between redraws of the bar.
+const INTERVAL: Duration = Duration::from_millis(100);
+/// Number of cells in the bar.
+const WIDTH: usize = 30;
+
+/// Renders the line of the bar for `done` out of `total` pieces of work, such as
+/// `[#########.....................] 30% (1500/5000)`.
+///
+/// # Panics
+/// Panics if `total` is zero.
+pub fn bar(done: usize, total: usize) -> String {
+    let done = done.min(total);
+    let filled = done * WIDTH / total;
+    format!(
+        "[{}{}] {}% ({done}/{total})",
+        "#".repeat(filled),
+        ".".repeat(WIDTH - filled),
+        done * 100 / total
+    )
+}
+
+/// Draws the progress of running days on stderr until dropped.
+///
+/// # Fields
+/// - `drawn`: Whether the bar is on screen. Drawing and erasing hold its lock, so the bar is
+///   never redrawn halfway through [`Bar::clear`].
+/// - `stop`: Tells the drawing thread to finish.
+/// - `thread`: The drawing thread, joined on drop.
+pub struct Bar {
+    drawn: Arc<Mutex<bool>>,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Bar {
+    /// Starts drawing the bar in the background.
+    pub fn show() -> Self {
+        let drawn = Arc::new(Mutex::new(false));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let thread = {
+            let (drawn, stop) = (Arc::clone(&drawn), Arc::clone(&stop));
+            thread::spawn(move || {
+                let mut since = None;
+                while !stop.load(Ordering::Relaxed) {
+                    match progress::current() {
+                        Some((done, total)) => {
+                            let since = *since.get_or_insert_with(Instant::now);
+                            if since.elapsed() >= DELAY {
+                                let mut drawn = drawn.lock().unwrap();
+                                eprint!("\r{BOLD}{}{RESET}{CLEAR}", bar(done, total));
+                                let _ = stderr().flush();
+                                *drawn = true;
+                            }
+                        }
+                        None => since = None,
+                    }
+                    thread::park_timeout(INTERVAL);
+                }
+            })
+        };
+
+        Bar {
+            drawn,
+            stop,
+            thread: Some(thread),
+        }
+    }
+
+    /// Erases the bar if it is on screen. It comes back while a day keeps reporting progress.
+    pub fn clear(&self) {
+        let mut drawn = self.drawn.lock().unwrap();
+        if *drawn {
+            eprint!("\r{CLEAR}");
+            let _ = stderr().flush();
+            *drawn = false;
+        }
+    }
+}
+
+impl Drop for Bar {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            thread.thread().unpark();
+            let _ = thread.join();
+        }
+        self.clear();
+    }
+}
@@ -0,0 +1,346 @@
+use crate::util::hash::fnv1a;
+use crate::util::parse::*;
+use std::any::Any;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Directory of the inputs when none is configured.
+pub const INPUT_DIR: &str = "input";
+
+/// Input set of the real puzzle inputs, used unless another one is selected.
+pub const REAL_SET: &str = "real";
+/// Input set of the examples from the puzzle descriptions, selected by `--example`.
+pub const EXAMPLE_SET: &str = "example";
+
+/// Finds the file of a day in one of its input sets, below a directory of inputs or answers.
+///
+/// A day keeps its real input either flat, as `day01.txt`, or together with its other sets in a
+/// directory, as `day01/real.txt` next to `day01/sample1.txt`. Other sets can also be kept flat,
+/// as `day01-sample1.txt`. The flat file is used if it exists or if the one in the directory does
+/// not, so missing files are reported and downloaded under their flat name.
+pub fn locate(dir: &Path, day: u32, set: Option<&str>) -> PathBuf {
+    let set = set.filter(|&set| set != REAL_SET);
+    let flat = match set {
+        Some(set) => dir.join(format!("day{day:02}-{set}.txt")),
+        None => dir.join(format!("day{day:02}.txt")),
+    };
+    let nested = dir
+        .join(format!("day{day:02}"))
+        .join(format!("{}.txt", set.unwrap_or(REAL_SET)));
+
+    if flat.is_file() || !nested.is_file() {
+        flat
+    } else {
+        nested
+    }
+}
+
+/// The answer to one part of a puzzle.
+///
+/// Most puzzles answer with a number, but some spell out letters or draw a picture that has to
+/// be read by eye. Keeping the kind lets answers be compared numerically, so `042` matches `42`,
+/// while text and grids must match exactly.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /// Rows of a multi line answer, such as letters drawn with `#` and `.`.
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    /// Checks if two answers are the same, comparing text that looks like a number numerically.
+    pub fn matches(&self, other: &Answer) -> bool {
+        match (self.number(), other.number()) {
+            (Some(a), Some(b)) => a == b,
+            _ => self == other,
+        }
+    }
+
+    /// A short stable hash standing in for the answer, for output that is shared publicly.
+    ///
+    /// The hash is FNV-1a of the answer's text followed by the MurmurHash3 finaliser, so it is
+    /// the same on every run and machine, two answers that [`matches`](Answer::matches) each other
+    /// redact alike and nearby numbers look nothing alike. It hides answers from readers, not
+    /// from someone hashing every candidate number.
+    pub fn redacted(&self) -> Answer {
+        let text = match self.number() {
+            Some(n) => n.to_string(),
+            None => self.to_string(),
+        };
+        let mut hash = fnv1a(text.as_bytes());
+        hash = (hash ^ (hash >> 33)).wrapping_mul(0xff51_afd7_ed55_8ccd);
+        hash = (hash ^ (hash >> 33)).wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+        Answer::Text(format!("#{:08x}", (hash ^ (hash >> 33)) as u32))
+    }
+
+    fn number(&self) -> Option<i64> {
+        match self {
+            Answer::Number(n) => Some(*n),
+            Answer::Text(text) => text.parse().ok(),
+            Answer::Grid(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+/// Recognizes the kind of answer from its text, the inverse of `Display`.
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        let text = text.trim_end_matches('\n');
+        if text.contains('\n') {
+            Answer::Grid(text.lines().map(String::from).collect())
+        } else if let Ok(n) = text.parse() {
+            Answer::Number(n)
+        } else {
+            Answer::Text(text.to_string())
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::from(text.as_str())
+    }
+}
+
+macro_rules! from_integer {
+    ($($t:ty)*) => ($(
+        impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                match i64::try_from(n) {
+                    Ok(n) => Answer::Number(n),
+                    Err(_) => Answer::Text(n.to_string()),
+                }
+            }
+        }
+    )*)
+}
+
+from_integer!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+/// Time spent in each phase of solving a day.
+///
+/// # Fields
+/// - `parse`: Time turning the raw input into the day's `Input`.
+/// - `parts`: Time of each part, `None` for days solving both parts together.
+/// - `solve`: Time solving both parts, excluding parsing.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Phases {
+    pub parse: Duration,
+    pub parts: Option<[Duration; 2]>,
+    pub solve: Duration,
+}
+
+impl Phases {
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+/// A day's solution behind a uniform interface, so that days with different `Input` and answer
+/// types can be stored together and executed generically.
+///
+/// The parsed input travels between the phases as [`Any`], which [`solution!`] downcasts back to
+/// the day's own `Input`, so parsing happens once however the parts are run. The provided
+/// [`solve`](Solver::solve) and [`solve_part`](Solver::solve_part) time and run the phases on top
+/// of the required methods.
+pub trait Solver: Sync {
+    /// Turns the raw input into the day's `Input`.
+    fn parse(&self, data: &str) -> Box<dyn Any>;
+
+    fn part1(&self, input: &dyn Any) -> Answer;
+
+    fn part2(&self, input: &dyn Any) -> Answer;
+
+    /// Both answers at once, for puzzles where the parts share expensive work. `None` solves the
+    /// parts one after the other.
+    fn solve_both(&self, _input: &dyn Any) -> Option<(Answer, Answer)> {
+        None
+    }
+
+    /// The title of the puzzle, if known.
+    fn name(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Parses the input and solves both parts, timing each phase.
+    fn solve(&self, data: &str) -> ((Answer, Answer), Phases) {
+        let instant = Instant::now();
+        let input = self.parse(data);
+        let parse = instant.elapsed();
+
+        let instant = Instant::now();
+        let (answers, parts) = match self.solve_both(input.as_ref()) {
+            Some(answers) => (answers, None),
+            None => {
+                let first = self.part1(input.as_ref());
+                let first_elapsed = instant.elapsed();
+
+                let second_instant = Instant::now();
+                let second = self.part2(input.as_ref());
+                (
+                    (first, second),
+                    Some([first_elapsed, second_instant.elapsed()]),
+                )
+            }
+        };
+        let phases = Phases {
+            parse,
+            parts,
+            solve: instant.elapsed(),
+        };
+
+        (answers, phases)
+    }
+
+    /// Parses the input and solves a single part, skipping the other.
+    ///
+    /// # Panics
+    /// Panics if `part` is not `1` or `2`.
+    fn solve_part(&self, part: u32, data: &str) -> Answer {
+        let input = self.parse(data);
+        match part {
+            1 => self.part1(input.as_ref()),
+            2 => self.part2(input.as_ref()),
+            _ => panic!("Invalid part {part}"),
+        }
+    }
+}
+
+/// Recovers a day's `Input` from the [`Any`] its [`Solver`] passes around. The day's `parse`
+/// only names the type, so [`solution!`] does not have to spell it out.
+///
+/// # Panics
+/// Panics if the input was parsed by another day.
+pub fn input<I: 'static>(_parse: fn(&str) -> I, input: &dyn Any) -> &I {
+    input
+        .downcast_ref()
+        .expect("Input parsed by a different solution")
+}
+
+/// A single registered puzzle solution.
+///
+/// # Fields
+/// - `year`, `day`: Which puzzle the solution is for.
+/// - `path`: Where the input of the day is read from.
+/// - `solver`: Parses the input and answers both parts, see [`Solver`].
+pub struct Solution {
+    pub year: u32,
+    pub day: u32,
+    pub path: PathBuf,
+    pub solver: &'static dyn Solver,
+}
+
+impl Solution {
+    /// The title of the puzzle, if registered with one.
+    pub fn name(&self) -> Option<&'static str> {
+        self.solver.name()
+    }
+}
+
+/// Registers a day as a [`Solver`] calling the day's `parse`, `part1` and `part2`. The `both`
+/// form calls the day's `solve_both` instead of `part1` and `part2`, for puzzles where the
+/// parts share expensive work. Either form can end with the title of the puzzle.
+macro_rules! solution {
+    ($year:tt, $day:tt, both $(, $name:literal)?) => {
+        solution!(@ $year, $day, {
+            fn solve_both(&self, any: &dyn Any) -> Option<(Answer, Answer)> {
+                use crate::$year::$day::*;
+                let input = input(parse, any);
+                let (first, second) = solve_both(input);
+                Some((Answer::from(first), Answer::from(second)))
+            }
+        } $(, $name)?)
+    };
+    ($year:tt, $day:tt $(, $name:literal)?) => {
+        solution!(@ $year, $day, {} $(, $name)?)
+    };
+    (@ $year:tt, $day:tt, { $($both:tt)* } $(, $name:literal)?) => {{
+        struct Day;
+
+        impl Solver for Day {
+            fn parse(&self, data: &str) -> Box<dyn Any> {
+                use crate::$year::$day::*;
+                Box::new(parse(data))
+            }
+
+            fn part1(&self, any: &dyn Any) -> Answer {
+                use crate::$year::$day::*;
+                let input = input(parse, any);
+                Answer::from(part1(input))
+            }
+
+            fn part2(&self, any: &dyn Any) -> Answer {
+                use crate::$year::$day::*;
+                let input = input(parse, any);
+                Answer::from(part2(input))
+            }
+
+            $($both)*
+
+            $(
+                fn name(&self) -> Option<&'static str> {
+                    Some($name)
+                }
+            )?
+        }
+
+        Solution {
+            year: stringify!($year).unsigned(),
+            day: stringify!($day).unsigned(),
+            path: PathBuf::new(),
+            solver: &Day,
+        }
+    }};
+}
+
+/// Returns every registered solution, ordered by year and day, reading the real inputs from
+/// [`INPUT_DIR`].
+pub fn solutions() -> Vec<Solution> {
+    solutions_in(Path::new(INPUT_DIR), None)
+}
+
+/// Returns every registered solution, ordered by year and day, reading an input set from a
+/// directory of inputs.
+///
+/// # Arguments
+/// * `dir` - The directory holding the `yearYYYY/dayDD.txt` inputs.
+/// * `set` - A named set of inputs such as `sample1`, `None` for the real inputs.
+pub fn solutions_in(dir: &Path, set: Option<&str>) -> Vec<Solution> {
+    let mut solutions = registered();
+    for solution in &mut solutions {
+        let year = dir.join(format!("year{}", solution.year));
+        solution.path = locate(&year, solution.day, set);
+    }
+    solutions
+}
+
+fn registered() -> Vec<Solution> {
+    [year2024()].into_iter().flatten().collect()
+}
+
+fn year2024() -> Vec<Solution> {
+    vec![
+        solution!(year2024, day01, "Historian Hysteria"),
+        solution!(year2024, day02, "Red-Nosed Reports"),
+        solution!(year2024, day03, "Mull It Over"),
+        solution!(year2024, day04, "Ceres Search"),
+        solution!(year2024, day05, "Print Queue"),
+        solution!(year2024, day06, both, "Guard Gallivant"),
+        solution!(year2024, day07, "Bridge Repair"),
+        solution!(year2024, day08, "Resonant Collinearity"),
+        solution!(year2024, day09, "Disk Fragmenter"),
+        solution!(year2024, day10, "Hoof It"),
+    ]
+}
@@ -0,0 +1,224 @@
+//! Saved runs that can be replayed without solving again.
+//!
+//! A report is a JSON document with one entry per day, holding the answers or error, the elapsed
+//! time and any visualisation frames recorded through [`debugger::tick`](crate::util::debugger):
+//!
+//! ```none
+//!   {"days":[{"year":2024,"day":6,"part1":"41","part2":"6","elapsed_us":1830,
+//!             "parse_us":40,"solve_us":1790,
+//!             "frames":[{"label":"(4, 6) facing Up","image":"....#....."}]}]}
+//! ```
+//!
+//! Days timing each part on its own also store `part1_us` and `part2_us`. Failed days store an
+//! `error` message instead of the two answers.
+use crate::runner::registry::{Answer, Phases};
+use crate::runner::run::DayResult;
+use crate::util::debugger::Frame;
+use crate::util::json::{Json, JsonError};
+use crate::util::viz::focus;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// A day as it appears in a report.
+///
+/// # Fields
+/// - `elapsed`: Time the original run took.
+/// - `phases`: The same time split into parsing and solving.
+/// - `outcome`: The answers to both parts, or the message of the error that stopped the day.
+/// - `frames`: Visualisation frames in the order they were rendered.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DayReport {
+    pub year: u32,
+    pub day: u32,
+    pub elapsed: Duration,
+    pub phases: Phases,
+    pub outcome: Result<(Answer, Answer), String>,
+    pub frames: Vec<Frame>,
+}
+
+impl DayReport {
+    pub fn new(result: &DayResult, frames: Vec<Frame>) -> Self {
+        DayReport {
+            year: result.year,
+            day: result.day,
+            elapsed: result.elapsed,
+            phases: result.phases,
+            outcome: result.outcome.clone().map_err(|error| error.to_string()),
+            frames,
+        }
+    }
+
+    /// A copy with both answers replaced by their [`redacted`](Answer::redacted) hashes.
+    pub fn redacted(&self) -> Self {
+        DayReport {
+            outcome: self
+                .outcome
+                .clone()
+                .map(|(part1, part2)| (part1.redacted(), part2.redacted())),
+            ..self.clone()
+        }
+    }
+}
+
+/// Crops the images of recorded frames to the area that changes, see [`focus`].
+pub fn focused(mut frames: Vec<Frame>, margin: i32) -> Vec<Frame> {
+    let images: Vec<String> = frames.iter().map(|frame| frame.image.clone()).collect();
+    for (frame, image) in frames.iter_mut().zip(focus(&images, margin)) {
+        frame.image = image;
+    }
+    frames
+}
+
+/// Reasons a report could not be read.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ReportError {
+    /// The file could not be read.
+    Io(String),
+    /// The file is not valid JSON.
+    Json(JsonError),
+    /// The JSON is missing a field or has one of the wrong type.
+    Invalid(&'static str),
+}
+
+impl fmt::Display for ReportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReportError::Io(error) => write!(f, "Unable to read report: {error}"),
+            ReportError::Json(error) => write!(f, "Invalid report: {error}"),
+            ReportError::Invalid(field) => write!(f, "Invalid report: bad or missing {field}"),
+        }
+    }
+}
+
+impl std::error::Error for ReportError {}
+
+/// Serialises the reports of a run.
+pub fn to_json(reports: &[DayReport]) -> String {
+    let days = reports
+        .iter()
+        .map(|report| {
+            let mut entries = vec![
+                ("year".to_string(), Json::from(report.year)),
+                ("day".to_string(), Json::from(report.day)),
+            ];
+            match &report.outcome {
+                Ok((part1, part2)) => {
+                    entries.push(("part1".to_string(), Json::from(part1.to_string())));
+                    entries.push(("part2".to_string(), Json::from(part2.to_string())));
+                }
+                Err(error) => entries.push(("error".to_string(), Json::from(error.as_str()))),
+            }
+            let micros = |duration: Duration| Json::from(duration.as_micros() as u64);
+            entries.push(("elapsed_us".to_string(), micros(report.elapsed)));
+            entries.push(("parse_us".to_string(), micros(report.phases.parse)));
+            entries.push(("solve_us".to_string(), micros(report.phases.solve)));
+            if let Some([part1, part2]) = report.phases.parts {
+                entries.push(("part1_us".to_string(), micros(part1)));
+                entries.push(("part2_us".to_string(), micros(part2)));
+            }
+
+            let frames = report
+                .frames
+                .iter()
+                .map(|frame| {
+                    Json::Object(vec![
+                        ("label".to_string(), Json::from(frame.label.as_str())),
+                        ("image".to_string(), Json::from(frame.image.as_str())),
+                    ])
+                })
+                .collect();
+            entries.push(("frames".to_string(), Json::Array(frames)));
+
+            Json::Object(entries)
+        })
+        .collect();
+
+    Json::Object(vec![("days".to_string(), Json::Array(days))]).to_string()
+}
+
+/// Reads the reports written by [`to_json`].
+pub fn from_json(text: &str) -> Result<Vec<DayReport>, ReportError> {
+    let json = Json::parse(text).map_err(ReportError::Json)?;
+    let days = json
+        .get("days")
+        .and_then(Json::as_array)
+        .ok_or(ReportError::Invalid("days"))?;
+
+    days.iter().map(day).collect()
+}
+
+/// Writes a report to a file.
+pub fn save(path: impl AsRef<Path>, reports: &[DayReport]) -> std::io::Result<()> {
+    fs::write(path, to_json(reports))
+}
+
+/// Reads a report from a file.
+pub fn load(path: impl AsRef<Path>) -> Result<Vec<DayReport>, ReportError> {
+    let text = fs::read_to_string(path).map_err(|error| ReportError::Io(error.to_string()))?;
+    from_json(&text)
+}
+
+fn day(json: &Json) -> Result<DayReport, ReportError> {
+    let number = |field| {
+        json.get(field)
+            .and_then(Json::as_u64)
+            .ok_or(ReportError::Invalid(field))
+    };
+    let text = |value: &Json, field| {
+        value
+            .get(field)
+            .and_then(Json::as_str)
+            .map(str::to_string)
+            .ok_or(ReportError::Invalid(field))
+    };
+
+    let outcome = match json.get("error") {
+        Some(error) => Err(error
+            .as_str()
+            .ok_or(ReportError::Invalid("error"))?
+            .to_string()),
+        None => Ok((
+            Answer::from(text(json, "part1")?),
+            Answer::from(text(json, "part2")?),
+        )),
+    };
+    let frames = match json.get("frames") {
+        Some(frames) => frames
+            .as_array()
+            .ok_or(ReportError::Invalid("frames"))?
+            .iter()
+            .map(|frame| {
+                Ok(Frame {
+                    label: text(frame, "label")?,
+                    image: text(frame, "image")?,
+                })
+            })
+            .collect::<Result<_, _>>()?,
+        None => Vec::new(),
+    };
+
+    // Unlike the total, phase timings may be left out.
+    let micros = |field| {
+        json.get(field)
+            .and_then(Json::as_u64)
+            .map(Duration::from_micros)
+    };
+    let phases = Phases {
+        parse: micros("parse_us").unwrap_or_default(),
+        parts: micros("part1_us")
+            .zip(micros("part2_us"))
+            .map(|(a, b)| [a, b]),
+        solve: micros("solve_us").unwrap_or_default(),
+    };
+
+    Ok(DayReport {
+        year: number("year")? as u32,
+        day: number("day")? as u32,
+        elapsed: Duration::from_micros(number("elapsed_us")?),
+        phases,
+        outcome,
+        frames,
+    })
+}
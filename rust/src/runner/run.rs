@@ -0,0 +1,526 @@
+use crate::runner::cache::{Cache, Entry};
+use crate::runner::memory::measure;
+use crate::runner::registry::{solutions, solutions_in, Answer, Phases, Solution, INPUT_DIR};
+use crate::util::hash::fnv1a;
+use crate::util::progress;
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::read_to_string;
+use std::panic::{self, catch_unwind, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::sync::{mpsc, Arc, Mutex, Once};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Selects which solutions to run. `None` matches every year or day.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Filter {
+    pub year: Option<u32>,
+    pub day: Option<u32>,
+}
+
+impl Filter {
+    pub fn new(year: Option<u32>, day: Option<u32>) -> Self {
+        Self { year, day }
+    }
+
+    /// Checks if the solution is selected by this filter.
+    pub fn matches(&self, solution: &Solution) -> bool {
+        self.year.is_none_or(|y| y == solution.year) && self.day.is_none_or(|d| d == solution.day)
+    }
+}
+
+/// Exit code of `--strict` runs with answers that differ from the known ones.
+pub const EXIT_MISMATCH: i32 = 1;
+/// Exit code of `--strict` runs asking for a day without a solution.
+pub const EXIT_NOT_FOUND: i32 = 2;
+/// Exit code of `--strict` runs missing the input of a day.
+pub const EXIT_MISSING_INPUT: i32 = 3;
+/// Exit code of `--strict` runs where a solution panicked.
+pub const EXIT_PANICKED: i32 = 4;
+/// Exit code of `--strict` runs where a solution ran out of time.
+pub const EXIT_TIMED_OUT: i32 = 5;
+
+static QUIET_HOOK: Once = Once::new();
+
+thread_local! {
+    /// Set while this thread runs a solution under [`supervise`].
+    static SUPERVISED: Cell<bool> = const { Cell::new(false) };
+    /// Where the last supervised panic on this thread happened.
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// How days are run and where their inputs come from.
+///
+/// Every function running days takes its options explicitly, so concurrent runs, such as the
+/// requests of [`serve`](crate::runner::serve) or the workers of [`iter_parallel`], never see
+/// each other's settings.
+///
+/// # Fields
+/// - `timeout`: How long each solution may run, `None` to wait as long as it takes.
+/// - `runs`: How many times each day runs, reporting the fastest run. Zero is treated as one.
+///   Repeating smooths out noise from the OS, making totals comparable between changes. Unlike
+///   [`bench`](crate::runner::timing::bench), the answers are still returned.
+/// - `cache`: Answers reused for days whose input is unchanged, which also receives the answers
+///   of the days that are solved. `None` solves every day. Only days reading their input from
+///   `input_dir` are cached, not those solved with [`solve_with`].
+/// - `input_dir`: The directory holding the `yearYYYY/dayDD.txt` inputs.
+/// - `input_set`: A named set of inputs such as `sample1`, checked against the answers known
+///   for that set. `None` reads the real inputs.
+#[derive(Clone, Debug)]
+pub struct RunOptions {
+    pub timeout: Option<Duration>,
+    pub runs: u32,
+    pub cache: Option<Arc<Mutex<Cache>>>,
+    pub input_dir: PathBuf,
+    pub input_set: Option<String>,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        RunOptions {
+            timeout: None,
+            runs: 1,
+            cache: None,
+            input_dir: PathBuf::from(INPUT_DIR),
+            input_set: None,
+        }
+    }
+}
+
+impl RunOptions {
+    /// Every registered solution, reading the selected input set from the input directory.
+    pub fn solutions(&self) -> Vec<Solution> {
+        solutions_in(&self.input_dir, self.input_set.as_deref())
+    }
+}
+
+/// Reasons a day could not produce answers.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum RunError {
+    /// The input file for the day does not exist or could not be read.
+    MissingInput(PathBuf),
+    /// No solution is registered for the requested year and day.
+    NotFound { year: u32, day: u32 },
+    /// The solution panicked, with the panic message if it was a string and where it happened.
+    Panicked(String),
+    /// The solution did not finish within the [`RunOptions::timeout`].
+    TimedOut(Duration),
+}
+
+impl RunError {
+    /// The exit code of a `--strict` run failing with this error.
+    ///
+    /// When several days fail, the run exits with the highest code, so timeouts take precedence
+    /// over panics, then missing inputs and finally wrong answers.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            RunError::MissingInput(_) => EXIT_MISSING_INPUT,
+            RunError::NotFound { .. } => EXIT_NOT_FOUND,
+            RunError::Panicked(_) => EXIT_PANICKED,
+            RunError::TimedOut(_) => EXIT_TIMED_OUT,
+        }
+    }
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::MissingInput(path) => write!(f, "Missing input {}", path.display()),
+            RunError::NotFound { year, day } => write!(f, "No solution for {year} Day {day:02}"),
+            RunError::Panicked(message) => write!(f, "Panicked: {message}"),
+            RunError::TimedOut(limit) => write!(f, "Timed out after {} ms", limit.as_millis()),
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
+
+/// The outcome of running a single day.
+///
+/// # Fields
+/// - `year`, `day`: Identify the solution.
+/// - `elapsed`: Time spent parsing and solving both parts. Zero when the day did not run.
+/// - `phases`: The same time split into parsing and each part. Zero when the day did not run.
+/// - `outcome`: The answers to both parts, or the reason they are missing.
+/// - `samples`: The elapsed time of each of the [`RunOptions::runs`]. `elapsed` and `phases`
+///   belong to the fastest of them. Empty when the day did not run.
+/// - `peak_memory`: Most heap in bytes the fastest run used at once, see
+///   [`memory`](crate::runner::memory). `None` without the `memory` feature, in parallel runs
+///   and when the day did not run.
+/// - `cached`: Whether the answers and timings were taken from the [`RunOptions::cache`] instead
+///   of solving the day.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DayResult {
+    pub year: u32,
+    pub day: u32,
+    pub elapsed: Duration,
+    pub phases: Phases,
+    pub outcome: Result<(Answer, Answer), RunError>,
+    pub samples: Vec<Duration>,
+    pub peak_memory: Option<usize>,
+    pub cached: bool,
+}
+
+/// The outcome of running a single part of a day.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PartResult {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub elapsed: Duration,
+    pub outcome: Result<Answer, RunError>,
+}
+
+/// Runs every solution selected by the filter, reading the inputs the options point to.
+///
+/// Results are returned in registry order, including days that failed to run.
+pub fn run(filter: &Filter, options: &RunOptions) -> Vec<DayResult> {
+    iter(filter, options).collect()
+}
+
+/// Lazily runs every solution selected by the filter.
+///
+/// Useful for frontends that want to report each day as soon as it finishes.
+pub fn iter<'a>(
+    filter: &'a Filter,
+    options: &'a RunOptions,
+) -> impl Iterator<Item = DayResult> + 'a {
+    options
+        .solutions()
+        .into_iter()
+        .filter(|solution| filter.matches(solution))
+        .map(|solution| load_and_execute(&solution, options))
+}
+
+/// Runs every solution selected by the filter on a pool of worker threads.
+///
+/// Results are yielded in registry order, each as soon as it and every earlier day have
+/// finished, so output looks the same as [`iter`] but arrives sooner. `elapsed` still measures
+/// each day on its own, so their sum is the CPU time rather than the wall clock time.
+///
+/// # Arguments
+/// * `filter` - Selects the solutions to run.
+/// * `threads` - Number of worker threads, at least one.
+/// * `options` - How to run each day, shared by every worker.
+pub fn iter_parallel(
+    filter: &Filter,
+    threads: usize,
+    options: &RunOptions,
+) -> impl Iterator<Item = DayResult> {
+    let selected: Vec<Solution> = options
+        .solutions()
+        .into_iter()
+        .filter(|solution| filter.matches(solution))
+        .collect();
+    let workers = threads.clamp(1, selected.len().max(1));
+    let queue = Arc::new(Mutex::new(selected.into_iter().enumerate()));
+    let (sender, receiver) = mpsc::channel();
+
+    for _ in 0..workers {
+        let queue = Arc::clone(&queue);
+        let sender = sender.clone();
+        let options = options.clone();
+
+        thread::spawn(move || loop {
+            let next = queue.lock().unwrap().next();
+            let Some((index, solution)) = next else {
+                break;
+            };
+            // Allocations of concurrent days add up, so their peaks are meaningless
+            let result = DayResult {
+                peak_memory: None,
+                ..load_and_execute(&solution, &options)
+            };
+            if sender.send((index, result)).is_err() {
+                break;
+            }
+        });
+    }
+    drop(sender);
+
+    // Hold back results that finish before an earlier day.
+    let mut pending = BTreeMap::new();
+    let mut next = 0;
+    std::iter::from_fn(move || loop {
+        if let Some(result) = pending.remove(&next) {
+            next += 1;
+            return Some(result);
+        }
+        let (index, result) = receiver.recv().ok()?;
+        pending.insert(index, result);
+    })
+}
+
+/// Lazily runs only one part of every solution selected by the filter.
+///
+/// # Panics
+/// Panics if `part` is not `1` or `2`.
+pub fn iter_part<'a>(
+    filter: &'a Filter,
+    part: u32,
+    options: &'a RunOptions,
+) -> impl Iterator<Item = PartResult> + 'a {
+    assert!(part == 1 || part == 2, "Invalid part {part}");
+
+    options
+        .solutions()
+        .into_iter()
+        .filter(|solution| filter.matches(solution))
+        .map(move |solution| {
+            let (elapsed, outcome) = match read_to_string(&solution.path) {
+                Ok(data) => {
+                    let instant = Instant::now();
+                    let solver = solution.solver;
+                    let answer = guarded(options.timeout, move || solver.solve_part(part, &data));
+                    (instant.elapsed(), answer)
+                }
+                Err(_) => (
+                    Duration::ZERO,
+                    Err(RunError::MissingInput(solution.path.clone())),
+                ),
+            };
+
+            PartResult {
+                year: solution.year,
+                day: solution.day,
+                part,
+                elapsed,
+                outcome,
+            }
+        })
+}
+
+/// Solves a single part of a day with the given input instead of reading it from disk, with the
+/// default [`RunOptions`].
+///
+/// # Panics
+/// Panics if `part` is not `1` or `2`.
+pub fn solve_part(year: u32, day: u32, part: u32, input: String) -> Result<Answer, RunError> {
+    solve_part_with(year, day, part, input, &RunOptions::default())
+}
+
+/// Solves a single part of a day with the given input, limited by the timeout of the options.
+///
+/// # Panics
+/// Panics if `part` is not `1` or `2`.
+pub fn solve_part_with(
+    year: u32,
+    day: u32,
+    part: u32,
+    input: String,
+    options: &RunOptions,
+) -> Result<Answer, RunError> {
+    assert!(part == 1 || part == 2, "Invalid part {part}");
+
+    solutions()
+        .iter()
+        .find(|solution| solution.year == year && solution.day == day)
+        .ok_or(RunError::NotFound { year, day })
+        .and_then(|solution| {
+            let solver = solution.solver;
+            guarded(options.timeout, move || solver.solve_part(part, &input))
+        })
+}
+
+/// Solves a single day with the given input instead of reading it from disk, with the default
+/// [`RunOptions`].
+pub fn solve(year: u32, day: u32, input: String) -> DayResult {
+    solve_with(year, day, input, &RunOptions::default())
+}
+
+/// Solves a single day with the given input, timed out and repeated as the options say. The
+/// input is neither looked up in nor added to the cache.
+pub fn solve_with(year: u32, day: u32, input: String, options: &RunOptions) -> DayResult {
+    match solutions()
+        .iter()
+        .find(|solution| solution.year == year && solution.day == day)
+    {
+        Some(solution) => execute(solution, input, options),
+        None => DayResult {
+            year,
+            day,
+            elapsed: Duration::ZERO,
+            phases: Phases::default(),
+            outcome: Err(RunError::NotFound { year, day }),
+            samples: Vec::new(),
+            peak_memory: None,
+            cached: false,
+        },
+    }
+}
+
+fn load_and_execute(solution: &Solution, options: &RunOptions) -> DayResult {
+    let Ok(data) = read_to_string(&solution.path) else {
+        return failed(solution, RunError::MissingInput(solution.path.clone()));
+    };
+    let (year, day) = (solution.year, solution.day);
+    let Some(cache) = &options.cache else {
+        return execute(solution, data, options);
+    };
+
+    let input = fnv1a(data.as_bytes());
+    let entry = cache.lock().unwrap().get(year, day, input);
+    if let Some(Entry {
+        answers,
+        elapsed,
+        phases,
+    }) = entry
+    {
+        return DayResult {
+            year,
+            day,
+            elapsed,
+            phases,
+            outcome: Ok(answers),
+            samples: Vec::new(),
+            peak_memory: None,
+            cached: true,
+        };
+    }
+
+    let result = execute(solution, data, options);
+    if let Ok(answers) = &result.outcome {
+        let entry = Entry {
+            answers: answers.clone(),
+            elapsed: result.elapsed,
+            phases: result.phases,
+        };
+        cache.lock().unwrap().insert(year, day, input, &entry);
+    }
+    result
+}
+
+fn execute(solution: &Solution, data: String, options: &RunOptions) -> DayResult {
+    let runs = options.runs.max(1);
+    let mut fastest = None;
+    let mut samples = Vec::with_capacity(runs as usize);
+
+    for _ in 0..runs {
+        let (solver, data) = (solution.solver, data.clone());
+        let instant = Instant::now();
+        let (outcome, peak) = measure(|| guarded(options.timeout, move || solver.solve(&data)));
+        let elapsed = instant.elapsed();
+
+        match outcome {
+            Ok(run) => {
+                if samples.iter().all(|&sample| elapsed < sample) {
+                    fastest = Some((elapsed, run, peak));
+                }
+                samples.push(elapsed);
+            }
+            Err(error) => return failed(solution, error),
+        }
+    }
+
+    let (elapsed, (answers, phases), peak_memory) = fastest.expect("At least one run");
+    DayResult {
+        year: solution.year,
+        day: solution.day,
+        elapsed,
+        phases,
+        outcome: Ok(answers),
+        samples,
+        peak_memory,
+        cached: false,
+    }
+}
+
+/// Runs part of a solution, see [`supervise`].
+///
+/// Any [`progress`] the solution announced is withdrawn once it returns.
+pub fn guarded<T, F>(timeout: Option<Duration>, solve: F) -> Result<T, RunError>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let result = supervise(timeout, solve);
+    progress::finish();
+    result
+}
+
+/// Runs part of a solution, turning a panic into [`RunError::Panicked`] so the remaining days
+/// still run.
+///
+/// With a timeout, the solution runs on a worker thread and gives up with
+/// [`RunError::TimedOut`] once the limit passes. Threads cannot be stopped from the outside, so
+/// the worker keeps running in the background until it finishes or the process exits.
+///
+/// The usual panic output is left out so it does not interrupt the report of the run, with the
+/// location added to the message instead. Set `RUST_BACKTRACE` to see the full output, including
+/// the backtrace.
+pub fn supervise<T, F>(timeout: Option<Duration>, solve: F) -> Result<T, RunError>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    quiet_hook();
+    let catch = |solve: F| {
+        SUPERVISED.with(|supervised| supervised.set(true));
+        let result = catch_unwind(AssertUnwindSafe(solve));
+        SUPERVISED.with(|supervised| supervised.set(false));
+
+        result.map_err(|payload| {
+            let message = message(&*payload);
+            match LOCATION.with(|location| location.borrow_mut().take()) {
+                Some(location) => RunError::Panicked(format!("{message} at {location}")),
+                None => RunError::Panicked(message),
+            }
+        })
+    };
+    let Some(timeout) = timeout else {
+        return catch(solve);
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || sender.send(catch(solve)));
+    receiver
+        .recv_timeout(timeout)
+        .unwrap_or(Err(RunError::TimedOut(timeout)))
+}
+
+/// Installs a panic hook that records the location of supervised panics instead of printing
+/// them, leaving every other panic to the previous hook.
+fn quiet_hook() {
+    QUIET_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        let verbose =
+            std::env::var("RUST_BACKTRACE").is_ok_and(|value| !matches!(&*value, "" | "0"));
+
+        panic::set_hook(Box::new(move |info| {
+            if SUPERVISED.with(Cell::get) {
+                let location = info.location().map(ToString::to_string);
+                LOCATION.with(|recorded| *recorded.borrow_mut() = location);
+                if !verbose {
+                    return;
+                }
+            }
+            previous(info);
+        }));
+    });
+}
+
+fn message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+fn failed(solution: &Solution, error: RunError) -> DayResult {
+    DayResult {
+        year: solution.year,
+        day: solution.day,
+        elapsed: Duration::ZERO,
+        phases: Phases::default(),
+        outcome: Err(error),
+        samples: Vec::new(),
+        peak_memory: None,
+        cached: false,
+    }
+}
@@ -0,0 +1,116 @@
+//! Examples extracted from puzzle descriptions.
+//!
+//! Descriptions show their examples in `<pre><code>` blocks, and each part ends by stating the
+//! answer for an example as emphasised code, such as `<code><em>41</em></code>`. [`samples`]
+//! and [`example_answers`] pull these out of a page fetched with
+//! [`fetch_puzzle`](crate::runner::download::fetch_puzzle), and [`save`] stores them as the
+//! input sets `sample1`, `sample2` and so on, ready for `--input-set sample1`.
+//!
+//! Both are heuristics. Some puzzles show several examples, or a different one for part two, so
+//! the answers are only stored for the first sample and are worth a look before trusting them.
+use crate::runner::registry::locate;
+use std::fs::{create_dir_all, write};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The contents of every `<pre><code>` block of a page, in page order and without repeats.
+pub fn samples(html: &str) -> Vec<String> {
+    let mut samples: Vec<String> = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<pre><code>") {
+        rest = &rest[start + "<pre><code>".len()..];
+        let Some(end) = rest.find("</code></pre>") else {
+            break;
+        };
+        let sample = unescape(&strip_tags(&rest[..end]));
+        if !sample.trim().is_empty() && !samples.contains(&sample) {
+            samples.push(sample);
+        }
+        rest = &rest[end..];
+    }
+
+    samples
+}
+
+/// The answer stated at the end of each part shown on a page, the last emphasised code of its
+/// `<article>`.
+pub fn example_answers(html: &str) -> Vec<String> {
+    html.split("<article")
+        .skip(1)
+        .filter_map(|article| {
+            let article = article.split("</article>").next().unwrap_or_default();
+            let last = |open: &str, close: &str| {
+                let start = article.rfind(open)? + open.len();
+                let end = article[start..].find(close)? + start;
+                Some((start, &article[start..end]))
+            };
+            let (_, answer) = [
+                last("<code><em>", "</em></code>"),
+                last("<em><code>", "</code></em>"),
+            ]
+            .into_iter()
+            .flatten()
+            .max()?;
+            Some(unescape(&strip_tags(answer)))
+        })
+        .collect()
+}
+
+/// Writes the samples of a page for one day, keeping any files that already exist.
+///
+/// Samples go to `dayDD/sampleN.txt` below `inputs`. When the page states answers for both
+/// parts, they go to `dayDD/sample1.txt` below `answers`.
+///
+/// # Returns
+/// * The paths of the files written.
+pub fn save(html: &str, inputs: &Path, answers: &Path, day: u32) -> io::Result<Vec<PathBuf>> {
+    let mut files: Vec<(&Path, String, String)> = samples(html)
+        .into_iter()
+        .enumerate()
+        .map(|(index, sample)| (inputs, format!("sample{}", index + 1), sample))
+        .collect();
+    if let [part1, part2, ..] = &example_answers(html)[..] {
+        if !files.is_empty() {
+            let text = format!("Part 1: {part1}\nPart 2: {part2}\n");
+            files.push((answers, "sample1".to_string(), text));
+        }
+    }
+
+    let mut written = Vec::new();
+    for (dir, set, text) in files {
+        // Keep samples extracted before, which may have been corrected by hand
+        if locate(dir, day, Some(&set)).is_file() {
+            continue;
+        }
+        let path = dir.join(format!("day{day:02}")).join(format!("{set}.txt"));
+        create_dir_all(dir.join(format!("day{day:02}")))?;
+        write(&path, text)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/// Removes HTML tags, keeping the text between them.
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut inside = false;
+    for c in html.chars() {
+        match c {
+            '<' => inside = true,
+            '>' if inside => inside = false,
+            c if !inside => text.push(c),
+            _ => (),
+        }
+    }
+    text
+}
+
+/// Replaces the entities puzzle pages use with the characters they stand for.
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
@@ -0,0 +1,293 @@
+//! Generates the files and registrations for a new day.
+//!
+//! Wiring a day by hand touches four files: the solution itself, its test, the module list in
+//! `src/lib.rs` and the registry. [`scaffold`] creates the first two from stubs that compile and
+//! pass, then inserts the module, test module and `solution!` entry into the existing lists,
+//! keeping them sorted. A year seen for the first time gets its own module block and registry
+//! function.
+//!
+//! All paths are relative to the crate root, which is the working directory of the runner.
+use std::fmt;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::path::{Path, PathBuf};
+
+/// Reasons a day could not be scaffolded.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ScaffoldError {
+    /// The solution file or one of the registrations already exists.
+    AlreadyExists(String),
+    /// A file could not be read or written.
+    Io(PathBuf, String),
+    /// A file to update does not have the expected structure.
+    Unrecognized(PathBuf, String),
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::AlreadyExists(what) => write!(f, "{what} already exists"),
+            ScaffoldError::Io(path, message) => write!(f, "{}: {message}", path.display()),
+            ScaffoldError::Unrecognized(path, message) => {
+                write!(f, "{}: {message}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+/// Creates and registers a new day below `root`.
+///
+/// Nothing is written unless every file can be updated.
+///
+/// # Returns
+/// * The paths of the created and modified files.
+pub fn scaffold(root: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let (module, test) = (format!("year{year}"), format!("day{day:02}"));
+    let source = root
+        .join("src")
+        .join(&module)
+        .join(&test)
+        .with_extension("rs");
+    let test_file = root
+        .join("tests")
+        .join(&module)
+        .join(format!("{test}_test.rs"));
+
+    for path in [&source, &test_file] {
+        if path.exists() {
+            return Err(ScaffoldError::AlreadyExists(path.display().to_string()));
+        }
+    }
+
+    let lib = root.join("src/lib.rs");
+    let tests = root.join("tests/test.rs");
+    let registry = root.join("src/runner/registry.rs");
+
+    let updates = [
+        (
+            &lib,
+            register_module(&read(&lib)?, year, day).map_err(|e| e.at(&lib))?,
+        ),
+        (
+            &tests,
+            register_test(&read(&tests)?, year, day).map_err(|e| e.at(&tests))?,
+        ),
+        (
+            &registry,
+            register_solution(&read(&registry)?, year, day).map_err(|e| e.at(&registry))?,
+        ),
+    ];
+
+    let mut written = Vec::new();
+    for (path, contents) in [
+        (&source, day_source()),
+        (&test_file, test_source(year, day)),
+    ] {
+        if let Some(parent) = path.parent() {
+            create_dir_all(parent)
+                .map_err(|e| ScaffoldError::Io(parent.to_path_buf(), e.to_string()))?;
+        }
+        write(path, contents).map_err(|e| ScaffoldError::Io(path.clone(), e.to_string()))?;
+        written.push(path.clone());
+    }
+    for (path, contents) in updates {
+        write(path, contents).map_err(|e| ScaffoldError::Io(path.clone(), e.to_string()))?;
+        written.push(path.clone());
+    }
+
+    Ok(written)
+}
+
+/// Stub solution that compiles and returns zero for both parts.
+pub fn day_source() -> String {
+    "\
+type Input<'a> = Vec<&'a str>;
+
+pub fn parse(input: &str) -> Input<'_> {
+    input.lines().collect()
+}
+
+pub fn part1(_input: &Input) -> u32 {
+    0
+}
+
+pub fn part2(_input: &Input) -> u32 {
+    0
+}
+"
+    .to_string()
+}
+
+/// Stub test checking both parts against an example that is still to be filled in.
+pub fn test_source(year: u32, day: u32) -> String {
+    format!(
+        "\
+use aoc::year{year}::day{day:02}::*;
+
+const EXAMPLE: &str = \"\\
+\";
+
+#[test]
+fn part1_test() {{
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 0);
+}}
+
+#[test]
+fn part2_test() {{
+    let input = parse(EXAMPLE);
+    assert_eq!(part2(&input), 0);
+}}
+"
+    )
+}
+
+/// Adds `pub mod dayNN;` to the year's module block in `src/lib.rs`.
+pub fn register_module(text: &str, year: u32, day: u32) -> Result<String, EditError> {
+    let entry = format!("pub mod day{day:02};");
+    insert_sorted(
+        text,
+        &format!("pub mod year{year} {{"),
+        "}",
+        &key(day),
+        &entry,
+    )
+    .or_else(|error| match error {
+        EditError::MissingBlock => Ok(format!(
+            "{}\n/// # Advent of Code {year}.\npub mod year{year} {{\n    {entry}\n}}\n",
+            text.trim_end()
+        )),
+        error => Err(error),
+    })
+}
+
+/// Adds `mod dayNN_test;` to the year's block in `tests/test.rs`.
+pub fn register_test(text: &str, year: u32, day: u32) -> Result<String, EditError> {
+    let entry = format!("mod day{day:02}_test;");
+    insert_sorted(text, &format!("mod year{year} {{"), "}", &key(day), &entry).or_else(|error| {
+        match error {
+            EditError::MissingBlock => Ok(format!(
+                "{}\n\nmod year{year} {{\n    {entry}\n}}\n",
+                text.trim_end()
+            )),
+            error => Err(error),
+        }
+    })
+}
+
+/// Adds the `solution!` entry to the year's registry function, creating it if needed.
+pub fn register_solution(text: &str, year: u32, day: u32) -> Result<String, EditError> {
+    let entry = format!("solution!(year{year}, day{day:02}),");
+    let open = format!("fn year{year}() -> Vec<Solution> {{");
+
+    let Some(start) = text.lines().position(|line| line.trim() == open) else {
+        let years =
+            text.find("].into_iter().flatten().collect()")
+                .ok_or(EditError::Unrecognized(
+                    "solutions() does not list the years",
+                ))?;
+        return Ok(format!(
+            "{}, year{year}(){}\n\n{open}\n    vec![\n        {entry}\n    ]\n}}\n",
+            &text[..years],
+            text[years..].trim_end()
+        ));
+    };
+
+    // The entries live in the `vec![` following the function header.
+    let vec_line = text
+        .lines()
+        .skip(start)
+        .position(|line| line.trim() == "vec![")
+        .ok_or(EditError::Unrecognized("Registry function without vec!"))?;
+    let prefix: String = text
+        .lines()
+        .take(start + vec_line)
+        .map(|line| format!("{line}\n"))
+        .collect();
+    let rest: String = text
+        .lines()
+        .skip(start + vec_line)
+        .map(|line| format!("{line}\n"))
+        .collect();
+
+    Ok(prefix + &insert_sorted(&rest, "vec![", "]", &key(day), &entry)?)
+}
+
+/// Why the text of a file could not be updated.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum EditError {
+    MissingBlock,
+    Duplicate(String),
+    Unrecognized(&'static str),
+}
+
+impl EditError {
+    fn at(self, path: &Path) -> ScaffoldError {
+        match self {
+            EditError::Duplicate(entry) => {
+                ScaffoldError::AlreadyExists(format!("{entry} in {}", path.display()))
+            }
+            EditError::MissingBlock => {
+                ScaffoldError::Unrecognized(path.to_path_buf(), "Missing block".into())
+            }
+            EditError::Unrecognized(message) => {
+                ScaffoldError::Unrecognized(path.to_path_buf(), message.to_string())
+            }
+        }
+    }
+}
+
+/// Inserts a line into the block opened by the `open` line, before the first greater entry.
+///
+/// Entries keep the indentation of the block's existing lines.
+///
+/// # Errors
+/// * `Duplicate` if a line of the block already mentions `key`.
+fn insert_sorted(
+    text: &str,
+    open: &str,
+    close: &str,
+    key: &str,
+    entry: &str,
+) -> Result<String, EditError> {
+    let lines: Vec<&str> = text.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| line.trim() == open)
+        .ok_or(EditError::MissingBlock)?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line.trim() == close)
+            .ok_or(EditError::Unrecognized("Unterminated block"))?;
+
+    let entries = &lines[start + 1..end];
+    if entries.iter().any(|line| line.contains(key)) {
+        return Err(EditError::Duplicate(key.to_string()));
+    }
+
+    let open_indent = lines[start].len() - lines[start].trim_start().len();
+    let indent = entries
+        .first()
+        .map_or(open_indent + 4, |line| line.len() - line.trim_start().len());
+    let position = start
+        + 1
+        + entries
+            .iter()
+            .position(|line| line.trim() > entry)
+            .unwrap_or(entries.len());
+
+    let mut result: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+    result.insert(position, format!("{}{entry}", " ".repeat(indent)));
+    Ok(result.join("\n") + "\n")
+}
+
+/// The module name of a day, which every registration mentions.
+fn key(day: u32) -> String {
+    format!("day{day:02}")
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    read_to_string(path).map_err(|e| ScaffoldError::Io(path.to_path_buf(), e.to_string()))
+}
@@ -0,0 +1,123 @@
+//! Turns real puzzle inputs into shareable synthetic ones.
+//!
+//! Puzzle inputs should not be published, but bug reports are much easier to act on with a
+//! reproduction. Scrubbing keeps the shape of the input so that it still parses:
+//!
+//! * Grids (lines of equal length without whitespace) have their cells shuffled, preserving
+//!   the count of every cell type, e.g. the single guard in day06.
+//! * Everywhere else each run of digits is replaced by a random number with the same number of
+//!   digits, within the range observed for numbers of that length. Runs that are the only
+//!   sample of their length, like day09's disk map, are randomized digit by digit.
+//! * All other characters, including line breaks and separators, are kept as is.
+use crate::util::rng::Rng;
+use std::collections::HashMap;
+
+/// Produces a synthetic input with the same structure as the original.
+pub fn scrub(input: &str, rng: &mut Rng) -> String {
+    if is_grid(input) {
+        scrub_grid(input, rng)
+    } else {
+        scrub_numbers(input, rng)
+    }
+}
+
+fn is_grid(input: &str) -> bool {
+    let mut lines = input.lines().filter(|line| !line.is_empty());
+    let Some(first) = lines.next() else {
+        return false;
+    };
+    let width = first.chars().count();
+    let mut height = 1;
+
+    for line in lines {
+        if line.chars().count() != width || line.contains(char::is_whitespace) {
+            return false;
+        }
+        height += 1;
+    }
+
+    width > 1 && height > 1 && !first.contains(char::is_whitespace)
+}
+
+fn scrub_grid(input: &str, rng: &mut Rng) -> String {
+    let mut cells: Vec<char> = input.chars().filter(|&c| c != '\n' && c != '\r').collect();
+    rng.shuffle(&mut cells);
+
+    let mut cells = cells.into_iter();
+    input
+        .chars()
+        .map(|c| match c {
+            '\n' | '\r' => c,
+            _ => cells.next().unwrap(),
+        })
+        .collect()
+}
+
+fn scrub_numbers(input: &str, rng: &mut Rng) -> String {
+    let runs = digit_runs(input);
+
+    // Observed value range per digit count.
+    let mut ranges: HashMap<usize, (u64, u64)> = HashMap::new();
+    let (mut min_digit, mut max_digit) = (b'9', b'0');
+    for &(start, end) in &runs {
+        let run = &input[start..end];
+        run.bytes().for_each(|b| {
+            min_digit = min_digit.min(b);
+            max_digit = max_digit.max(b);
+        });
+        if let Ok(value) = run.parse::<u64>() {
+            let range = ranges.entry(run.len()).or_insert((value, value));
+            *range = (range.0.min(value), range.1.max(value));
+        }
+    }
+
+    let mut output = String::with_capacity(input.len());
+    let mut last = 0;
+
+    for (start, end) in runs {
+        output.push_str(&input[last..start]);
+        let len = end - start;
+
+        match ranges.get(&len) {
+            Some(&(low, high)) if low < high => {
+                output.push_str(&format!("{:0len$}", rng.range(low..=high)));
+            }
+            _ => (0..len).for_each(|i| {
+                // Avoid introducing leading zeros into multi digit numbers.
+                let low = if i == 0 && len > 1 {
+                    min_digit.max(b'1')
+                } else {
+                    min_digit
+                };
+                let digit = rng.range(low as u64..=max_digit.max(low) as u64) as u8;
+                output.push(digit as char);
+            }),
+        }
+
+        last = end;
+    }
+
+    output.push_str(&input[last..]);
+    output
+}
+
+/// Returns the byte ranges of every maximal run of ASCII digits.
+fn digit_runs(input: &str) -> Vec<(usize, usize)> {
+    let bytes = input.as_bytes();
+    let mut runs = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i].is_ascii_digit() {
+            let start = i;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            runs.push((start, i));
+        } else {
+            i += 1;
+        }
+    }
+
+    runs
+}
@@ -0,0 +1,157 @@
+//! Minimal HTTP frontend for the solver registry.
+//!
+//! Exposes a single endpoint, `POST /solve/{year}/{day}`, taking the puzzle input as the request
+//! body and responding with a JSON object containing both answers and the elapsed time:
+//!
+//! ```none
+//!   {"year":2024,"day":1,"part1":"11","part2":"31","elapsed_us":42}
+//! ```
+//!
+//! Only the subset of HTTP/1.1 needed by simple clients is supported. Each connection is handled
+//! on its own thread and closed after a single response. Request lines and headers over
+//! [`MAX_HEAD`] and bodies over [`MAX_BODY`] are refused before being read, a client that stops
+//! sending is dropped after [`READ_TIMEOUT`], and days are solved with the timeout of the
+//! [`RunOptions`] the server was started with.
+use crate::runner::run::{solve_with, RunError, RunOptions};
+use crate::util::json::escape;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{Shutdown, TcpListener, TcpStream, ToSocketAddrs};
+use std::thread;
+use std::time::Duration;
+
+/// Longest request line and headers accepted together, far above what simple clients send.
+pub const MAX_HEAD: u64 = 8 << 10;
+
+/// Largest request body accepted, far above the size of any puzzle input.
+pub const MAX_BODY: usize = 1 << 20;
+
+/// How long a connection may stay silent before it is dropped.
+pub const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Listens on the given address and answers solve requests until the process is stopped.
+///
+/// # Errors
+/// Returns an error if the address cannot be bound.
+pub fn serve<A: ToSocketAddrs>(address: A, options: &RunOptions) -> io::Result<()> {
+    let listener = TcpListener::bind(address)?;
+
+    for stream in listener.incoming().flatten() {
+        let options = options.clone();
+        thread::spawn(move || {
+            // A client hanging up mid-request is not worth taking the server down for.
+            let _ = handle(stream, &options);
+        });
+    }
+
+    Ok(())
+}
+
+fn handle(stream: TcpStream, options: &RunOptions) -> io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new(&stream);
+
+    // Reading stops at the limit, leaving an unfinished line without its blank line after it
+    let mut head = (&mut reader).take(MAX_HEAD);
+    let mut request_line = String::new();
+    head.read_line(&mut request_line)?;
+
+    let mut content_length = Some(0);
+    let mut ended = false;
+    loop {
+        let mut header = String::new();
+        if head.read_line(&mut header)? == 0 {
+            break;
+        }
+        if header.trim().is_empty() {
+            ended = true;
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse::<usize>().ok();
+            }
+        }
+    }
+    if !ended && head.limit() == 0 {
+        let message = format!("Request line and headers are longer than {MAX_HEAD} bytes");
+        respond(
+            &stream,
+            "431 Request Header Fields Too Large",
+            &error(&message),
+        )?;
+        // Closing with the rest of the request unread would reset the connection before the
+        // client reads the response, so a bounded amount of it is skipped first
+        stream.shutdown(Shutdown::Write)?;
+        io::copy(&mut reader.take(MAX_HEAD), &mut io::sink())?;
+        return Ok(());
+    }
+
+    let content_length = match content_length {
+        None => return respond(&stream, "400 Bad Request", &error("Invalid Content-Length")),
+        Some(length) if length > MAX_BODY => {
+            let message = format!("Body is larger than {MAX_BODY} bytes");
+            return respond(&stream, "413 Payload Too Large", &error(&message));
+        }
+        Some(length) => length,
+    };
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    let (status, json) = route(&request_line, body, options);
+    respond(&stream, status, &json)
+}
+
+fn route(request_line: &str, body: Vec<u8>, options: &RunOptions) -> (&'static str, String) {
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default();
+    let target = parts.next().unwrap_or_default();
+    let segments: Vec<_> = target.trim_matches('/').split('/').collect();
+
+    let (year, day) = match segments.as_slice() {
+        ["solve", year, day] => match (year.parse(), day.parse()) {
+            (Ok(year), Ok(day)) => (year, day),
+            _ => return ("400 Bad Request", error("Invalid year or day")),
+        },
+        _ => return ("404 Not Found", error("Unknown endpoint")),
+    };
+
+    if method != "POST" {
+        return ("405 Method Not Allowed", error("Use POST"));
+    }
+
+    let Ok(input) = String::from_utf8(body) else {
+        return ("400 Bad Request", error("Input is not valid UTF-8"));
+    };
+
+    let result = solve_with(year, day, input, options);
+    match result.outcome {
+        Ok((part1, part2)) => (
+            "200 OK",
+            format!(
+                "{{\"year\":{year},\"day\":{day},\"part1\":\"{}\",\"part2\":\"{}\",\"elapsed_us\":{}}}",
+                escape(&part1.to_string()),
+                escape(&part2.to_string()),
+                result.elapsed.as_micros()
+            ),
+        ),
+        Err(error @ RunError::NotFound { .. }) => ("404 Not Found", self::error(&error.to_string())),
+        Err(error @ RunError::TimedOut(_)) => {
+            ("503 Service Unavailable", self::error(&error.to_string()))
+        }
+        Err(error) => ("500 Internal Server Error", self::error(&error.to_string())),
+    }
+}
+
+fn respond(mut stream: &TcpStream, status: &str, json: &str) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{json}",
+        json.len()
+    )?;
+    stream.flush()
+}
+
+fn error(message: &str) -> String {
+    format!("{{\"error\":\"{}\"}}", escape(message))
+}
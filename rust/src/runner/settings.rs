@@ -0,0 +1,97 @@
+//! Persistent defaults for the runner, read from the `[run]` section of `aoc.toml`.
+//!
+//! Saves repeating the same flags on every invocation:
+//!
+//! ```toml
+//! [download]
+//! session = "53616c7465645f5f..."
+//!
+//! [run]
+//! input = "../inputs"
+//! year = 2024
+//! bench_runs = 50
+//! regression = 15
+//! output = "csv"
+//! ```
+//!
+//! Flags given on the command line take precedence over these. The session token stays in the
+//! `[download]` section, see [`download`](crate::runner::download).
+use crate::runner::cli::Output;
+use crate::util::config::Config;
+use std::fmt;
+use std::path::PathBuf;
+
+/// Section of `aoc.toml` holding the runner defaults.
+pub const SECTION: &str = "run";
+
+/// Runner defaults. Every field is optional, leaving the built in default in place.
+///
+/// # Fields
+/// - `input`: Directory holding the `yearYYYY/dayDD.txt` inputs instead of `input`.
+/// - `year`: Year selected when the command line does not name one.
+/// - `bench_runs`: Timed runs per day in `--bench` mode, at least one.
+/// - `regression`: Percentage above which `--baseline` flags a slower day, at least one.
+/// - `output`: Format used without `--output`.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Settings {
+    pub input: Option<PathBuf>,
+    pub year: Option<u32>,
+    pub bench_runs: Option<u32>,
+    pub regression: Option<u32>,
+    pub output: Option<Output>,
+}
+
+/// A key of the `[run]` section with a value it does not accept.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SettingsError {
+    pub key: &'static str,
+    pub value: String,
+}
+
+impl fmt::Display for SettingsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid value {:?} for {} in [{SECTION}]",
+            self.value, self.key
+        )
+    }
+}
+
+impl std::error::Error for SettingsError {}
+
+impl Settings {
+    /// Reads the `[run]` section. Unknown keys are ignored.
+    pub fn from_config(config: &Config) -> Result<Settings, SettingsError> {
+        let value = |key: &'static str| config.get(SECTION, key).map(|value| (key, value));
+        let invalid = |(key, value): (&'static str, &str)| SettingsError {
+            key,
+            value: value.to_string(),
+        };
+        let number = |key| match value(key) {
+            Some(entry) => entry
+                .1
+                .parse()
+                .ok()
+                .filter(|&n: &u32| n > 0)
+                .map(Some)
+                .ok_or_else(|| invalid(entry)),
+            None => Ok(None),
+        };
+
+        let output = match value("output") {
+            Some((_, "text")) => Some(Output::Text),
+            Some((_, "csv")) => Some(Output::Csv),
+            Some(entry) => return Err(invalid(entry)),
+            None => None,
+        };
+
+        Ok(Settings {
+            input: value("input").map(|(_, path)| PathBuf::from(path)),
+            year: number("year")?,
+            bench_runs: number("bench_runs")?,
+            regression: number("regression")?,
+            output,
+        })
+    }
+}
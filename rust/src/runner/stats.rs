@@ -0,0 +1,132 @@
+//! Per year summaries of recorded solution timings.
+//!
+//! Every normal run records the elapsed time of each solved day in `timings.toml`, keeping only
+//! the latest measurement:
+//!
+//! ```toml
+//! [timings.2024]
+//! 01 = 412
+//! 06 = 18342
+//! ```
+//!
+//! Values are microseconds. The summaries combine these with the registry, so days that are
+//! registered but never ran still count towards the total. The classic goal is to solve all
+//! days of a year in under one second combined, so each year also reports how much of that
+//! budget it uses.
+use crate::runner::registry::Solution;
+use crate::runner::timing::Timing;
+use crate::util::config::Config;
+use std::time::Duration;
+
+/// Default location of the timing store.
+pub const TIMINGS: &str = "timings.toml";
+
+/// Section prefix for the timings of a year.
+const SECTION: &str = "timings.";
+
+/// The combined runtime goal for all days of a year.
+pub const GOAL: Duration = Duration::from_secs(1);
+
+/// Stores the latest elapsed time of a day.
+pub fn record(store: &mut Config, year: u32, day: u32, elapsed: Duration) {
+    store.insert(
+        &format!("{SECTION}{year}"),
+        &format!("{day:02}"),
+        &elapsed.as_micros().to_string(),
+    );
+}
+
+/// Returns every recorded `(year, day, elapsed)`, ordered by year and day.
+///
+/// The store is a plain file that may have been edited by hand or cut short, so entries that
+/// are not numbers are skipped rather than failing every later run.
+pub fn recorded(store: &Config) -> Vec<(u32, u32, Duration)> {
+    let mut timings: Vec<_> = entries(store).filter_map(|(_, _, timing)| timing).collect();
+    timings.sort_unstable();
+    timings
+}
+
+/// Iterates over the entries of every year section in file order.
+///
+/// # Returns
+/// * The section and key of each entry, with its `(year, day, elapsed)` or `None` if the year,
+///   day or elapsed time is not a number.
+pub fn entries(store: &Config) -> impl Iterator<Item = (&str, &str, Option<(u32, u32, Duration)>)> {
+    store
+        .sections()
+        .filter_map(|name| Some((name, name.strip_prefix(SECTION)?)))
+        .flat_map(move |(name, year)| {
+            store.section(name).map(move |(day, micros)| {
+                let timing = year
+                    .parse()
+                    .ok()
+                    .zip(day.parse().ok())
+                    .zip(micros.parse().ok());
+                let timing =
+                    timing.map(|((year, day), micros)| (year, day, Duration::from_micros(micros)));
+                (name, day, timing)
+            })
+        })
+}
+
+/// Summary of one year.
+///
+/// # Fields
+/// - `registered`: Number of days with a solution in the registry.
+/// - `solved`: Number of those days with a recorded timing.
+/// - `timing`: Statistics of the recorded timings, `None` if no day has run yet.
+/// - `total`: Sum of the recorded timings.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct YearStats {
+    pub year: u32,
+    pub registered: usize,
+    pub solved: usize,
+    pub timing: Option<Timing>,
+    pub total: Duration,
+}
+
+impl YearStats {
+    /// The share of the one second [`GOAL`] used by the year, in percent.
+    pub fn percent_of_goal(&self) -> f64 {
+        self.total.as_secs_f64() / GOAL.as_secs_f64() * 100.0
+    }
+
+    /// Checks if every registered day ran and all of them together took less than the goal.
+    pub fn meets_goal(&self) -> bool {
+        self.solved == self.registered && self.total < GOAL
+    }
+}
+
+/// Summarises the recorded timings of each registered year.
+pub fn per_year(solutions: &[Solution], store: &Config) -> Vec<YearStats> {
+    let timings = recorded(store);
+    let mut years: Vec<u32> = solutions.iter().map(|solution| solution.year).collect();
+    years.dedup();
+
+    years
+        .into_iter()
+        .map(|year| {
+            let registered: Vec<&Solution> = solutions
+                .iter()
+                .filter(|solution| solution.year == year)
+                .collect();
+            let samples: Vec<Duration> = registered
+                .iter()
+                .filter_map(|solution| {
+                    timings
+                        .iter()
+                        .find(|&&(y, d, _)| y == year && d == solution.day)
+                        .map(|&(_, _, elapsed)| elapsed)
+                })
+                .collect();
+
+            YearStats {
+                year,
+                registered: registered.len(),
+                solved: samples.len(),
+                timing: (!samples.is_empty()).then(|| Timing::from_samples(&samples)),
+                total: samples.iter().sum(),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,57 @@
+//! Overview of what is done and what is missing for each registered day.
+//!
+//! A day is complete once it has an input to run on, known answers to verify against and a test
+//! file. All paths are relative to the crate root, which is the working directory of the runner.
+use crate::runner::registry::Solution;
+use crate::runner::run::{Filter, RunOptions};
+use crate::runner::verify::answers_path;
+use std::path::{Path, PathBuf};
+
+/// The location of the tests for a day.
+pub fn test_path(year: u32, day: u32) -> PathBuf {
+    Path::new("tests")
+        .join(format!("year{year}"))
+        .join(format!("day{day:02}_test.rs"))
+}
+
+/// Which of the files around a registered solution exist.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct DayStatus {
+    pub year: u32,
+    pub day: u32,
+    pub name: Option<&'static str>,
+    pub input: bool,
+    pub answers: bool,
+    pub tests: bool,
+}
+
+impl DayStatus {
+    /// Checks the files of a solution in an input set below `root`, `None` for the real inputs.
+    pub fn of(solution: &Solution, set: Option<&str>, root: &Path) -> Self {
+        let (year, day) = (solution.year, solution.day);
+        DayStatus {
+            year,
+            day,
+            name: solution.name(),
+            input: root.join(&solution.path).is_file(),
+            answers: root.join(answers_path(year, day, set)).is_file(),
+            tests: root.join(test_path(year, day)).is_file(),
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.input && self.answers && self.tests
+    }
+}
+
+/// Returns the status of every registered day selected by the filter, in registry order, with
+/// the inputs and answers of the input set the options select.
+pub fn list(filter: &Filter, options: &RunOptions, root: &Path) -> Vec<DayStatus> {
+    let set = options.input_set.as_deref();
+    options
+        .solutions()
+        .iter()
+        .filter(|solution| filter.matches(solution))
+        .map(|solution| DayStatus::of(solution, set, root))
+        .collect()
+}
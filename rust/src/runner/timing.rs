@@ -0,0 +1,134 @@
+//! Repeated timing of solutions, for numbers stable enough to compare between changes.
+//!
+//! A single run is dominated by noise from caches, frequency scaling and the scheduler. Each day
+//! is instead run a few times without measuring to warm up, then timed over many runs and
+//! summarised by the minimum, median, mean and standard deviation.
+use crate::runner::registry::{solutions, Solution};
+use crate::runner::run::{guarded, Filter, RunError, RunOptions};
+use std::fs::read_to_string;
+use std::time::{Duration, Instant};
+
+/// Summary statistics of repeated runs.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Timing {
+    pub runs: u32,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Timing {
+    /// Summarises the durations of individual runs.
+    ///
+    /// # Panics
+    /// Panics if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "No samples to summarise");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let runs = sorted.len();
+
+        let median = if runs % 2 == 1 {
+            sorted[runs / 2]
+        } else {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+        };
+
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / runs as f64;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / runs as f64;
+
+        Self {
+            runs: runs as u32,
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+/// The timing of a single day.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DayTiming {
+    pub year: u32,
+    pub day: u32,
+    pub outcome: Result<Timing, RunError>,
+}
+
+/// Lazily times every solution selected by the filter, reading the inputs the options point to.
+///
+/// # Arguments
+/// * `filter` - Selects the days to time.
+/// * `warmup` - Number of untimed runs before measuring.
+/// * `runs` - Number of timed runs, at least one.
+/// * `options` - Where the inputs are and how long a day may take. Its own `runs` and `cache`
+///   are not used.
+pub fn bench<'a>(
+    filter: &'a Filter,
+    warmup: u32,
+    runs: u32,
+    options: &'a RunOptions,
+) -> impl Iterator<Item = DayTiming> + 'a {
+    options
+        .solutions()
+        .into_iter()
+        .filter(|solution| filter.matches(solution))
+        .map(move |solution| {
+            let outcome = match read_to_string(&solution.path) {
+                Ok(data) => measure(&solution, &data, warmup, runs, options),
+                Err(_) => Err(RunError::MissingInput(solution.path.clone())),
+            };
+            DayTiming {
+                year: solution.year,
+                day: solution.day,
+                outcome,
+            }
+        })
+}
+
+/// Times a single day with the given input instead of reading it from disk.
+pub fn bench_input(
+    year: u32,
+    day: u32,
+    input: &str,
+    warmup: u32,
+    runs: u32,
+    options: &RunOptions,
+) -> DayTiming {
+    let outcome = solutions()
+        .iter()
+        .find(|solution| solution.year == year && solution.day == day)
+        .ok_or(RunError::NotFound { year, day })
+        .and_then(|solution| measure(solution, input, warmup, runs, options));
+
+    DayTiming { year, day, outcome }
+}
+
+/// Times a day, as a whole against the timeout if one is set.
+fn measure(
+    solution: &Solution,
+    data: &str,
+    warmup: u32,
+    runs: u32,
+    options: &RunOptions,
+) -> Result<Timing, RunError> {
+    let (solver, data) = (solution.solver, data.to_string());
+    guarded(options.timeout, move || {
+        for _ in 0..warmup {
+            std::hint::black_box(solver.solve(&data));
+        }
+
+        let samples: Vec<Duration> = (0..runs.max(1))
+            .map(|_| {
+                let instant = Instant::now();
+                std::hint::black_box(solver.solve(&data));
+                instant.elapsed()
+            })
+            .collect();
+
+        Timing::from_samples(&samples)
+    })
+}
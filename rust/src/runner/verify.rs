@@ -0,0 +1,39 @@
+//! Compares answers with the known correct ones, turning the runner into a regression suite.
+//!
+//! Known answers live next to the inputs, in `answers/year2024/day01.txt`. The file holds the
+//! answers to part one and two on their own lines, either bare or labelled like `Part 1: 123`,
+//! in the same format [`cross_check`](crate::runner::cross_check) reads from external commands.
+//! Days without a file are not checked. Answers to the other input sets of a day are found the
+//! same way as their inputs, such as `answers/year2024/day01/sample1.txt`, see [`locate`].
+use crate::runner::cross_check::answers;
+use crate::runner::registry::{locate, Answer};
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+
+/// The location of the known answers for a day in an input set, `None` for the real inputs.
+pub fn answers_path(year: u32, day: u32, set: Option<&str>) -> PathBuf {
+    locate(&Path::new("answers").join(format!("year{year}")), day, set)
+}
+
+/// Reads the known answers for a day in an input set from their default location.
+///
+/// # Returns
+/// * Both answers, or `None` if the file is missing or holds fewer than two answers.
+pub fn expected(year: u32, day: u32, set: Option<&str>) -> Option<(Answer, Answer)> {
+    let text = read_to_string(answers_path(year, day, set)).ok()?;
+    parse_expected(&text)
+}
+
+/// Reads known answers from the contents of an answers file.
+pub fn parse_expected(text: &str) -> Option<(Answer, Answer)> {
+    let (part1, part2) = answers(text)?;
+    Some((Answer::from(part1), Answer::from(part2)))
+}
+
+/// Checks each part against the known answer.
+///
+/// # Returns
+/// * Whether part one and part two are correct.
+pub fn check(expected: &(Answer, Answer), actual: &(Answer, Answer)) -> [bool; 2] {
+    [expected.0.matches(&actual.0), expected.1.matches(&actual.1)]
+}
@@ -1,40 +1,76 @@
-use regex::Regex;
+use crate::util::scanner::{ByteSet, Scanner};
 
 type Input = str;
 
-pub fn parse(input: &str) -> &str {
-    input
+/// First bytes of `mul(`, `do()` and `don't()`.
+const TOKEN_START: ByteSet = ByteSet::new(b"md");
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Instruction {
+    Mul(u32, u32),
+    Do,
+    Dont,
 }
 
-pub fn part1(input: &Input) -> u32 {
-    let re = Regex::new("mul\\((\\d{1,3}),(\\d{1,3})\\)").unwrap();
+/// Copies the memory, which the parts then borrow as `&str`.
+///
+/// The registry keeps every parsed input as a `Box<dyn Any>` between timing the parse and the
+/// parts, which only holds owned values, so the memory can not stay borrowed from the input.
+pub fn parse(input: &str) -> String {
+    input.to_string()
+}
 
-    re.captures_iter(&input).fold(0, |acc, cap| {
-        let first_number: u32 = cap[1].parse().unwrap();
-        let second_number: u32 = cap[2].parse().unwrap();
-        acc + first_number * second_number
-    })
+pub fn part1(input: &Input) -> u32 {
+    instructions(input)
+        .map(|instruction| match instruction {
+            Instruction::Mul(a, b) => a * b,
+            _ => 0,
+        })
+        .sum()
 }
 
 pub fn part2(input: &Input) -> u32 {
-    let re = Regex::new(r"(don't\(\)|do\(\))").unwrap();
+    let mut enabled = true;
 
-    let mut capture = true;
-    let mut filter_text = String::new();
-    let mut last_pos = 0;
+    instructions(input)
+        .map(|instruction| {
+            match instruction {
+                Instruction::Mul(a, b) if enabled => return a * b,
+                Instruction::Mul(..) => (),
+                Instruction::Do => enabled = true,
+                Instruction::Dont => enabled = false,
+            }
+            0
+        })
+        .sum()
+}
 
-    for mat in re.find_iter(input) {
-        let section = &input[last_pos..mat.start()];
-        if capture {
-            filter_text.push_str(section);
+/// Finds the valid instructions among the corrupted memory, left to right.
+fn instructions(input: &str) -> impl Iterator<Item = Instruction> + '_ {
+    let mut scanner = Scanner::new(input);
+
+    std::iter::from_fn(move || {
+        while scanner.skip_until(&TOKEN_START) {
+            if let Some(instruction) = scanner.attempt(instruction) {
+                return Some(instruction);
+            }
+            scanner.advance(1);
         }
-        capture = mat.as_str() == "do()";
-        last_pos = mat.end();
-    }
+        None
+    })
+}
 
-    if capture {
-        filter_text.push_str(&input[last_pos..]);
+fn instruction(scanner: &mut Scanner) -> Option<Instruction> {
+    if scanner.eat(b"mul(") {
+        let a = scanner.number(3)?;
+        scanner.eat(b",").then_some(())?;
+        let b = scanner.number(3)?;
+        scanner.eat(b")").then_some(Instruction::Mul(a, b))
+    } else if scanner.eat(b"do()") {
+        Some(Instruction::Do)
+    } else if scanner.eat(b"don't()") {
+        Some(Instruction::Dont)
+    } else {
+        None
     }
-
-    part1(&filter_text)
 }
@@ -96,7 +96,7 @@ fn get_fix_order(
     result.clone()
 }
 
-fn is_valid_order(rule: &Vec<u32>, valid_line: &Vec<u32>) -> bool {
+fn is_valid_order(rule: &[u32], valid_line: &[u32]) -> bool {
     let mut valid = true;
     for num in valid_line.iter() {
         valid = rule.iter().all(|r| r != num);
@@ -104,7 +104,7 @@ fn is_valid_order(rule: &Vec<u32>, valid_line: &Vec<u32>) -> bool {
     valid
 }
 
-fn find_first_index_of_any(vec1: &Vec<u32>, vec2: &Vec<u32>) -> Option<usize> {
+fn find_first_index_of_any(vec1: &[u32], vec2: &[u32]) -> Option<usize> {
     vec2.iter()
         .filter_map(|&num| vec1.iter().position(|&x| x == num))
         .min()
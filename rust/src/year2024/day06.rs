@@ -1,164 +1,164 @@
+use crate::util::blockers::Blockers;
+use crate::util::debugger;
 use crate::util::direction::Direction;
 use crate::util::grid::Grid;
-use crate::util::grid_iterator::GridIterator;
+use crate::util::lazy::Cached;
 use crate::util::point::Point;
+use crate::util::progress;
+use crate::util::thread::{parallel_map_init, threads};
 use std::collections::HashSet;
-use std::sync::{Arc, Mutex};
-use std::{thread, vec};
 
-type Input = Grid<char>;
+type Map = Grid<char>;
+
+/// A cell the guard visits for the first time, with its state one move before reaching it.
+type Candidate = (Point, (Point, Direction));
+
+pub struct Input {
+    pub grid: Map,
+    candidates: Cached<Vec<Candidate>>,
+}
+
+impl Input {
+    /// The walk is shared by both parts, only part 2 probes its cells for loops.
+    fn candidates(&self) -> &[Candidate] {
+        self.candidates.get_or_compute(|| candidates(&self.grid))
+    }
+}
 
 pub fn parse(input: &str) -> Input {
-    Grid::parse(input, None).expect("Failed to parse input into Grid<char>")
+    let grid = Grid::parse(input, None).expect("Failed to parse input into Grid<char>");
+    Input {
+        grid,
+        candidates: Cached::new(),
+    }
 }
 
 pub fn part1(input: &Input) -> i32 {
-    let input: &mut Input = &mut input.clone();
-    let binding = &mut input.clone();
-    let mut iterator = initialize_iterator(binding);
-    process_grid(&mut iterator, false, &mut input.clone()).0
+    // Every visited cell is a candidate, except the guard's own
+    input.candidates().len() as i32 + 1
 }
 
 pub fn part2(input: &Input) -> i32 {
-    let input: &mut Input = &mut input.clone();
-    let binding = &mut input.clone();
-    let mut iterator = initialize_iterator(binding);
-    process_grid(&mut iterator, true, &mut input.clone()).1
+    loops(&input.grid, input.candidates())
 }
 
-fn process_grid(
-    iterator: &mut GridIterator<char>,
-    should_count_loops: bool,
-    input: &mut Input,
-) -> (i32, i32) {
-    let mut count = 0;
-    let obstacle = Arc::new(Mutex::new(Vec::new()));
-    let starting_point = iterator.get_current_position().clone();
-    let starting_direction = iterator.get_current_direction().clone();
-
-    let mut handles = vec![];
-
-    loop {
-        if !iterator.have_next() {
-            break;
-        }
-        let data = iterator.get_current_value();
-        match data {
-            Some('.') => {
-                iterator.set_current_value('X');
-                count += 1;
-            }
-            Some('^') => {
-                count += 1;
-            }
-            Some('X') => {}
-            Some('#') => {
-                turn_right(iterator);
-            }
-            _ => panic!("Invalid data {:?}", data),
-        };
+pub fn solve_both(input: &Input) -> (i32, i32) {
+    (part1(input), part2(input))
+}
 
-        if should_count_loops {
-            let pos = iterator.get_current_position().clone();
-            let input_clone = input.clone();
-            let start_point_clone = starting_point.clone();
-            let start_dir_clone = starting_direction.clone();
-            let obstacle_clone = Arc::clone(&obstacle);
-
-            let handle = thread::spawn(move || {
-                let mut local_obstacle = Vec::new();
-                count_loop(
-                    &pos,
-                    input_clone,
-                    start_point_clone,
-                    start_dir_clone,
-                    &mut local_obstacle,
-                );
-                let mut obstacle = obstacle_clone.lock().unwrap();
-                obstacle.extend(local_obstacle);
-            });
-
-            handles.push(handle);
+/// Walks the guard's path, keeping the first visit of every cell.
+///
+/// The guard's own cell can not hold an obstacle, every other newly visited cell is paired with
+/// the state one move before reaching it to probe from.
+fn candidates(grid: &Map) -> Vec<Candidate> {
+    let path = path(grid);
+    show(grid, &path);
+
+    let mut seen = grid.same_size_with('.');
+    let mut candidates = Vec::new();
+    seen.set_value(&path[0].0, 'X');
+    for pair in path.windows(2) {
+        let (point, _) = pair[1];
+        if seen.get_value(&point) == Some('.') {
+            seen.set_value(&point, 'X');
+            candidates.push((point, pair[0]));
         }
-
-        iterator.next(false);
     }
-
-    for handle in handles {
-        handle.join().expect("Thread panicked");
-    }
-
-    let distinct_obstacles: HashSet<_> = obstacle.lock().unwrap().iter().cloned().collect();
-    (count, distinct_obstacles.len() as i32)
+    candidates
 }
 
-fn count_loop(
-    current_position: &Point,
-    input: Input,
-    starting_point: Point,
-    starting_direction: Direction,
-    obstacles: &mut Vec<Point>,
-) {
-    let loop_input = &mut input.clone();
-    let obstacles_map: &mut HashSet<(Point, Direction)> = &mut HashSet::new();
-    let iterator = &mut GridIterator::new(loop_input, &starting_direction, 1);
-
-    iterator.set_current_position(current_position);
-    let current_value = iterator.get_current_value();
-    if current_value == Some('^') || current_value == Some('#') {
-        return;
-    }
-    let obstacle_candidate = iterator.get_current_position().clone();
+/// Counts the candidates where an obstacle traps the guard.
+fn loops(grid: &Map, candidates: &[Candidate]) -> i32 {
+    // Each worker probes on its own copy of the obstacles.
+    let blockers = Blockers::from_grid(grid, |&c| c == '#');
+    progress::start(candidates.len());
+    let loops = parallel_map_init(
+        candidates,
+        threads(),
+        || blockers.clone(),
+        |blockers, (candidate, previous)| {
+            let trapped = is_loop(blockers, candidate, previous);
+            progress::advance(1);
+            trapped
+        },
+    );
+    loops.into_iter().filter(|&trapped| trapped).count() as i32
+}
 
-    iterator.set_current_value('O');
-    iterator.set_current_position(&starting_point);
+/// Walks the guard from its starting cell until it leaves the map.
+///
+/// The path holds the guard's position and facing after every move, starting with where it
+/// stands. A turn in front of an obstacle is a move of its own, so the same cell can follow
+/// itself with a new direction. An obstacle can only change the path from the first time the
+/// guard reaches its cell, which makes the first visits of the path exactly the cells worth
+/// probing in part 2.
+///
+/// # Panics
+/// Panics if the map has no guard, or if the guard never leaves the map.
+pub fn path(grid: &Map) -> Vec<(Point, Direction)> {
+    let (mut position, mut direction) = guard(grid);
+    let mut path = vec![(position, direction)];
+    let limit = 4 * grid.get_size() as usize;
 
     loop {
-        let data = iterator.get_current_value();
-        match data {
-            Some('^') | Some('.') | Some('X') => {}
-            Some('#') | Some('O') => {
-                if !obstacles_map.insert((
-                    iterator.get_current_position().clone(),
-                    iterator.get_current_direction().clone(),
-                )) {
-                    obstacles.push(obstacle_candidate);
-                    break;
-                }
-                turn_right(iterator);
-            }
-            _ => panic!("Invalid data {:?}", data),
-        }
-
-        iterator.next(false);
-        if !iterator.have_next() {
-            break;
+        let next = position.add(&direction.to_point());
+        match grid.get_value(&next) {
+            None => return path,
+            Some('#') => direction = direction.turn_right(),
+            Some(_) => position = next,
         }
+        path.push((position, direction));
+        assert!(path.len() <= limit, "The guard never leaves the map");
     }
 }
 
-fn turn_right(iterator: &mut GridIterator<char>) {
-    let current_direction = iterator.get_current_direction();
-    let new_position = iterator
-        .get_current_position()
-        .sub(&current_direction.to_point());
-    let new_direction = current_direction.turn_right();
-    iterator.set_current_position(&new_position);
-    iterator.change_direction(&new_direction);
+/// Finds the guard and the direction it faces.
+fn guard(grid: &Map) -> (Point, Direction) {
+    grid.points()
+        .find_map(|point| Some((point, Direction::parse(grid.get_value(&point)?)?)))
+        .expect("Guard must face a direction")
 }
 
-fn initialize_iterator(input: &mut Input) -> GridIterator<char> {
-    let mut iterator = GridIterator::new(input, &Direction::Right, 1);
-    let directions: Vec<char> = vec!['^', 'v', '<', '>'];
-
-    let (val, position) = iterator.find(find, &directions).unwrap();
-    let direction = Direction::parse(val).unwrap();
+/// Replays the path for the debugger, marking the cells visited so far.
+fn show(grid: &Map, path: &[(Point, Direction)]) {
+    if !debugger::is_enabled() && !debugger::is_recording() {
+        return;
+    }
 
-    iterator.set_current_position(&position);
-    iterator.change_direction(&direction);
-    iterator
+    let mut marked = grid.clone();
+    for (position, direction) in path {
+        marked.set_value(position, 'X');
+        debugger::tick(&format!("{position:?} facing {direction:?}"), || {
+            marked.render()
+        });
+    }
 }
 
-fn find(vec: &Vec<char>, right: char) -> bool {
-    vec.iter().any(|&c| c == right)
+/// Checks if an obstacle on the candidate cell traps the guard, leaving the obstacles unchanged.
+///
+/// The guard jumps from one obstacle to the next, and is trapped once it turns at the same place
+/// in the same direction twice.
+///
+/// # Arguments
+/// * `blockers` - The obstacles of the map.
+/// * `candidate` - An empty cell on the guard's path.
+/// * `previous` - The guard's state one step before reaching the candidate.
+fn is_loop(blockers: &mut Blockers, candidate: &Point, previous: &(Point, Direction)) -> bool {
+    let mut turns = HashSet::new();
+    let (mut position, mut direction) = *previous;
+
+    blockers.insert(candidate);
+    let trapped = loop {
+        let Some(obstacle) = blockers.next_in_direction(&position, &direction) else {
+            break false;
+        };
+        position = obstacle.sub(&direction.to_point());
+        if !turns.insert((position, direction)) {
+            break true;
+        }
+        direction = direction.turn_right();
+    };
+
+    blockers.remove(candidate);
+    trapped
 }
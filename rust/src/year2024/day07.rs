@@ -1,4 +1,7 @@
+use crate::aoc_bench;
+use crate::util::recurse::trampoline;
 use std::collections::HashMap;
+use std::ops::ControlFlow::{Break, Continue};
 
 type Input = HashMap<i64, Vec<i64>>;
 
@@ -46,18 +49,31 @@ pub fn part2(input: &Input) -> i64 {
         .sum()
 }
 
-fn is_valid(values: &Vec<i64>, index: usize, expected: i64, concat: bool) -> bool {
-    if index == 0 {
-        return expected == values[index];
-    }
+fn is_valid(values: &[i64], index: usize, expected: i64, concat: bool) -> bool {
+    trampoline((index, expected), |(index, expected), calls| {
+        if index == 0 {
+            return if expected == values[index] {
+                Break(())
+            } else {
+                Continue(())
+            };
+        }
+
+        let val = values[index];
 
-    let val = values[index];
+        if concat && expected % power_of_ten(val) == val {
+            calls.push((index - 1, expected / power_of_ten(val)));
+        }
+        if expected % val == 0 {
+            calls.push((index - 1, expected / val));
+        }
+        if expected >= val {
+            calls.push((index - 1, expected - val));
+        }
 
-    (concat
-        && expected % power_of_ten(val) == val
-        && is_valid(values, index - 1, expected / power_of_ten(val), concat))
-        || (expected % val == 0 && is_valid(values, index - 1, expected / val, concat))
-        || (expected >= val && is_valid(values, index - 1, expected - val, concat))
+        Continue(())
+    })
+    .is_break()
 }
 
 fn power_of_ten(val: i64) -> i64 {
@@ -69,3 +85,10 @@ fn power_of_ten(val: i64) -> i64 {
         1000
     }
 }
+
+aoc_bench! {
+    is_valid => {
+        let values = vec![6, 8, 6, 15];
+        move || is_valid(&values, 3, 7290, true)
+    },
+}
@@ -1,8 +1,32 @@
 use std::collections::HashMap;
 
+use crate::util::lazy::Cached;
 use crate::util::{direction::Direction, grid::Grid, grid_iterator::GridIterator, point::Point};
 
-type Input = (Grid<char>, HashMap<char, Vec<Point>>);
+pub struct Input {
+    pub grid: Grid<char>,
+    pub antennas: HashMap<char, Vec<Point>>,
+    pairs: Cached<Vec<(Point, Point)>>,
+}
+
+impl Input {
+    /// Every ordered pair of distinct antennas sharing a frequency, used by both parts.
+    fn pairs(&self) -> &[(Point, Point)] {
+        self.pairs.get_or_compute(|| {
+            let mut pairs = Vec::new();
+            for frequency in self.antennas.values() {
+                for &first in frequency {
+                    for &second in frequency {
+                        if first != second {
+                            pairs.push((first, second));
+                        }
+                    }
+                }
+            }
+            pairs
+        })
+    }
+}
 
 pub fn parse(input: &str) -> Input {
     let grid = Grid::parse(input, None).expect("Failed to parse input into Grid<char>");
@@ -21,25 +45,23 @@ pub fn parse(input: &str) -> Input {
             }
         }
     }
-    (grid, antennas)
+    Input {
+        grid,
+        antennas,
+        pairs: Cached::new(),
+    }
 }
 
 pub fn part1(input: &Input) -> i64 {
-    let (grid, antennas) = input;
+    let grid = &input.grid;
     let mut locations = grid.same_size_with(0);
 
-    for frequrency in antennas.values() {
-        for &first in frequrency {
-            for &second in frequrency {
-                if first != second {
-                    let distance = second.sub(&first);
-                    let antinode = second.add(&distance);
+    for &(first, second) in input.pairs() {
+        let distance = second.sub(&first);
+        let antinode = second.add(&distance);
 
-                    if grid.contains(&antinode) {
-                        locations.set_value(&antinode, 1);
-                    }
-                }
-            }
+        if grid.contains(&antinode) {
+            locations.set_value(&antinode, 1);
         }
     }
 
@@ -48,22 +70,19 @@ pub fn part1(input: &Input) -> i64 {
 }
 
 pub fn part2(input: &Input) -> i64 {
-    let (grid, antennas) = input;
+    let grid = &input.grid;
     let mut locations = grid.same_size_with(0);
 
-    for frequrency in antennas.values() {
-        for &first in frequrency {
-            for &second in frequrency {
-                if first != second {
-                    let distance = second.sub(&first);
-                    let mut antinode = second;
+    // Resonant harmonics occur at every grid point in line with both antennas, so walk the line
+    // through them in steps of the reduced difference. Each pair comes in both orders, so walking
+    // from the first antenna towards the second covers the whole line once.
+    for &(first, second) in input.pairs() {
+        let step = second.sub(&first).reduced();
 
-                    while grid.contains(&antinode) {
-                        locations.set_value(&antinode, 1);
-                        antinode = antinode.add(&distance);
-                    }
-                }
-            }
+        let mut antinode = first;
+        while grid.contains(&antinode) {
+            locations.set_value(&antinode, 1);
+            antinode = antinode.add(&step);
         }
     }
 
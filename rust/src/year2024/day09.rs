@@ -1,110 +1,86 @@
-use std::collections::HashSet;
+use crate::util::runs::Runs;
 
 type Input = Vec<u32>;
 
+/// The disk as runs of file ids, `None` for free space, with the handles of the files and of
+/// the gaps in disk order.
+type Disk = (Runs<Option<u64>>, Vec<usize>, Vec<usize>);
+
 pub fn parse(input: &str) -> Input {
     input.chars().filter_map(|c| c.to_digit(10)).collect()
 }
 
+/// Fills each gap from the left with blocks taken from the last file, splitting that file when
+/// the gap is too small for all of it.
 pub fn part1(input: &Input) -> u64 {
-    let mut right = input.len() - 1;
-    let mut disk_memory: Vec<u64> = vec![];
-    let mut file_size = input[right];
-    let mut file_value = right / 2;
+    let (mut disk, files, gaps) = disk(input);
+    let mut remaining = files.iter().rev().copied();
+    let mut file = remaining.next();
 
-    for (left, &block_size) in input.iter().enumerate() {
-        if left >= right {
-            for _ in 0..file_size {
-                disk_memory.push(file_value as u64);
+    'gaps: for gap in gaps {
+        while let Some(current) = file {
+            let (file_run, gap_run) = (disk.get(current), disk.get(gap));
+            if file_run.start < gap_run.start {
+                break 'gaps;
             }
-            break;
-        }
-        if left % 2 == 0 {
-            for _ in 0..block_size {
-                disk_memory.push((left / 2) as u64);
+            if gap_run.length == 0 {
+                break;
             }
-        } else {
-            for _ in 0..block_size {
-                if file_size == 0 && right >= 2 {
-                    right = right.saturating_sub(2);
-                    file_size = input[right];
-                    file_value = right / 2;
-                }
-                disk_memory.push(file_value as u64);
-                file_size = file_size.saturating_sub(1);
+
+            if file_run.length <= gap_run.length {
+                disk.relocate(current, gap, None);
+                file = remaining.next();
+            } else {
+                let rest = disk.split(current, file_run.length - gap_run.length);
+                disk.relocate(current, gap, None);
+                file = Some(rest);
             }
         }
     }
 
-    let mut result: u64 = 0;
-    disk_memory.iter().enumerate().for_each(|(index, &value)| {
-        result = result.saturating_add(value * index as u64);
-    });
-    result
+    disk.checksum(|&id| id)
 }
 
+/// Moves each whole file, highest id first, into the leftmost gap before it that fits.
 pub fn part2(input: &Input) -> u64 {
-    let mut right = input.len() - 1;
-    let mut disk_memory: Vec<u64> = vec![];
-    let mut file_size = input[right];
-    let mut file_value = right / 2;
-    let mut moved_file_indexes: HashSet<usize> = HashSet::new();
+    let (mut disk, files, gaps) = disk(input);
+    // Gaps only ever shrink, so one too small for a size stays too small, and each search for a
+    // size resumes from the gap the previous one found.
+    let mut first = [0; 10];
 
-    for (left, &block_size) in input.iter().enumerate() {
-        if left >= right {
-            for _ in 0..file_size {
-                disk_memory.push(file_value as u64);
-            }
-            break;
-        }
-        if moved_file_indexes.contains(&left) {
-            disk_memory.resize(disk_memory.len() + input[left] as usize, 0);
+    for &file in files.iter().rev() {
+        let run = *disk.get(file);
+        let skip = &mut first[run.length as usize];
+        let Some(offset) = gaps[*skip..]
+            .iter()
+            .position(|&gap| disk.get(gap).length >= run.length)
+        else {
+            *skip = gaps.len();
             continue;
+        };
+        *skip += offset;
+
+        let gap = gaps[*skip];
+        if disk.get(gap).start < run.start {
+            disk.relocate(file, gap, None);
         }
+    }
 
-        if left % 2 == 0 {
-            for _ in 0..block_size {
-                disk_memory.push((left / 2) as u64);
-            }
-        } else {
-            let mut empty_block_size = block_size;
+    disk.checksum(|&id| id)
+}
 
-            while empty_block_size > 0 {
-                for index in (0..=right).rev().step_by(2) {
-                    if index <= left || empty_block_size == 0 {
-                        disk_memory.resize(disk_memory.len() + empty_block_size as usize, 0);
-                        empty_block_size = 0;
-                        break;
-                    }
-                    if moved_file_indexes.contains(&index) {
-                        if index == right {
-                            right = right.saturating_sub(2);
-                        }
-                        continue;
-                    }
-                    file_size = input[index];
-                    file_value = index / 2;
-                    if file_size > empty_block_size {
-                        continue;
-                    }
-                    moved_file_indexes.insert(index);
+fn disk(input: &Input) -> Disk {
+    let mut disk = Runs::new();
+    let mut files = Vec::new();
+    let mut gaps = Vec::new();
 
-                    if index == right {
-                        right = right.saturating_sub(2);
-                    }
-                    for _ in (0..file_size).rev() {
-                        disk_memory.push(file_value as u64);
-                        empty_block_size = empty_block_size.saturating_sub(1);
-                    }
-                    break;
-                }
-            }
+    for (index, &size) in input.iter().enumerate() {
+        if index % 2 == 0 {
+            files.push(disk.push(Some((index / 2) as u64), size as u64));
+        } else {
+            gaps.push(disk.push(None, size as u64));
         }
     }
 
-    let mut result: u64 = 0;
-    disk_memory.iter().enumerate().for_each(|(index, &value)| {
-        result = result.saturating_add(value * index as u64);
-    });
-    result
+    (disk, files, gaps)
 }
@@ -0,0 +1,54 @@
+use aoc::ffi::*;
+use std::ffi::{c_char, CStr};
+use std::ptr;
+
+const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+#[test]
+fn aoc_solve_test() {
+    let mut part1: *mut c_char = ptr::null_mut();
+    let mut part2: *mut c_char = ptr::null_mut();
+
+    unsafe {
+        let status = aoc_solve(
+            2024,
+            1,
+            EXAMPLE.as_ptr(),
+            EXAMPLE.len(),
+            &mut part1,
+            &mut part2,
+        );
+        assert_eq!(status, AOC_OK);
+        assert_eq!(CStr::from_ptr(part1).to_str(), Ok("11"));
+        assert_eq!(CStr::from_ptr(part2).to_str(), Ok("31"));
+
+        aoc_free_string(part1);
+        aoc_free_string(part2);
+    }
+}
+
+#[test]
+fn aoc_solve_not_found_test() {
+    let mut part1: *mut c_char = ptr::null_mut();
+    let mut part2: *mut c_char = ptr::null_mut();
+
+    let status = unsafe {
+        aoc_solve(
+            2024,
+            26,
+            EXAMPLE.as_ptr(),
+            EXAMPLE.len(),
+            &mut part1,
+            &mut part2,
+        )
+    };
+    assert_eq!(status, AOC_NOT_FOUND);
+    assert!(part1.is_null() && part2.is_null());
+}
@@ -0,0 +1,72 @@
+use aoc::runner::baseline::*;
+use aoc::util::config::Config;
+use std::time::Duration;
+
+#[test]
+fn from_config_test() {
+    let store = Config::parse("[timings.2024]\n01 = 412\n06 = 18342\n").unwrap();
+    let baseline = Baseline::from_config(&store);
+
+    assert_eq!(baseline.len(), 2);
+    assert_eq!(baseline.get(2024, 6), Some(Duration::from_micros(18342)));
+    assert_eq!(baseline.get(2024, 2), None);
+    assert!(Baseline::from_config(&Config::default()).is_empty());
+}
+
+#[test]
+fn change_test() {
+    let micros = Duration::from_micros;
+
+    let change = Change::new(micros(1000), micros(1234), THRESHOLD);
+    assert_eq!(format_percent(change.percent()), "+23.4%");
+    assert!(change.regressed);
+
+    let change = Change::new(micros(1000), micros(950), THRESHOLD);
+    assert_eq!(format_percent(change.percent()), "-5.0%");
+    assert!(!change.regressed);
+
+    // Within the threshold, or too few microseconds to tell from noise
+    assert!(!Change::new(micros(1000), micros(1080), THRESHOLD).regressed);
+    assert!(Change::new(micros(1000), micros(1080), 5).regressed);
+    assert!(!Change::new(micros(4), micros(40), THRESHOLD).regressed);
+    assert!(Change::new(Duration::ZERO, micros(60), THRESHOLD).regressed);
+}
+
+#[test]
+fn load_test() {
+    let path = std::env::temp_dir().join(format!("aoc-baseline-{}.toml", std::process::id()));
+    assert!(matches!(
+        Baseline::load(&path),
+        Err(BaselineError::Io { .. })
+    ));
+
+    std::fs::write(&path, "[timings.2024]\n01 = 412\n").unwrap();
+    let baseline = Baseline::load(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(
+        baseline.unwrap().get(2024, 1),
+        Some(Duration::from_micros(412))
+    );
+}
+
+#[test]
+fn load_invalid_test() {
+    let path = std::env::temp_dir().join(format!("aoc-baseline-bad-{}.toml", std::process::id()));
+    std::fs::write(&path, "[timings.2024]\n01 = 412\n06 = fast\n").unwrap();
+    let baseline = Baseline::load(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    let file = path.display().to_string();
+    let error = baseline.unwrap_err();
+    assert_eq!(
+        error,
+        BaselineError::Invalid {
+            path: file.clone(),
+            key: "timings.2024.06".to_string()
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        format!("Invalid baseline {file}: timings.2024.06 is not a timing")
+    );
+}
@@ -0,0 +1,61 @@
+use aoc::runner::cache::*;
+use aoc::util::config::Config;
+use aoc::*;
+use std::time::Duration;
+
+#[test]
+fn cache_test() {
+    let entry = Entry {
+        answers: (
+            Answer::Number(41),
+            Answer::Grid(vec!["#..#".to_string(), "####".to_string()]),
+        ),
+        elapsed: Duration::from_micros(1830),
+        phases: Phases {
+            parse: Duration::from_micros(40),
+            parts: Some([Duration::from_micros(90), Duration::from_micros(1700)]),
+            solve: Duration::from_micros(1790),
+        },
+    };
+    let mut cache = Cache::new("build", Config::default());
+    assert_eq!(cache.get(2024, 6, 7), None);
+
+    cache.insert(2024, 6, 7, &entry);
+    assert_eq!(cache.get(2024, 6, 7), Some(entry.clone()));
+    assert_eq!(cache.get(2024, 6, 8), None);
+    assert_eq!(cache.get(2024, 5, 7), None);
+
+    // Entries survive a round trip through the file, but not a rebuild
+    let path = std::env::temp_dir().join(format!("aoc-cache-{}.toml", std::process::id()));
+    cache.save(&path).unwrap();
+    let store = Config::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(
+        Cache::new("build", store.clone()).get(2024, 6, 7),
+        Some(entry)
+    );
+    assert_eq!(Cache::new("rebuild", store).get(2024, 6, 7), None);
+}
+
+#[test]
+fn corrupt_entry_test() {
+    let entry = "[cache.2024.06]\nbuild = build\ninput = 0000000000000007\npart1 = 41\npart2 = 6\n";
+    let timings = "parse_us = 40\nsolve_us = 1790\n";
+
+    let valid = Config::parse(&format!("{entry}elapsed_us = 1830\n{timings}")).unwrap();
+    assert!(Cache::new("build", valid).get(2024, 6, 7).is_some());
+
+    for corrupt in [
+        format!("{entry}elapsed_us = 18x0\n{timings}"),
+        format!("{entry}elapsed_us = 1830\n{timings}part1_us = \"90 us\"\npart2_us = 1700\n"),
+    ] {
+        let store = Config::parse(&corrupt).unwrap();
+        assert_eq!(Cache::new("build", store).get(2024, 6, 7), None);
+    }
+}
+
+#[test]
+fn build_test() {
+    assert_eq!(build(), build());
+    assert_eq!(build().unwrap().len(), 16);
+}
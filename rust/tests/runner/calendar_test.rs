@@ -0,0 +1,73 @@
+use aoc::runner::calendar::*;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The instant of a UTC date and time.
+fn utc(year: u32, month: u32, day: u32, hour: u64, minute: u64, second: u64) -> SystemTime {
+    let days = days_from_civil(year, month, day) as u64;
+    UNIX_EPOCH + Duration::from_secs(days * 86_400 + hour * 3600 + minute * 60 + second)
+}
+
+#[test]
+fn civil_test() {
+    assert_eq!(days_from_civil(1970, 1, 1), 0);
+    assert_eq!(days_from_civil(2024, 12, 1), 20_058);
+    assert_eq!(civil_from_days(20_058), (2024, 12, 1));
+    assert_eq!(civil_from_days(days_from_civil(2000, 2, 29)), (2000, 2, 29));
+    assert_eq!(civil_from_days(-1), (1969, 12, 31));
+
+    for days in (0..30_000).step_by(17) {
+        let (year, month, day) = civil_from_days(days);
+        assert_eq!(days_from_civil(year, month, day), days);
+    }
+}
+
+#[test]
+fn today_test() {
+    // Day 6 unlocks at midnight in New York, 05:00 UTC
+    assert_eq!(
+        today(utc(2024, 12, 6, 5, 0, 0)),
+        Today::Unlocked { year: 2024, day: 6 }
+    );
+    assert_eq!(
+        today(utc(2024, 12, 6, 4, 59, 59)),
+        Today::Unlocked { year: 2024, day: 5 }
+    );
+    assert_eq!(
+        today(utc(2024, 12, 1, 4, 0, 0)),
+        Today::Locked {
+            year: 2024,
+            day: 1,
+            remaining: Duration::from_secs(3600)
+        }
+    );
+    assert_eq!(
+        today(utc(2024, 12, 26, 5, 0, 0)),
+        Today::Locked {
+            year: 2025,
+            day: 1,
+            remaining: Duration::from_secs(340 * 86_400)
+        }
+    );
+    // Events from 2025 on end after day 12
+    assert_eq!(
+        today(utc(2025, 12, 12, 5, 0, 0)),
+        Today::Unlocked {
+            year: 2025,
+            day: 12
+        }
+    );
+    assert!(matches!(
+        today(utc(2025, 12, 13, 5, 0, 0)),
+        Today::Locked { year: 2026, .. }
+    ));
+    assert_eq!(days_in(2024), 25);
+}
+
+#[test]
+fn format_remaining_test() {
+    assert_eq!(format_remaining(Duration::from_secs(299)), "00:04:59");
+    assert_eq!(
+        format_remaining(Duration::from_secs(45 * 86_400 + 3 * 3600 + 12 * 60 + 9)),
+        "45d 03:12:09"
+    );
+}
@@ -0,0 +1,181 @@
+use aoc::runner::cli::*;
+use aoc::runner::settings::Settings;
+use aoc::*;
+
+fn parse_args(line: &str) -> Result<Options, CliError> {
+    parse(line.split_whitespace().map(str::to_string))
+}
+
+#[test]
+fn run_test() {
+    let options = parse_args("").unwrap();
+    assert_eq!(options.command, Command::Run);
+    assert_eq!(options.filter, Filter::default());
+    assert_eq!(options.part, None);
+
+    let options = parse_args("2024.06 --bench").unwrap();
+    assert_eq!(options.filter, Filter::new(Some(2024), Some(6)));
+    assert!(options.bench);
+    assert!(!options.parallel);
+
+    let options = parse_args("--parallel 2024 6 2").unwrap();
+    assert_eq!(options.filter, Filter::new(Some(2024), Some(6)));
+    assert_eq!(options.part, Some(2));
+    assert!(options.parallel);
+}
+
+#[test]
+fn flag_value_test() {
+    assert_eq!(parse_args("--part 2 2024").unwrap().part, Some(2));
+    assert_eq!(parse_args("2024 --part=1").unwrap().part, Some(1));
+    assert_eq!(
+        parse_args("2024 --part=1").unwrap().filter,
+        Filter::new(Some(2024), None)
+    );
+    assert_eq!(
+        parse_args("--part").unwrap_err(),
+        CliError::MissingValue("--part")
+    );
+    assert_eq!(
+        parse_args("--part 3").unwrap_err(),
+        CliError::InvalidValue {
+            flag: "--part",
+            value: "3".to_string()
+        }
+    );
+    assert!(parse_args("--bench=yes").is_err());
+}
+
+#[test]
+fn command_test() {
+    let options = parse_args("serve 0.0.0.0:9000 --step").unwrap();
+    assert_eq!(options.command, Command::Serve);
+    assert_eq!(options.arguments, vec!["0.0.0.0:9000"]);
+    assert!(options.step);
+
+    let options = parse_args("cross-check 2024.3").unwrap();
+    assert_eq!(options.command, Command::CrossCheck);
+    assert_eq!(options.filter, Filter::new(Some(2024), Some(3)));
+
+    assert_eq!(parse_args("scrub 2024 1 42").unwrap().arguments.len(), 3);
+
+    let options = parse_args("samples 2024 6").unwrap();
+    assert_eq!(options.command, Command::Samples);
+    assert_eq!(options.arguments, vec!["2024", "6"]);
+
+    let options = parse_args("today --bench").unwrap();
+    assert_eq!(options.command, Command::Today);
+    assert!(options.bench);
+
+    let options = parse_args("replay run.json --step").unwrap();
+    assert_eq!(options.command, Command::Replay);
+    assert_eq!(options.arguments, vec!["run.json"]);
+
+    let options = parse_args("2024.6 --record=run.json").unwrap();
+    assert_eq!(options.record.as_deref(), Some("run.json"));
+    assert_eq!(
+        parse_args("--max-frames 500").unwrap().max_frames,
+        Some(500)
+    );
+    assert!(parse_args("--max-frames 1").is_err());
+    assert_eq!(parse_args("--output=csv").unwrap().output, Output::Csv);
+    assert_eq!(parse_args("").unwrap().output, Output::Text);
+    assert!(parse_args("--output xml").is_err());
+    assert!(parse_args("2024 --notify").unwrap().notify);
+    assert!(parse_args("2024 --strict").unwrap().strict);
+    assert_eq!(
+        parse_args("2024 --timeout 1500").unwrap().timeout,
+        Some(std::time::Duration::from_millis(1500))
+    );
+    assert!(parse_args("2024 --timeout 0").is_err());
+    assert_eq!(parse_args("2024").unwrap().runs, 1);
+    assert_eq!(parse_args("2024 --runs 10").unwrap().runs, 10);
+    assert!(parse_args("2024 --runs 0").is_err());
+    assert_eq!(
+        parse_args("2024 --report html").unwrap().report,
+        Some(Report::Html)
+    );
+    assert!(parse_args("2024 --report pdf").is_err());
+    assert_eq!(
+        parse_args("2024 --slower-than 2.5").unwrap().slower_than,
+        Some(std::time::Duration::from_micros(2500))
+    );
+    assert!(parse_args("2024 --slower-than -1").is_err());
+    assert_eq!(
+        parse_args("2024 --baseline old.toml").unwrap().baseline,
+        Some("old.toml".to_string())
+    );
+    assert_eq!(
+        parse_args("2024.1 --example").unwrap().input_set.as_deref(),
+        Some("example")
+    );
+    assert_eq!(
+        parse_args("2024.1 --input-set sample1")
+            .unwrap()
+            .input_set
+            .as_deref(),
+        Some("sample1")
+    );
+    assert!(parse_args("2024.1 --input-set ../real").is_err());
+    assert!(parse_args("2024.1 --input-set sample1 --example").is_err());
+    assert!(parse_args("2024 --redact").unwrap().redact);
+    assert!(parse_args("2024 --no-cache").unwrap().no_cache);
+    assert!(parse_args("2024 --slower-than fast").is_err());
+
+    let options = parse_args("2024 6 --input example.txt").unwrap();
+    assert_eq!(options.filter, Filter::new(Some(2024), Some(6)));
+    assert_eq!(options.input.as_deref(), Some("example.txt"));
+}
+
+#[test]
+fn error_test() {
+    assert_eq!(
+        parse_args("--verbose").unwrap_err(),
+        CliError::UnknownFlag("--verbose".to_string())
+    );
+    assert_eq!(
+        parse_args("srve").unwrap_err(),
+        CliError::UnexpectedArgument("srve".to_string())
+    );
+    assert!(parse_args("2024 1 2 3").is_err());
+    assert!(parse_args("-h").unwrap().help);
+}
+
+#[test]
+fn help_test() {
+    let text = help();
+    assert!(text.starts_with("Usage: aoc"));
+    for flag in FLAGS {
+        assert!(text.contains(flag.name));
+    }
+    for command in COMMANDS {
+        assert!(text.contains(command.name));
+    }
+}
+
+#[test]
+fn defaults_test() {
+    let defaults = Settings {
+        year: Some(2024),
+        output: Some(Output::Csv),
+        ..Settings::default()
+    };
+    let parse_with_defaults =
+        |line: &str| parse_with(line.split_whitespace().map(str::to_string), &defaults).unwrap();
+
+    assert_eq!(
+        parse_with_defaults("").filter,
+        Filter::new(Some(2024), None)
+    );
+    assert_eq!(
+        parse_with_defaults("6 2").filter,
+        Filter::new(Some(2024), Some(6))
+    );
+    assert_eq!(parse_with_defaults("6 2").part, Some(2));
+    assert_eq!(
+        parse_with_defaults("2023.1").filter,
+        Filter::new(Some(2023), Some(1))
+    );
+    assert_eq!(parse_with_defaults("").output, Output::Csv);
+    assert_eq!(parse_with_defaults("--output text").output, Output::Text);
+}
@@ -0,0 +1,61 @@
+use aoc::runner::cross_check::*;
+use aoc::util::config::Config;
+use aoc::*;
+
+#[test]
+fn answers_test() {
+    assert_eq!(
+        answers("11\n31\n"),
+        Some(("11".to_string(), "31".to_string()))
+    );
+    assert_eq!(
+        answers("Reading input\nPart 1: 11\nPart 2: 31\n\n"),
+        Some(("11".to_string(), "31".to_string()))
+    );
+    assert_eq!(answers("only one\n"), None);
+}
+
+#[test]
+fn commands_test() {
+    let config = Config::parse(
+        "[cross-check.2024]\n03 = \"./day3\"\n01 = \"./01\"\n[other]\n02 = \"ignored\"\n",
+    )
+    .unwrap();
+    assert_eq!(
+        commands(&config),
+        Ok(vec![
+            (2024, 1, "./01".to_string()),
+            (2024, 3, "./day3".to_string())
+        ])
+    );
+}
+
+#[test]
+fn invalid_key_test() {
+    let config = Config::parse("[cross-check.2024]\n01 = \"./01\"\nday3 = \"./day3\"\n").unwrap();
+    let error = CrossCheckError::Config("cross-check.2024.day3".to_string());
+    assert_eq!(commands(&config), Err(error.clone()));
+    assert_eq!(
+        error.to_string(),
+        "Invalid cross-check key cross-check.2024.day3, expected a year and day"
+    );
+
+    let config = Config::parse("[cross-check.next]\n01 = \"./01\"\n").unwrap();
+    assert!(cross_check(&config, &Filter::default(), &RunOptions::default()).is_err());
+}
+
+#[test]
+fn unknown_day_test() {
+    let config = Config::parse("[cross-check.2024]\n26 = \"true\"\n").unwrap();
+    let filter = Filter::default();
+    let checks: Vec<_> = cross_check(&config, &filter, &RunOptions::default())
+        .unwrap()
+        .collect();
+    assert_eq!(
+        checks[0].outcome,
+        Err(CrossCheckError::Run(RunError::NotFound {
+            year: 2024,
+            day: 26
+        }))
+    );
+}
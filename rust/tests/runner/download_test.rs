@@ -0,0 +1,37 @@
+use aoc::runner::download::*;
+use aoc::runner::registry::solutions;
+use aoc::util::config::Config;
+
+#[test]
+fn session_test() {
+    let config = Config::parse("[download]\nsession = \"from-file\"\n").unwrap();
+    assert_eq!(session(&config, None), Some("from-file".to_string()));
+    assert_eq!(
+        session(&config, Some("from-env\n".to_string())),
+        Some("from-env".to_string())
+    );
+    assert_eq!(session(&Config::default(), Some(" ".to_string())), None);
+    assert_eq!(session(&Config::default(), None), None);
+}
+
+#[test]
+fn url_test() {
+    assert_eq!(url(2024, 6), "https://adventofcode.com/2024/day/6/input");
+    assert_eq!(puzzle_url(2024, 6), "https://adventofcode.com/2024/day/6");
+    assert_eq!(
+        puzzle_path(2024, 6),
+        std::path::Path::new("puzzles/year2024/day06.html")
+    );
+}
+
+#[test]
+fn ensure_test() {
+    let mut solution = solutions().remove(0);
+
+    solution.path = std::env::temp_dir().join("aoc_download_test_missing/day01.txt");
+    assert_eq!(ensure(&solution, None), Err(DownloadError::NoSession));
+
+    solution.path = std::env::temp_dir().join("aoc_download_test_present.txt");
+    std::fs::write(&solution.path, "1 2\n").unwrap();
+    assert_eq!(ensure(&solution, None), Ok(false));
+}
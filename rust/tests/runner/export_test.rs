@@ -0,0 +1,20 @@
+use aoc::runner::export::*;
+use aoc::*;
+use std::time::Duration;
+
+#[test]
+fn csv_row_test() {
+    let phases = Phases {
+        parse: Duration::from_micros(52),
+        parts: Some([Duration::from_micros(9), Duration::from_micros(14)]),
+        solve: Duration::from_micros(23),
+    };
+    assert_eq!(csv_row(2024, 1, &phases), "2024,1,52,9,14,75");
+    assert_eq!(CSV_HEADER.split(',').count(), 6);
+
+    let both = Phases {
+        parts: None,
+        ..phases
+    };
+    assert_eq!(csv_row(2024, 6, &both), "2024,6,52,,,75");
+}
@@ -0,0 +1,43 @@
+use aoc::runner::html::*;
+use aoc::runner::report::DayReport;
+use aoc::*;
+
+#[test]
+fn to_html_test() {
+    let reports = vec![
+        DayReport::new(
+            &solve(
+                2024,
+                1,
+                "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n".to_string(),
+            ),
+            Vec::new(),
+        ),
+        DayReport::new(&solve(2015, 1, String::new()), Vec::new()),
+    ];
+    let page = to_html(&reports);
+
+    assert!(page.starts_with("<!DOCTYPE html>"));
+    assert!(page.trim_end().ends_with("</html>"));
+    assert!(!page.contains("<script") && !page.contains("<link"));
+    assert!(page.contains("Solved 1 of 2 days"));
+    assert!(page.contains("<td class=\"answer\">11</td><td class=\"answer\">31</td>"));
+    assert!(page.contains("No solution for 2015 Day 01"));
+    // Only the solved day gets a bar
+    assert_eq!(page.matches("<rect class=\"solve\"").count(), 1);
+}
+
+#[test]
+fn empty_test() {
+    let page = to_html(&[]);
+    assert!(page.contains("Solved 0 of 0 days"));
+    assert!(!page.contains("<svg"));
+}
+
+#[test]
+fn escape_test() {
+    assert_eq!(
+        escape("<a href=\"x\">Tom & Jerry's</a>"),
+        "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;"
+    );
+}
@@ -0,0 +1,26 @@
+use aoc::runner::memory::*;
+use aoc::*;
+
+#[test]
+fn measure_test() {
+    let (length, peak) = measure(|| vec![0_u8; 1 << 20].len());
+    assert_eq!(length, 1 << 20);
+
+    if enabled() {
+        // Other tests allocate and free concurrently, so the exact peak is not reliable here
+        assert!(peak.is_some());
+        assert!(solve(2024, 1, "3   4\n".to_string()).peak_memory.is_some());
+    } else {
+        assert_eq!(peak, None);
+        assert_eq!(solve(2024, 1, "3   4\n".to_string()).peak_memory, None);
+    }
+}
+
+#[test]
+fn format_bytes_test() {
+    assert_eq!(format_bytes(0), "0 B");
+    assert_eq!(format_bytes(1023), "1023 B");
+    assert_eq!(format_bytes(1536), "1.5 KiB");
+    assert_eq!(format_bytes(12 << 20), "12.0 MiB");
+    assert_eq!(format_bytes(3 << 30), "3.0 GiB");
+}
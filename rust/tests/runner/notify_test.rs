@@ -0,0 +1,32 @@
+use aoc::runner::notify::*;
+use aoc::util::config::Config;
+use std::fs::{read_to_string, remove_file};
+use std::time::Duration;
+
+#[test]
+fn message_test() {
+    assert_eq!(
+        message(10, 0, Duration::from_millis(1234)),
+        "Solved 10 in 1234 ms"
+    );
+    assert_eq!(
+        message(10, 2, Duration::from_millis(5)),
+        "Solved 10 in 5 ms, 2 wrong"
+    );
+}
+
+#[test]
+fn notify_test() {
+    let path = std::env::temp_dir().join(format!("aoc-notify-{}", std::process::id()));
+    let config = Config::parse(&format!(
+        "[notify]\ncommand = \"printf '%s' \\\"$1\\\" > {}\"\n",
+        path.display()
+    ))
+    .unwrap();
+
+    assert!(command(&Config::default()).is_none());
+    notify(&config, "Solved 1 in 2 ms").unwrap();
+    assert_eq!(read_to_string(&path).unwrap(), "Solved 1 in 2 ms");
+
+    remove_file(&path).unwrap();
+}
@@ -0,0 +1,11 @@
+use aoc::runner::progress::*;
+
+#[test]
+fn bar_test() {
+    assert_eq!(bar(0, 4), format!("[{}] 0% (0/4)", ".".repeat(30)));
+    assert_eq!(
+        bar(1500, 5000),
+        format!("[{}{}] 30% (1500/5000)", "#".repeat(9), ".".repeat(21))
+    );
+    assert_eq!(bar(7, 5), format!("[{}] 100% (5/5)", "#".repeat(30)));
+}
@@ -0,0 +1,47 @@
+use aoc::runner::report::*;
+use aoc::util::debugger::Frame;
+use aoc::*;
+use std::time::Duration;
+
+#[test]
+fn round_trip_test() {
+    let result = solve(
+        2024,
+        1,
+        "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n".to_string(),
+    );
+    let frames = vec![Frame {
+        label: "step 1".to_string(),
+        image: "#.\n.#\n".to_string(),
+    }];
+    let reports = vec![
+        DayReport::new(&result, frames),
+        DayReport::new(&solve(2015, 1, String::new()), Vec::new()),
+    ];
+
+    let loaded = from_json(&to_json(&reports)).unwrap();
+    assert_eq!(
+        loaded[0].outcome,
+        Ok((Answer::Number(11), Answer::Number(31)))
+    );
+    assert_eq!(loaded[0].frames[0].image, "#.\n.#\n");
+    assert_eq!(
+        loaded[0].elapsed,
+        Duration::from_micros(result.elapsed.as_micros() as u64)
+    );
+    assert_eq!(
+        loaded[1].outcome,
+        Err("No solution for 2015 Day 01".to_string())
+    );
+    assert!(loaded[1].frames.is_empty());
+}
+
+#[test]
+fn invalid_test() {
+    assert_eq!(from_json("{}"), Err(ReportError::Invalid("days")));
+    assert_eq!(
+        from_json(r#"{"days":[{"year":2024,"day":1,"part1":"1","elapsed_us":5}]}"#),
+        Err(ReportError::Invalid("part2"))
+    );
+    assert!(matches!(from_json("{"), Err(ReportError::Json(_))));
+}
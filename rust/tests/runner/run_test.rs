@@ -0,0 +1,174 @@
+use aoc::runner::run::{iter_parallel, supervise, EXIT_MISMATCH, EXIT_NOT_FOUND};
+use aoc::*;
+use std::time::Duration;
+
+const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+#[test]
+fn solve_test() {
+    let result = solve(2024, 1, EXAMPLE.to_string());
+    assert_eq!(result.outcome, Ok((Answer::Number(11), Answer::Number(31))));
+
+    let [part1, part2] = result.phases.parts.unwrap();
+    assert!(result.phases.solve >= part1 + part2);
+    assert!(result.phases.total() <= result.elapsed);
+}
+
+#[test]
+fn runs_test() {
+    let options = RunOptions {
+        runs: 3,
+        ..Default::default()
+    };
+    let result = solve_with(2024, 1, EXAMPLE.to_string(), &options);
+
+    assert_eq!(result.outcome, Ok((Answer::Number(11), Answer::Number(31))));
+    assert_eq!(result.samples.len(), 3);
+    assert_eq!(result.samples.iter().min(), Some(&result.elapsed));
+    assert!(solve_with(2024, 26, EXAMPLE.to_string(), &options)
+        .samples
+        .is_empty());
+    assert_eq!(solve(2024, 1, EXAMPLE.to_string()).samples.len(), 1);
+}
+
+#[test]
+fn phases_both_test() {
+    let result = solve(2024, 6, "#.\n^.\n".to_string());
+    assert!(result.outcome.is_ok());
+    assert_eq!(result.phases.parts, None);
+}
+
+#[test]
+fn solve_not_found_test() {
+    let result = solve(2024, 26, EXAMPLE.to_string());
+    assert_eq!(
+        result.outcome,
+        Err(RunError::NotFound {
+            year: 2024,
+            day: 26
+        })
+    );
+}
+
+#[test]
+fn solve_panicked_test() {
+    // Without a guard the solution cannot find where to start.
+    let result = solve(2024, 6, "..\n".to_string());
+    assert!(matches!(result.outcome, Err(RunError::Panicked(_))));
+    assert!(matches!(
+        solve_part(2024, 6, 1, "..\n".to_string()),
+        Err(RunError::Panicked(_))
+    ));
+}
+
+#[test]
+fn supervise_test() {
+    let limit = Some(Duration::from_millis(20));
+
+    assert_eq!(supervise(None, || 42), Ok(42));
+    assert_eq!(supervise(limit, || 42), Ok(42));
+    assert_eq!(
+        supervise(limit, || std::thread::sleep(Duration::from_secs(1))),
+        Err(RunError::TimedOut(Duration::from_millis(20)))
+    );
+    // A generous limit, as printing a backtrace with RUST_BACKTRACE set takes a while
+    for limit in [None, Some(Duration::from_secs(10))] {
+        match supervise(limit, || -> u32 { panic!("boom") }) {
+            Err(RunError::Panicked(message)) => {
+                assert!(message.starts_with("boom at tests/runner/run_test.rs:"))
+            }
+            other => panic!("Unexpected {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn exit_code_test() {
+    let panicked = RunError::Panicked("boom".to_string());
+    let missing = RunError::MissingInput("input/year2024/day01.txt".into());
+
+    assert_eq!(panicked.to_string(), "Panicked: boom");
+    assert!(RunError::TimedOut(Duration::from_secs(1)).exit_code() > panicked.exit_code());
+    assert!(panicked.exit_code() > missing.exit_code());
+    assert!(missing.exit_code() > EXIT_MISMATCH);
+    assert_eq!(
+        RunError::NotFound {
+            year: 2024,
+            day: 26
+        }
+        .exit_code(),
+        EXIT_NOT_FOUND
+    );
+}
+
+#[test]
+fn answer_round_trip_test() {
+    for answer in [
+        Answer::Number(-42),
+        Answer::Text("ABCDEFGH".to_string()),
+        Answer::Grid(vec!["#..#".to_string(), "####".to_string()]),
+    ] {
+        assert_eq!(Answer::from(answer.to_string()), answer);
+    }
+}
+
+#[test]
+fn answer_matches_test() {
+    assert!(Answer::Number(42).matches(&Answer::Text("042".to_string())));
+    assert!(!Answer::Number(42).matches(&Answer::Text("43".to_string())));
+    assert!(!Answer::Text("ab".to_string()).matches(&Answer::Text("AB".to_string())));
+    assert_eq!(Answer::from(u64::MAX), Answer::Text(u64::MAX.to_string()));
+}
+
+#[test]
+fn answer_redacted_test() {
+    let redacted = Answer::Number(42).redacted();
+    assert_eq!(redacted, Answer::Text("#56ee3cec".to_string()));
+    assert_eq!(Answer::Text("042".to_string()).redacted(), redacted);
+    assert_ne!(Answer::Number(43).redacted(), redacted);
+}
+
+#[test]
+fn solve_part_test() {
+    assert_eq!(
+        solve_part(2024, 1, 1, EXAMPLE.to_string()),
+        Ok(Answer::Number(11))
+    );
+    assert_eq!(
+        solve_part(2024, 1, 2, EXAMPLE.to_string()),
+        Ok(Answer::Number(31))
+    );
+    assert_eq!(
+        solve_part(2024, 26, 1, EXAMPLE.to_string()),
+        Err(RunError::NotFound {
+            year: 2024,
+            day: 26
+        })
+    );
+}
+
+#[test]
+fn iter_parallel_order_test() {
+    let filter = Filter::new(Some(2024), None);
+    let sequential = run(&filter, &RunOptions::default());
+    let parallel: Vec<DayResult> = iter_parallel(&filter, 4, &RunOptions::default()).collect();
+
+    let days = |results: &[DayResult]| -> Vec<(u32, u32)> {
+        results
+            .iter()
+            .map(|result| (result.year, result.day))
+            .collect()
+    };
+    assert_eq!(days(&parallel), days(&sequential));
+    assert_eq!(
+        iter_parallel(&Filter::new(Some(1999), None), 4, &RunOptions::default()).count(),
+        0
+    );
+}
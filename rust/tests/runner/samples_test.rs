@@ -0,0 +1,65 @@
+use aoc::runner::samples::*;
+use std::fs::{create_dir_all, read_to_string, remove_dir_all, write};
+
+const PAGE: &str = "\
+<main>
+<article class=\"day-desc\"><h2>--- Day 1: Test ---</h2>
+<p>For example:</p>
+<pre><code>3   4
+4   &lt;3
+</code></pre>
+<p>The distance in line <code>1</code> is <code><em>2</em></code>.</p>
+<p>In total, that is <code><em>11</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2>
+<pre><code><em>3</em>   4
+4   &lt;3
+</code></pre>
+<pre><code>1 2
+</code></pre>
+<p>So, the answer is <em><code>31</code></em>.</p>
+</article>
+</main>";
+
+#[test]
+fn samples_test() {
+    assert_eq!(samples(PAGE), ["3   4\n4   <3\n", "1 2\n"]);
+    assert!(samples("<p>No examples</p>").is_empty());
+}
+
+#[test]
+fn example_answers_test() {
+    assert_eq!(example_answers(PAGE), ["11", "31"]);
+    assert_eq!(
+        example_answers("<article><p>Nothing</p></article>"),
+        [] as [&str; 0]
+    );
+}
+
+#[test]
+fn save_test() {
+    let root = std::env::temp_dir().join(format!("aoc-samples-{}", std::process::id()));
+    let (inputs, answers) = (root.join("input"), root.join("answers"));
+    create_dir_all(inputs.join("day01")).unwrap();
+    write(inputs.join("day01/sample2.txt"), "fixed by hand\n").unwrap();
+
+    let written = save(PAGE, &inputs, &answers, 1).unwrap();
+    assert_eq!(
+        written,
+        [
+            inputs.join("day01/sample1.txt"),
+            answers.join("day01/sample1.txt")
+        ]
+    );
+    assert_eq!(
+        read_to_string(inputs.join("day01/sample2.txt")).unwrap(),
+        "fixed by hand\n"
+    );
+    assert_eq!(
+        read_to_string(answers.join("day01/sample1.txt")).unwrap(),
+        "Part 1: 11\nPart 2: 31\n"
+    );
+    assert!(save(PAGE, &inputs, &answers, 1).unwrap().is_empty());
+    remove_dir_all(&root).unwrap();
+}
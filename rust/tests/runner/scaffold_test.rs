@@ -0,0 +1,88 @@
+use aoc::runner::scaffold::*;
+use std::fs::{copy, create_dir_all, read_to_string, remove_dir_all};
+use std::path::Path;
+
+const LIB: &str = "\
+pub mod runner {
+    pub mod run;
+}
+
+/// # Year 2024.
+pub mod year2024 {
+    pub mod day01;
+    pub mod day03;
+}
+";
+
+#[test]
+fn register_module_test() {
+    let text = register_module(LIB, 2024, 2).unwrap();
+    assert!(text.contains("    pub mod day01;\n    pub mod day02;\n    pub mod day03;\n"));
+
+    let text = register_module(LIB, 2025, 1).unwrap();
+    assert!(text.ends_with("pub mod year2025 {\n    pub mod day01;\n}\n"));
+
+    assert_eq!(
+        register_module(LIB, 2024, 3),
+        Err(EditError::Duplicate("day03".to_string()))
+    );
+}
+
+#[test]
+fn register_solution_test() {
+    let registry = "\
+pub fn solutions() -> Vec<Solution> {
+    [year2024()].into_iter().flatten().collect()
+}
+
+fn year2024() -> Vec<Solution> {
+    vec![
+        solution!(year2024, day01),
+        solution!(year2024, day06, both),
+    ]
+}
+";
+    let text = register_solution(registry, 2024, 4).unwrap();
+    assert!(text.contains(
+        "        solution!(year2024, day01),\n        solution!(year2024, day04),\n        solution!(year2024, day06, both),\n"
+    ));
+    assert!(register_solution(registry, 2024, 6).is_err());
+
+    let text = register_solution(registry, 2025, 1).unwrap();
+    assert!(text.contains("[year2024(), year2025()].into_iter()"));
+    assert!(text.contains("fn year2025() -> Vec<Solution> {\n    vec![\n        solution!(year2025, day01),\n    ]\n}\n"));
+}
+
+#[test]
+fn scaffold_test() {
+    // Work on a copy of the real files.
+    let root = std::env::temp_dir().join("aoc_scaffold_test");
+    let _ = remove_dir_all(&root);
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+    for file in ["src/lib.rs", "tests/test.rs", "src/runner/registry.rs"] {
+        create_dir_all(root.join(file).parent().unwrap()).unwrap();
+        copy(manifest.join(file), root.join(file)).unwrap();
+    }
+
+    let written = scaffold(&root, 2024, 25).unwrap();
+    assert_eq!(written.len(), 5);
+    assert_eq!(
+        read_to_string(root.join("src/year2024/day25.rs")).unwrap(),
+        day_source()
+    );
+    assert!(read_to_string(root.join("tests/year2024/day25_test.rs"))
+        .unwrap()
+        .starts_with("use aoc::year2024::day25::*;"));
+    assert!(read_to_string(root.join("src/runner/registry.rs"))
+        .unwrap()
+        .contains("solution!(year2024, day25),"));
+
+    assert!(matches!(
+        scaffold(&root, 2024, 25),
+        Err(ScaffoldError::AlreadyExists(_))
+    ));
+    assert!(matches!(
+        scaffold(&root, 2024, 1),
+        Err(ScaffoldError::AlreadyExists(_))
+    ));
+}
@@ -0,0 +1,44 @@
+use aoc::runner::scrub::*;
+use aoc::util::rng::Rng;
+
+#[test]
+fn scrub_grid_test() {
+    let input = "....#\n.#..^\n#....\n";
+    let output = scrub(input, &mut Rng::new(7));
+
+    let lines: Vec<_> = output.lines().map(str::len).collect();
+    assert_eq!(lines, vec![5, 5, 5]);
+
+    let mut expected: Vec<_> = input.chars().collect();
+    let mut actual: Vec<_> = output.chars().collect();
+    expected.sort_unstable();
+    actual.sort_unstable();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn scrub_numbers_test() {
+    let input = "190: 10 19\n3267: 81 40 27\n";
+    let output = scrub(input, &mut Rng::new(7));
+
+    assert_eq!(output.lines().count(), 2);
+    for (original, scrubbed) in input.split_whitespace().zip(output.split_whitespace()) {
+        assert_eq!(original.len(), scrubbed.len());
+    }
+    for number in output
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|n| n.len() == 2)
+    {
+        let value: u32 = number.parse().unwrap();
+        assert!((10..=81).contains(&value));
+    }
+}
+
+#[test]
+fn scrub_long_run_test() {
+    let input = "2333133121414131402";
+    let output = scrub(input, &mut Rng::new(7));
+
+    assert_eq!(output.len(), input.len());
+    assert!(output.bytes().all(|b| (b'0'..=b'4').contains(&b)));
+}
@@ -0,0 +1,81 @@
+use aoc::runner::serve::*;
+use aoc::*;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::OnceLock;
+use std::thread;
+use std::time::Duration;
+
+/// Starts one server for all tests on a free local port.
+fn address() -> &'static str {
+    static ADDRESS: OnceLock<String> = OnceLock::new();
+    ADDRESS.get_or_init(|| {
+        let port = TcpListener::bind("127.0.0.1:0")
+            .and_then(|listener| listener.local_addr())
+            .unwrap()
+            .port();
+        let address = format!("127.0.0.1:{port}");
+        let server = address.clone();
+        thread::spawn(move || serve(server, &RunOptions::default()));
+
+        for _ in 0..100 {
+            if TcpStream::connect(&address).is_ok() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        address
+    })
+}
+
+fn request(raw: &str) -> String {
+    let mut stream = TcpStream::connect(address()).unwrap();
+    stream.write_all(raw.as_bytes()).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+#[test]
+fn solve_test() {
+    let body = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+    let response = request(&format!(
+        "POST /solve/2024/1 HTTP/1.1\r\nContent-Length: {}\r\n\r\n{body}",
+        body.len()
+    ));
+
+    assert!(response.starts_with("HTTP/1.1 200 OK"));
+    assert!(response.contains("\"part1\":\"11\",\"part2\":\"31\""));
+}
+
+#[test]
+fn invalid_content_length_test() {
+    let response = request("POST /solve/2024/1 HTTP/1.1\r\nContent-Length: many\r\n\r\n");
+    assert!(response.starts_with("HTTP/1.1 400 Bad Request"));
+}
+
+#[test]
+fn body_too_large_test() {
+    let response = request(&format!(
+        "POST /solve/2024/1 HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+        MAX_BODY + 1
+    ));
+    assert!(response.starts_with("HTTP/1.1 413 Payload Too Large"));
+
+    let response = request("POST /solve/2024/1 HTTP/1.1\r\nContent-Length: 99999999999999\r\n\r\n");
+    assert!(response.starts_with("HTTP/1.1 413 Payload Too Large"));
+}
+
+#[test]
+fn head_too_long_test() {
+    let target = "a".repeat(MAX_HEAD as usize);
+    let response = request(&format!("POST /{target} HTTP/1.1\r\n\r\n"));
+    assert!(response.starts_with("HTTP/1.1 431 Request Header Fields Too Large"));
+
+    let header = format!("X-Padding: {}\r\n", "a".repeat(100));
+    let response = request(&format!(
+        "POST /solve/2024/1 HTTP/1.1\r\n{}\r\n",
+        header.repeat(MAX_HEAD as usize / header.len() + 1)
+    ));
+    assert!(response.starts_with("HTTP/1.1 431 Request Header Fields Too Large"));
+}
@@ -0,0 +1,51 @@
+use aoc::runner::cli::Output;
+use aoc::runner::settings::*;
+use aoc::util::config::Config;
+use std::path::PathBuf;
+
+fn settings(text: &str) -> Result<Settings, SettingsError> {
+    Settings::from_config(&Config::parse(text).unwrap())
+}
+
+#[test]
+fn from_config_test() {
+    let text = "\
+[download]
+session = \"abc\"
+
+[run]
+input = \"../inputs\"
+year = 2024
+bench_runs = 50
+regression = 15
+output = \"csv\"
+";
+    assert_eq!(
+        settings(text),
+        Ok(Settings {
+            input: Some(PathBuf::from("../inputs")),
+            year: Some(2024),
+            bench_runs: Some(50),
+            regression: Some(15),
+            output: Some(Output::Csv),
+        })
+    );
+    assert_eq!(settings(""), Ok(Settings::default()));
+}
+
+#[test]
+fn invalid_value_test() {
+    assert_eq!(
+        settings("[run]\nbench_runs = 0\n"),
+        Err(SettingsError {
+            key: "bench_runs",
+            value: "0".to_string()
+        })
+    );
+    assert_eq!(
+        settings("[run]\noutput = \"xml\"\n")
+            .unwrap_err()
+            .to_string(),
+        "Invalid value \"xml\" for output in [run]"
+    );
+}
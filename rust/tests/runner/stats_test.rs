@@ -0,0 +1,68 @@
+use aoc::runner::registry::solutions;
+use aoc::runner::stats::*;
+use aoc::util::config::Config;
+use std::time::Duration;
+
+#[test]
+fn record_test() {
+    let mut store = Config::default();
+    record(&mut store, 2024, 6, Duration::from_micros(1500));
+    record(&mut store, 2024, 1, Duration::from_micros(20));
+    record(&mut store, 2024, 6, Duration::from_micros(1200));
+
+    assert_eq!(store.get("timings.2024", "06"), Some("1200"));
+    assert_eq!(
+        recorded(&Config::parse(&store.to_string()).unwrap()),
+        vec![
+            (2024, 1, Duration::from_micros(20)),
+            (2024, 6, Duration::from_micros(1200))
+        ]
+    );
+}
+
+#[test]
+fn per_year_test() {
+    let solutions = solutions();
+    let registered = solutions.iter().filter(|s| s.year == 2024).count();
+    let store = Config::parse("[timings.2024]\n01 = 100\n02 = 300\n03 = 200\n99 = 5\n").unwrap();
+
+    let stats = per_year(&solutions, &store);
+    assert_eq!(stats.len(), 1);
+
+    let year = stats[0];
+    assert_eq!(year.year, 2024);
+    assert_eq!(year.registered, registered);
+    assert_eq!(year.solved, 3);
+    assert_eq!(year.total, Duration::from_micros(600));
+    assert_eq!(year.timing.unwrap().median, Duration::from_micros(200));
+    assert!((year.percent_of_goal() - 0.06).abs() < 1e-9);
+    assert!(!year.meets_goal());
+
+    let empty = per_year(&solutions, &Config::default());
+    assert_eq!(empty[0].timing, None);
+}
+
+#[test]
+fn invalid_entries_test() {
+    let store = Config::parse(
+        "[timings.2024]\n01 = 100\n02 = 3x0\n04 = \"1.5 ms\"\n[timings.next]\n01 = 5\n",
+    )
+    .unwrap();
+
+    assert_eq!(
+        recorded(&store),
+        vec![(2024, 1, Duration::from_micros(100))]
+    );
+    let invalid: Vec<_> = entries(&store)
+        .filter(|(_, _, timing)| timing.is_none())
+        .map(|(section, key, _)| (section, key))
+        .collect();
+    assert_eq!(
+        invalid,
+        vec![
+            ("timings.2024", "02"),
+            ("timings.2024", "04"),
+            ("timings.next", "01")
+        ]
+    );
+}
@@ -0,0 +1,47 @@
+use aoc::runner::registry::solutions;
+use aoc::runner::status::*;
+use aoc::*;
+use std::fs::{create_dir_all, remove_dir_all, write};
+use std::path::Path;
+
+#[test]
+fn test_path_test() {
+    assert_eq!(
+        test_path(2024, 6),
+        Path::new("tests/year2024/day06_test.rs")
+    );
+}
+
+#[test]
+fn list_test() {
+    let root = std::env::temp_dir().join(format!("aoc-status-{}", std::process::id()));
+    create_dir_all(root.join("input/year2024")).unwrap();
+    create_dir_all(root.join("tests/year2024")).unwrap();
+    write(root.join("input/year2024/day01.txt"), "").unwrap();
+    write(root.join("tests/year2024/day01_test.rs"), "").unwrap();
+
+    let days = list(
+        &Filter::new(Some(2024), None),
+        &RunOptions::default(),
+        &root,
+    );
+    assert_eq!(
+        days.len(),
+        solutions().iter().filter(|s| s.year == 2024).count()
+    );
+    assert_eq!(
+        days[0],
+        DayStatus {
+            year: 2024,
+            day: 1,
+            name: Some("Historian Hysteria"),
+            input: true,
+            answers: false,
+            tests: true
+        }
+    );
+    assert!(!days[0].is_complete());
+    assert!(!days[1].input && !days[1].tests);
+
+    remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,35 @@
+use aoc::runner::timing::*;
+use aoc::*;
+use std::time::Duration;
+
+#[test]
+fn from_samples_test() {
+    let samples: Vec<Duration> = [7, 2, 4, 4, 5, 5, 4, 9]
+        .into_iter()
+        .map(Duration::from_micros)
+        .collect();
+    let timing = Timing::from_samples(&samples);
+
+    assert_eq!(timing.runs, 8);
+    assert_eq!(timing.min, Duration::from_micros(2));
+    assert_eq!(timing.median, Duration::from_nanos(4500));
+    assert_eq!(timing.mean, Duration::from_micros(5));
+    assert_eq!(timing.stddev, Duration::from_micros(2));
+}
+
+#[test]
+fn bench_input_test() {
+    let timing = bench_input(2024, 1, "3   4\n4   3\n", 1, 5, &RunOptions::default())
+        .outcome
+        .unwrap();
+    assert_eq!(timing.runs, 5);
+    assert!(timing.min <= timing.median);
+
+    assert_eq!(
+        bench_input(2024, 26, "", 0, 1, &RunOptions::default()).outcome,
+        Err(RunError::NotFound {
+            year: 2024,
+            day: 26
+        })
+    );
+}
@@ -0,0 +1,62 @@
+use aoc::runner::registry::locate;
+use aoc::runner::verify::*;
+use aoc::*;
+use std::fs::{create_dir_all, remove_dir_all, write};
+use std::path::Path;
+
+#[test]
+fn answers_path_test() {
+    assert_eq!(
+        answers_path(2024, 6, None),
+        Path::new("answers/year2024/day06.txt")
+    );
+}
+
+#[test]
+fn locate_test() {
+    let dir = std::env::temp_dir().join(format!("aoc-locate-{}", std::process::id()));
+    create_dir_all(dir.join("day06")).unwrap();
+    assert_eq!(locate(&dir, 6, None), dir.join("day06.txt"));
+    assert_eq!(
+        locate(&dir, 6, Some("sample1")),
+        dir.join("day06-sample1.txt")
+    );
+
+    write(dir.join("day06/real.txt"), "").unwrap();
+    write(dir.join("day06/sample1.txt"), "").unwrap();
+    assert_eq!(locate(&dir, 6, None), dir.join("day06/real.txt"));
+    assert_eq!(locate(&dir, 6, Some("real")), dir.join("day06/real.txt"));
+    assert_eq!(
+        locate(&dir, 6, Some("sample1")),
+        dir.join("day06/sample1.txt")
+    );
+
+    write(dir.join("day06.txt"), "").unwrap();
+    assert_eq!(locate(&dir, 6, None), dir.join("day06.txt"));
+    remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn parse_expected_test() {
+    assert_eq!(
+        parse_expected("Part 1: 11\nPart 2: HELLO\n"),
+        Some((Answer::Number(11), Answer::Text("HELLO".to_string())))
+    );
+    assert_eq!(parse_expected("11\n"), None);
+}
+
+#[test]
+fn check_test() {
+    let expected = parse_expected("11\n31\n").unwrap();
+    let actual = solve(
+        2024,
+        1,
+        "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n".to_string(),
+    )
+    .outcome
+    .unwrap();
+    assert_eq!(check(&expected, &actual), [true, true]);
+
+    let wrong = (Answer::Number(11), Answer::Number(30));
+    assert_eq!(check(&expected, &wrong), [true, false]);
+}
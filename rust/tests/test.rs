@@ -1,3 +1,84 @@
+#[cfg(feature = "ffi")]
+mod ffi {
+    mod ffi_test;
+}
+
+mod runner {
+    mod baseline_test;
+    mod cache_test;
+    mod calendar_test;
+    mod cli_test;
+    mod cross_check_test;
+    mod download_test;
+    mod export_test;
+    mod html_test;
+    mod memory_test;
+    mod notify_test;
+    mod progress_test;
+    mod report_test;
+    mod run_test;
+    mod samples_test;
+    mod scaffold_test;
+    mod scrub_test;
+    mod serve_test;
+    mod settings_test;
+    mod stats_test;
+    mod status_test;
+    mod timing_test;
+    mod verify_test;
+}
+
+mod util {
+    mod automaton_nd_test;
+    mod base_test;
+    mod bingo_test;
+    mod bits_test;
+    mod blockers_test;
+    mod bricks_test;
+    mod cards_test;
+    mod chars_test;
+    mod circuit_test;
+    mod combat_test;
+    mod config_test;
+    mod crt_test;
+    mod cycle_test;
+    mod direction_test;
+    mod falling_test;
+    mod fixed_grid_test;
+    mod geometry_test;
+    mod graph_test;
+    mod grid_test;
+    mod hash_test;
+    mod json_test;
+    mod lazy_test;
+    mod math_test;
+    mod matrix_test;
+    mod monkey_test;
+    mod morton_test;
+    mod octree_test;
+    mod parse_test;
+    mod pathfinding_test;
+    mod perfect_test;
+    mod point_cloud_test;
+    mod point_test;
+    mod rangemap_test;
+    mod recurse_test;
+    mod reduce_test;
+    mod rope_test;
+    mod runs_test;
+    mod scanner_test;
+    mod schedule_test;
+    mod sets_test;
+    mod shortcut_test;
+    mod state_test;
+    mod thread_test;
+    mod tilt_test;
+    mod trie_test;
+    mod turtle_test;
+    mod viz_test;
+    mod workflow_test;
+}
+
 mod year2024 {
     mod day01_test;
     mod day02_test;
@@ -0,0 +1,42 @@
+use aoc::util::automaton_nd::*;
+
+const EXAMPLE: &str = "\
+.#.
+..#
+###
+";
+
+#[test]
+fn offsets_test() {
+    assert_eq!(offsets::<2>().len(), 8);
+    assert_eq!(offsets::<3>().len(), 26);
+    assert_eq!(offsets::<4>().len(), 80);
+    assert!(!offsets::<3>().contains(&[0, 0, 0]));
+    assert_eq!(neighbours(&[5, 5]).filter(|&[x, _]| x == 6).count(), 3);
+}
+
+#[test]
+fn conway_3d_test() {
+    let mut automaton: Automaton<3> = Automaton::parse(EXAMPLE, '#');
+    automaton.step(conway);
+    assert_eq!(automaton.len(), 11);
+    (1..6).for_each(|_| automaton.step(conway));
+    assert_eq!(automaton.len(), 112);
+}
+
+#[test]
+fn conway_4d_test() {
+    let mut automaton: Automaton<4> = Automaton::parse(EXAMPLE, '#');
+    (0..6).for_each(|_| automaton.step(conway));
+    assert_eq!(automaton.len(), 848);
+}
+
+#[test]
+fn conway_2d_blinker_test() {
+    let mut automaton: Automaton<2> = Automaton::parse("...\n###\n...\n", '#');
+    let original = automaton.active.clone();
+    automaton.step(conway);
+    assert!(automaton.active.contains(&[1, 0]) && automaton.active.contains(&[1, 2]));
+    automaton.step(conway);
+    assert_eq!(automaton.active, original);
+}
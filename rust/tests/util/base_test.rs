@@ -0,0 +1,58 @@
+use aoc::util::base::*;
+
+#[test]
+fn radix_test() {
+    assert_eq!(to_radix(0, 2), "0");
+    assert_eq!(to_radix(255, 16), "ff");
+    assert_eq!(to_radix(35, 36), "z");
+    assert_eq!(from_radix("FF", 16), Some(255));
+    assert_eq!(from_radix("102", 2), None);
+    assert_eq!(from_radix(&to_radix(u64::MAX, 7), 7), Some(u64::MAX));
+}
+
+#[test]
+fn snafu_test() {
+    let table = [
+        (1, "1"),
+        (3, "1="),
+        (8, "2="),
+        (20, "1-0"),
+        (2022, "1=11-2"),
+        (12345, "1-0---0"),
+        (314159265, "1121-1110-1=0"),
+    ];
+    for (value, snafu) in table {
+        assert_eq!(SNAFU.encode(value), snafu);
+        assert_eq!(SNAFU.decode(snafu), Some(value));
+    }
+
+    let total: i64 = [
+        "1=-0-2", "12111", "2=0=", "21", "2=01", "111", "20012", "112", "1=-1=", "1-12", "12",
+        "1=", "122",
+    ]
+    .iter()
+    .filter_map(|line| SNAFU.decode(line))
+    .sum();
+    assert_eq!(SNAFU.encode(total), "2=-1=0");
+    assert_eq!(SNAFU.decode("1x"), None);
+    assert_eq!(SNAFU.decode(""), None);
+}
+
+#[test]
+fn balanced_ternary_test() {
+    assert_eq!(BALANCED_TERNARY.encode(0), "0");
+    assert_eq!(BALANCED_TERNARY.encode(8), "+0-");
+    assert_eq!(BALANCED_TERNARY.encode(-8), "-0+");
+    assert!((-100..100).all(|n| BALANCED_TERNARY.decode(&BALANCED_TERNARY.encode(n)) == Some(n)));
+}
+
+#[test]
+fn bijective_test() {
+    assert_eq!(COLUMNS.encode(0), "");
+    assert_eq!(COLUMNS.encode(26), "Z");
+    assert_eq!(COLUMNS.encode(27), "AA");
+    assert_eq!(COLUMNS.encode(702), "ZZ");
+    assert_eq!(COLUMNS.decode("AAA"), Some(703));
+    assert_eq!(COLUMNS.decode("A1"), None);
+    assert!((0..2000).all(|n| COLUMNS.decode(&COLUMNS.encode(n)) == Some(n)));
+}
@@ -0,0 +1,41 @@
+use aoc::util::bits::*;
+
+const EXAMPLE: &str = "\
+00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010
+";
+
+#[test]
+fn vote_test() {
+    let (values, width) = parse_binary(EXAMPLE);
+    assert_eq!(width, 5);
+    assert_eq!(vote(&values, width, Criteria::MostCommon { tie: 1 }), 22);
+    assert_eq!(vote(&values, width, Criteria::LeastCommon { tie: 0 }), 9);
+}
+
+#[test]
+fn filter_by_criteria_test() {
+    let (values, width) = parse_binary(EXAMPLE);
+    let oxygen = filter_by_criteria(&values, width, Criteria::MostCommon { tie: 1 });
+    let co2 = filter_by_criteria(&values, width, Criteria::LeastCommon { tie: 0 });
+    assert_eq!(oxygen, Some(23));
+    assert_eq!(co2, Some(10));
+}
+
+#[test]
+fn tie_test() {
+    assert_eq!(Criteria::MostCommon { tie: 1 }.select(2, 2), 1);
+    assert_eq!(Criteria::MostCommon { tie: 0 }.select(2, 2), 0);
+    assert_eq!(Criteria::LeastCommon { tie: 1 }.select(3, 1), 1);
+    assert_eq!(Criteria::LeastCommon { tie: 1 }.select(1, 3), 0);
+}
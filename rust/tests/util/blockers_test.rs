@@ -0,0 +1,94 @@
+use aoc::util::blockers::*;
+use aoc::util::direction::Direction;
+use aoc::util::grid::Grid;
+use aoc::util::point::Point;
+
+const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+fn blockers() -> Blockers {
+    let grid: Grid<char> = Grid::parse(EXAMPLE, None).unwrap();
+    Blockers::from_grid(&grid, |&c| c == '#')
+}
+
+#[test]
+fn next_in_direction_test() {
+    let blockers = blockers();
+    let guard = Point::new(4, 6);
+
+    assert_eq!(
+        blockers.next_in_direction(&guard, &Direction::Up),
+        Some(Point::new(4, 0))
+    );
+    assert_eq!(
+        blockers.next_in_direction(&guard, &Direction::Left),
+        Some(Point::new(1, 6))
+    );
+    assert_eq!(blockers.next_in_direction(&guard, &Direction::Right), None);
+    assert_eq!(blockers.next_in_direction(&guard, &Direction::Down), None);
+
+    // The obstacle under the starting point does not count
+    assert_eq!(
+        blockers.next_in_direction(&Point::new(2, 3), &Direction::Up),
+        None
+    );
+    assert_eq!(
+        blockers.next_in_direction(&Point::new(2, 9), &Direction::Up),
+        Some(Point::new(2, 3))
+    );
+    assert_eq!(
+        blockers.next_in_direction(&Point::new(3, -1), &Direction::Right),
+        None
+    );
+}
+
+#[test]
+fn slide_test() {
+    let blockers = blockers();
+
+    assert_eq!(
+        blockers.slide(&Point::new(4, 6), &Direction::Up),
+        Point::new(4, 1)
+    );
+    assert_eq!(
+        blockers.slide(&Point::new(4, 6), &Direction::Right),
+        Point::new(9, 6)
+    );
+    assert_eq!(
+        blockers.slide(&Point::new(1, 8), &Direction::Left),
+        Point::new(1, 8)
+    );
+}
+
+#[test]
+fn insert_remove_test() {
+    let mut blockers = blockers();
+    let obstacle = Point::new(3, 6);
+
+    assert!(!blockers.contains(&obstacle));
+    assert!(blockers.insert(&obstacle));
+    assert!(!blockers.insert(&obstacle));
+    assert!(blockers.contains(&obstacle));
+    assert_eq!(
+        blockers.next_in_direction(&Point::new(4, 6), &Direction::Left),
+        Some(obstacle)
+    );
+    assert_eq!(
+        blockers.next_in_direction(&Point::new(3, 9), &Direction::Up),
+        Some(obstacle)
+    );
+
+    assert!(blockers.remove(&obstacle));
+    assert!(!blockers.remove(&obstacle));
+    assert_eq!(blockers, self::blockers());
+}
@@ -0,0 +1,58 @@
+use aoc::util::bricks::*;
+use aoc::util::geometry::Cuboid;
+use aoc::util::parse::*;
+use aoc::util::point3::Point3;
+
+const EXAMPLE: &str = "\
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9
+";
+
+fn parse(input: &str) -> Vec<Cuboid> {
+    input
+        .lines()
+        .map(|line| {
+            let n: Vec<u32> = line.iter_unsigned().collect();
+            let p = |i: usize| Point3::new(n[i] as i32, n[i + 1] as i32, n[i + 2] as i32);
+            Cuboid::new(p(0), p(3))
+        })
+        .collect()
+}
+
+#[test]
+fn settle_test() {
+    let mut bricks = parse(EXAMPLE);
+    let graph = settle(&mut bricks);
+
+    assert_eq!(bricks[0].min.z, 1);
+    assert_eq!(bricks[6].min.z, 5);
+    assert_eq!(graph.in_degrees(), vec![0, 1, 1, 2, 2, 2, 1]);
+    assert_eq!(removable(&graph), vec![1, 2, 3, 4, 6]);
+}
+
+#[test]
+fn chain_reaction_test() {
+    let mut bricks = parse(EXAMPLE);
+    let graph = settle(&mut bricks);
+    let total: usize = (0..bricks.len()).map(|b| chain_reaction(&graph, b)).sum();
+    assert_eq!(chain_reaction(&graph, 0), 6);
+    assert_eq!(total, 7);
+}
+
+#[test]
+fn cuboid_test() {
+    let a = Cuboid::new(Point3::new(2, 2, 2), Point3::EMPTY);
+    let b = Cuboid::new(Point3::new(1, 1, 1), Point3::new(5, 5, 5));
+    assert_eq!(a.volume(), 27);
+    assert_eq!(
+        a.intersection(&b),
+        Some(Cuboid::new(Point3::new(1, 1, 1), Point3::new(2, 2, 2)))
+    );
+    assert!(a.contains(&Point3::new(0, 1, 2)));
+    assert!(!a.overlaps(&b.translate(&Point3::new(2, 0, 0))));
+}
@@ -0,0 +1,105 @@
+use aoc::util::cards::*;
+
+const EXAMPLE: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
+
+fn winnings(ranking: &Ranking) -> usize {
+    let mut hands: Vec<_> = EXAMPLE
+        .lines()
+        .map(|line| {
+            let (cards, bid) = line.split_once(' ').unwrap();
+            (ranking.hand(cards), bid.parse::<usize>().unwrap())
+        })
+        .collect();
+    hands.sort_unstable();
+
+    hands
+        .iter()
+        .enumerate()
+        .map(|(i, (_, bid))| (i + 1) * bid)
+        .sum()
+}
+
+#[test]
+fn winnings_test() {
+    assert_eq!(winnings(&Ranking::new(Ranking::STANDARD)), 6440);
+}
+
+#[test]
+fn winnings_with_joker_test() {
+    assert_eq!(winnings(&Ranking::with_joker(Ranking::JOKER, 'J')), 5905);
+}
+
+#[test]
+fn hand_type_order_test() {
+    let ranking = Ranking::new(Ranking::STANDARD);
+    let kinds: Vec<_> = [
+        "23456", "A23A4", "23432", "TTT98", "23332", "AA8AA", "AAAAA",
+    ]
+    .iter()
+    .map(|cards| ranking.hand(cards).kind)
+    .collect();
+
+    assert_eq!(
+        kinds,
+        vec![
+            HandType::HighCard,
+            HandType::OnePair,
+            HandType::TwoPair,
+            HandType::ThreeOfAKind,
+            HandType::FullHouse,
+            HandType::FourOfAKind,
+            HandType::FiveOfAKind,
+        ]
+    );
+    assert!(kinds.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn same_type_order_test() {
+    let ranking = Ranking::new(Ranking::STANDARD);
+    // Ties are broken by the first differing card, not by the strongest group.
+    assert!(ranking.hand("33332") > ranking.hand("2AAAA"));
+    assert!(ranking.hand("77888") > ranking.hand("77788"));
+    assert!(ranking.hand("KK677") > ranking.hand("KTJJT"));
+    assert_eq!(ranking.hand("T55J5"), ranking.hand("T55J5"));
+}
+
+#[test]
+fn stronger_type_wins_test() {
+    let ranking = Ranking::new(Ranking::STANDARD);
+    assert!(ranking.hand("22345") > ranking.hand("AKQJT"));
+    assert!(ranking.hand("22233") > ranking.hand("AAAKQ"));
+}
+
+#[test]
+fn joker_shape_test() {
+    let ranking = Ranking::with_joker(Ranking::JOKER, 'J');
+    assert_eq!(ranking.hand("QJJQ2").kind, HandType::FourOfAKind);
+    assert_eq!(ranking.hand("JJJJJ").kind, HandType::FiveOfAKind);
+    assert_eq!(ranking.hand("2345J").kind, HandType::OnePair);
+    assert_eq!(ranking.hand("2233J").kind, HandType::FullHouse);
+    assert_eq!(ranking.shape("KTJJT"), vec![4, 1]);
+}
+
+#[test]
+fn joker_is_weakest_test() {
+    let ranking = Ranking::with_joker(Ranking::JOKER, 'J');
+    // Both are four of a kind, so the joker's low rank decides.
+    assert!(ranking.hand("QQQQ2") > ranking.hand("JKKK2"));
+    assert!(ranking.rank('J') < ranking.rank('2'));
+}
+
+#[test]
+fn from_shape_test() {
+    assert_eq!(HandType::from_shape(&[]), HandType::HighCard);
+    assert_eq!(HandType::from_shape(&[1, 1, 1, 1, 1]), HandType::HighCard);
+    assert_eq!(HandType::from_shape(&[2, 2, 1]), HandType::TwoPair);
+    assert_eq!(HandType::from_shape(&[3, 2]), HandType::FullHouse);
+    assert_eq!(HandType::from_shape(&[6]), HandType::FiveOfAKind);
+}
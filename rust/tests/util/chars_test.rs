@@ -0,0 +1,54 @@
+use aoc::util::chars::*;
+
+#[test]
+fn index_test() {
+    assert_eq!(index('a'), Some(0));
+    assert_eq!(index('Z'), Some(25));
+    assert_eq!(index('-'), None);
+    assert_eq!(from_index(7), 'h');
+    assert!((0..26).all(|i| index(from_index(i)) == Some(i)));
+}
+
+#[test]
+fn rotate_test() {
+    assert_eq!(rotate('z', 1), 'a');
+    assert_eq!(rotate('A', -1), 'Z');
+    assert_eq!(rotate('-', 5), '-');
+    assert_eq!(
+        rotate_str("qzmt-zixmtkozy-ivhz", 343),
+        "very-encrypted-name"
+    );
+    assert_eq!(
+        rotate_str(&rotate_str("Hello, World", 11), -11),
+        "Hello, World"
+    );
+}
+
+#[test]
+fn priority_test() {
+    assert_eq!(priority('p'), Some(16));
+    assert_eq!(priority('L'), Some(38));
+    assert_eq!(priority('1'), None);
+
+    let common = priority_mask("vJrwpWtwJgWr") & priority_mask("hcsFMMfFFhFp");
+    assert_eq!(common.trailing_zeros(), 16);
+
+    let badge = [
+        "vJrwpWtwJgWrhcsFMMfFFhFp",
+        "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+        "PmmdzqPrVvPwwTWBwg",
+    ]
+    .iter()
+    .map(|line| priority_mask(line))
+    .fold(u64::MAX, |a, b| a & b);
+    assert_eq!(badge.trailing_zeros(), 18);
+}
+
+#[test]
+fn frequencies_test() {
+    let counts = frequencies("aaaaa-bbb-z-y-x");
+    assert_eq!(counts[0], 5);
+    assert_eq!(counts[1], 3);
+    assert_eq!(counts[25], 1);
+    assert_eq!(counts.iter().sum::<usize>(), 11);
+}
@@ -0,0 +1,82 @@
+use aoc::util::circuit::*;
+
+const EXAMPLE: &str = "\
+123 -> x
+456 -> y
+x AND y -> d
+x OR y -> e
+x LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT x -> h
+NOT y -> i
+";
+
+#[test]
+fn evaluate_test() {
+    let mut circuit = Circuit::parse(EXAMPLE);
+    let expected = [
+        ("d", 72),
+        ("e", 507),
+        ("f", 492),
+        ("g", 114),
+        ("h", 65412),
+        ("i", 65079),
+        ("x", 123),
+        ("y", 456),
+    ];
+    for (wire, value) in expected {
+        assert_eq!(circuit.evaluate(wire), Some(value), "wire {wire}");
+    }
+    assert_eq!(circuit.evaluate("z"), None);
+}
+
+#[test]
+fn override_test() {
+    let mut circuit = Circuit::parse(EXAMPLE);
+    assert_eq!(circuit.evaluate("d"), Some(72));
+
+    circuit.set_override("x", 0xFF);
+    assert_eq!(circuit.evaluate("d"), Some(0xFF & 456));
+
+    circuit.reset();
+    assert_eq!(circuit.evaluate("d"), Some(72));
+}
+
+#[test]
+fn logic_gates_test() {
+    let input = "\
+x00: 1
+x01: 1
+x02: 1
+y00: 0
+y01: 1
+y02: 0
+
+x00 AND y00 -> z00
+x01 XOR y01 -> z01
+x02 OR y02 -> z02
+";
+    let mut circuit = Circuit::parse_with_width(input, 1);
+    let outputs: Vec<String> = circuit
+        .wires()
+        .into_iter()
+        .filter(|wire| wire.starts_with('z'))
+        .map(String::from)
+        .collect();
+    let z: u64 = outputs
+        .iter()
+        .enumerate()
+        .map(|(bit, wire)| circuit.evaluate(wire).unwrap() << bit)
+        .sum();
+    assert_eq!(z, 4);
+}
+
+#[test]
+fn long_chain_test() {
+    let mut input = String::from("1 -> w0\n");
+    for i in 1..10_000 {
+        input.push_str(&format!("w{} LSHIFT 0 -> w{i}\n", i - 1));
+    }
+    let mut circuit = Circuit::parse(&input);
+    assert_eq!(circuit.evaluate("w9999"), Some(1));
+}
@@ -0,0 +1,128 @@
+use aoc::util::combat::*;
+use aoc::util::point::Point;
+
+const SCENARIOS: [(&str, u32, i32, char); 6] = [
+    (
+        "#######\n#.G...#\n#...EG#\n#.#.#G#\n#..G#E#\n#.....#\n#######",
+        47,
+        590,
+        'G',
+    ),
+    (
+        "#######\n#G..#E#\n#E#E.E#\n#G.##.#\n#...#E#\n#...E.#\n#######",
+        37,
+        982,
+        'E',
+    ),
+    (
+        "#######\n#E..EG#\n#.#G.E#\n#E.##E#\n#G..#.#\n#..E#.#\n#######",
+        46,
+        859,
+        'E',
+    ),
+    (
+        "#######\n#E.G#.#\n#.#G..#\n#G.#.G#\n#G..#.#\n#...E.#\n#######",
+        35,
+        793,
+        'G',
+    ),
+    (
+        "#######\n#.E...#\n#.#..G#\n#.###.#\n#E#G#G#\n#...#G#\n#######",
+        54,
+        536,
+        'G',
+    ),
+    (
+        "#########\n#G......#\n#.E.#...#\n#..##..G#\n#...##..#\n#...#...#\n#.G...G.#\n#.....G.#\n#########",
+        20,
+        937,
+        'G',
+    ),
+];
+
+#[test]
+fn parse_test() {
+    let battle = Battle::parse(SCENARIOS[0].0);
+    assert_eq!(battle.units.len(), 6);
+    assert_eq!(battle.units[0].kind, 'G');
+    assert_eq!(battle.units[0].position, Point::new(2, 1));
+    assert!(battle
+        .units
+        .iter()
+        .all(|unit| unit.hit_points == HIT_POINTS));
+}
+
+#[test]
+fn movement_test() {
+    let mut battle = Battle::parse(
+        "#########\n#G..G..G#\n#.......#\n#.......#\n#G..E..G#\n#.......#\n#.......#\n#G..G..G#\n#########",
+    );
+    for _ in 0..3 {
+        assert!(battle.round());
+    }
+
+    assert_eq!(
+        battle.render(),
+        "#########\n#.......#\n#..GGG..#\n#..GEG..#\n#G..G...#\n#......G#\n#.......#\n#.......#\n#########\n"
+    );
+}
+
+#[test]
+fn first_round_test() {
+    let mut battle = Battle::parse(SCENARIOS[0].0);
+    assert!(battle.round());
+
+    assert_eq!(
+        battle.render(),
+        "#######\n#..G..#\n#...EG#\n#.#G#G#\n#...#E#\n#.....#\n#######\n"
+    );
+    let mut units = battle.units.clone();
+    units.sort_by_key(|unit| (unit.position.y, unit.position.x));
+    let hit_points: Vec<i32> = units.iter().map(|unit| unit.hit_points).collect();
+    assert_eq!(hit_points, vec![200, 197, 197, 200, 197, 197]);
+}
+
+#[test]
+fn fight_test() {
+    for (map, rounds, hit_points, winner) in SCENARIOS {
+        let outcome = Battle::parse(map).fight();
+        assert_eq!(
+            outcome,
+            Outcome {
+                rounds,
+                hit_points,
+                winner
+            },
+            "{map}"
+        );
+    }
+    assert_eq!(Battle::parse(SCENARIOS[0].0).fight().score(), 27730);
+}
+
+#[test]
+fn flawless_victory_test() {
+    let expected = [
+        (0, 15, 4988),
+        (2, 4, 31284),
+        (3, 15, 3478),
+        (4, 12, 6474),
+        (5, 34, 1140),
+    ];
+
+    for (scenario, attack, score) in expected {
+        let (power, outcome) = flawless_victory(SCENARIOS[scenario].0, 'E');
+        assert_eq!((power, outcome.score()), (attack, score));
+        assert_eq!(outcome.winner, 'E');
+    }
+}
+
+#[test]
+fn factions_test() {
+    // A third kind fights everyone else.
+    let mut battle = Battle::parse("#######\n#E.X.G#\n#######");
+    battle.set_attack('X', 200);
+    let outcome = battle.fight();
+
+    assert_eq!(outcome.winner, 'X');
+    assert_eq!(battle.losses('E') + battle.losses('G'), 2);
+}
@@ -0,0 +1,106 @@
+use aoc::util::config::*;
+
+const EXAMPLE: &str = r#"
+# Top level keys
+year = 2024
+
+[cross-check.2024]
+01 = "./day01 {input}"   # trailing comment
+03 = "echo \"quoted\""
+
+[empty]
+"#;
+
+#[test]
+fn parse_test() {
+    let config = Config::parse(EXAMPLE).unwrap();
+    assert_eq!(config.get("", "year"), Some("2024"));
+    assert_eq!(
+        config.get("cross-check.2024", "01"),
+        Some("./day01 {input}")
+    );
+    assert_eq!(
+        config.get("cross-check.2024", "03"),
+        Some("echo \"quoted\"")
+    );
+    assert_eq!(config.get("cross-check.2024", "02"), None);
+    assert_eq!(
+        config.sections().collect::<Vec<_>>(),
+        vec!["", "cross-check.2024", "empty"]
+    );
+}
+
+#[test]
+fn parse_error_test() {
+    let error = Config::parse("[ok]\nkey = \"unterminated").unwrap_err();
+    assert_eq!(error.line, 2);
+    assert!(Config::parse("[broken").is_err());
+    assert!(Config::parse("no equals sign").is_err());
+}
+
+#[test]
+fn insert_test() {
+    let mut config = Config::default();
+    config.insert("a", "key", "1");
+    config.insert("a", "key", "2");
+    assert_eq!(config.section("a").collect::<Vec<_>>(), vec![("key", "2")]);
+}
+
+#[test]
+fn quoted_key_test() {
+    let config = Config::parse("[answers]\n\"day 01 = part 1\" = 11\n").unwrap();
+    assert_eq!(config.get("answers", "day 01 = part 1"), Some("11"));
+}
+
+#[test]
+fn round_trip_test() {
+    let config = Config::parse(EXAMPLE).unwrap();
+    let written = config.to_string();
+
+    assert_eq!(
+        written,
+        "year = 2024\n\n[cross-check.2024]\n01 = \"./day01 {input}\"\n03 = \"echo \\\"quoted\\\"\"\n\n[empty]\n"
+    );
+    assert_eq!(Config::parse(&written).unwrap(), config);
+    assert_eq!(Config::parse(&written).unwrap().to_string(), written);
+}
+
+#[test]
+fn round_trip_values_test() {
+    let mut config = Config::default();
+    let values = [
+        "plain",
+        "",
+        "with spaces",
+        "# not a comment",
+        "a = b",
+        "line\nbreak\ttab\r",
+        "\"quoted\" \\ backslash",
+        "[not a header]",
+        "ĄĘ unicode",
+    ];
+    for (index, value) in values.iter().enumerate() {
+        config.insert("values", &format!("key {index}"), value);
+    }
+    config.insert("", "top", "-12");
+
+    let parsed = Config::parse(&config.to_string()).unwrap();
+    for (index, value) in values.iter().enumerate() {
+        assert_eq!(parsed.get("values", &format!("key {index}")), Some(*value));
+    }
+    assert_eq!(parsed.get("", "top"), Some("-12"));
+}
+
+#[test]
+fn save_and_remove_test() {
+    let path = std::env::temp_dir().join("aoc_config_save_test.toml");
+    let mut config = Config::parse(EXAMPLE).unwrap();
+    assert_eq!(
+        config.remove("cross-check.2024", "03"),
+        Some("echo \"quoted\"".to_string())
+    );
+    assert_eq!(config.remove("cross-check.2024", "03"), None);
+
+    config.save(&path).unwrap();
+    assert_eq!(Config::load(&path).unwrap(), config);
+}
@@ -0,0 +1,55 @@
+use aoc::util::crt::*;
+
+#[test]
+fn execute_test() {
+    let program = parse("noop\naddx 3\naddx -5\n");
+    let mut during = Vec::new();
+    let x = run(&program, |cycle, x| during.push((cycle, x)));
+
+    assert_eq!(during, vec![(1, 1), (2, 1), (3, 1), (4, 4), (5, 4)]);
+    assert_eq!(x, -1);
+}
+
+#[test]
+fn generic_execute_test() {
+    // Instructions of different lengths, sampling a two register state.
+    let program = [(1, 'a'), (3, 'b'), (2, 'a')];
+    let mut samples = Vec::new();
+    let state = execute(
+        &program,
+        (0, 0),
+        |&(cycles, _)| cycles,
+        |&(_, register), (a, b)| {
+            if register == 'a' {
+                (a + 1, b)
+            } else {
+                (a, b + 1)
+            }
+        },
+        |cycle, state| samples.push((cycle, state)),
+    );
+
+    assert_eq!(state, (2, 1));
+    assert_eq!(samples.len(), 6);
+    assert_eq!(samples[1], (2, (1, 0)));
+    assert_eq!(samples[5], (6, (1, 1)));
+}
+
+#[test]
+fn signal_strength_test() {
+    let program = parse("noop\naddx 3\naddx -5\n");
+    assert_eq!(signal_strength(&program, &[2, 4, 5]), 2 + 4 * 4 + 5 * 4);
+    assert_eq!(signal_strength(&program, &[20]), 0);
+}
+
+#[test]
+fn draw_test() {
+    // The sprite moves right by three, then back to the left.
+    let program = parse("addx 3\nnoop\nnoop\nnoop\naddx -3\nnoop\nnoop\nnoop\n");
+    let screen = draw(&program, 5, 2);
+
+    assert_eq!(screen.lines(), vec!["##.##", "..#.."]);
+    assert_eq!(screen.count(), 5);
+    assert!(screen.get(3, 0));
+    assert!(!screen.get(9, 9));
+}
@@ -0,0 +1,60 @@
+use aoc::util::cycle::*;
+
+fn step(x: &u64) -> u64 {
+    (x * x + 1) % 255
+}
+
+#[test]
+fn brent_test() {
+    // 3 -> 10 -> 101 -> 2 -> 5 -> 26 -> 167 -> 95 -> 101
+    assert_eq!(
+        brent(3, step),
+        Cycle {
+            start: 2,
+            length: 6
+        }
+    );
+    assert_eq!(
+        brent(0, |x: &u64| (x + 1) % 7),
+        Cycle {
+            start: 0,
+            length: 7
+        }
+    );
+}
+
+#[test]
+fn detect_test() {
+    let (cycle, history) = detect(3, step);
+    assert_eq!(cycle, brent(3, step));
+    assert_eq!(history.len(), cycle.start + cycle.length);
+    assert_eq!(&history[..3], &[3, 10, 101]);
+}
+
+#[test]
+fn period_test() {
+    assert_eq!(
+        period(&[1, 2, 3, 4, 2, 3]),
+        Some(Cycle {
+            start: 1,
+            length: 3
+        })
+    );
+    assert_eq!(period(&[1, 2, 3]), None);
+}
+
+#[test]
+fn nth_with_cycle_test() {
+    let mut expected = 3;
+    for n in 0..100 {
+        assert_eq!(nth_with_cycle(3, step, n), expected);
+        expected = step(&expected);
+    }
+
+    let cycle = Cycle {
+        start: 2,
+        length: 6,
+    };
+    assert_eq!(cycle.index(1), 1);
+    assert_eq!(cycle.index(1_000_000_000), 2 + (1_000_000_000 - 2) % 6);
+}
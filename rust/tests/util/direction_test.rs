@@ -0,0 +1,50 @@
+use aoc::util::direction::*;
+use aoc::util::point::Point;
+
+#[test]
+fn try_from_char_test() {
+    assert_eq!(Direction::try_from('^'), Ok(Direction::Up));
+    assert_eq!(Direction::try_from('v'), Ok(Direction::Down));
+    assert_eq!(Direction::try_from('E'), Ok(Direction::Right));
+    assert_eq!(Direction::try_from('l'), Ok(Direction::Left));
+    assert_eq!(
+        Direction::try_from('x'),
+        Err(ParseDirectionError("x".to_string()))
+    );
+}
+
+#[test]
+fn from_str_test() {
+    assert_eq!("up".parse(), Ok(Direction::Up));
+    assert_eq!("North".parse(), Ok(Direction::Up));
+    assert_eq!("N".parse(), Ok(Direction::Up));
+    assert_eq!("U".parse(), Ok(Direction::Up));
+    assert_eq!("WEST".parse(), Ok(Direction::Left));
+    assert_eq!("NE".parse(), Ok(Direction::RightUp));
+    assert_eq!("down-left".parse(), Ok(Direction::LeftDown));
+
+    let error = "sideways".parse::<Direction>().unwrap_err();
+    assert_eq!(error.to_string(), "Invalid direction \"sideways\"");
+    assert!("".parse::<Direction>().is_err());
+}
+
+#[test]
+fn parse_compatibility_test() {
+    assert_eq!(Direction::parse('>'), Some(Direction::Right));
+    assert_eq!(Direction::parse('?'), None);
+}
+
+#[test]
+fn point_round_trip_test() {
+    for direction in ORTHOGONAL {
+        let point = Point::from(direction);
+        assert_eq!(Direction::try_from(point), Ok(direction));
+    }
+    assert_eq!(Point::from(Direction::LeftUp), Point::LEFT_UP);
+    assert_eq!(Direction::try_from(Point::EMPTY), Ok(Direction::Stop));
+    assert_eq!(Direction::try_from(Point::new(2, 0)), Err(Point::new(2, 0)));
+    assert_eq!(
+        Direction::try_from(Point::new(5, -3).signum()),
+        Ok(Direction::RightUp)
+    );
+}
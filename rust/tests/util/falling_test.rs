@@ -0,0 +1,98 @@
+use aoc::util::falling::*;
+use aoc::util::geometry::path;
+use aoc::util::parse::*;
+use aoc::util::point::Point;
+
+const SAND: &str = "\
+498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9
+";
+
+fn cave() -> SparseSpace {
+    let mut space = SparseSpace::default();
+    for line in SAND.lines() {
+        let numbers: Vec<u32> = line.iter_unsigned().collect();
+        let corners: Vec<Point> = numbers
+            .chunks(2)
+            .map(|c| Point::new(c[0] as i32, c[1] as i32))
+            .collect();
+        for segment in path(&corners) {
+            segment.points().for_each(|p| space.place(&p, TERRAIN));
+        }
+    }
+    space
+}
+
+#[test]
+fn sand_abyss_test() {
+    let mut falling = Falling::new(cave());
+    falling.abyss = Some(9);
+    let source = Point::new(500, 0);
+
+    let mut count = 0;
+    while falling.drop(&[Point::EMPTY], source, sand()).is_some() {
+        count += 1;
+    }
+    assert_eq!(count, 24);
+}
+
+#[test]
+fn sand_floor_test() {
+    let mut space = cave();
+    space.floor = Some(11);
+    let mut falling = Falling::new(space);
+    let source = Point::new(500, 0);
+
+    let mut count = 0;
+    while falling.drop(&[Point::EMPTY], source, sand()).is_some() {
+        count += 1;
+    }
+    assert_eq!(count, 93);
+}
+
+#[test]
+fn rocks_test() {
+    let horizontal = [0, 1, 2, 3].map(|x| Point::new(x, 0));
+    let plus = [(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)].map(|(x, y)| Point::new(x, y - 2));
+    let corner = [(2, 0), (2, 1), (0, 2), (1, 2), (2, 2)].map(|(x, y)| Point::new(x, y - 2));
+    let vertical = [0, 1, 2, 3].map(|y| Point::new(0, y - 3));
+    let square = [(0, 0), (1, 0), (0, 1), (1, 1)].map(|(x, y)| Point::new(x, y - 1));
+    let shapes: [&[Point]; 5] = [&horizontal, &plus, &corner, &vertical, &square];
+
+    let pattern = b">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+    let mut next_jet = 0;
+    let height = 4000;
+    let mut falling = Falling::new(DenseSpace::new(7, height));
+    let mut top = height;
+
+    for rock in 0..2022 {
+        let start = Point::new(2, top - 4);
+        let id = falling
+            .drop(shapes[rock % 5], start, jets(pattern, &mut next_jet))
+            .unwrap();
+        top = top.min(falling.units[id].iter().map(|p| p.y).min().unwrap());
+    }
+
+    assert_eq!(height - top, 3068);
+}
+
+#[test]
+fn supports_test() {
+    // Two bricks side by side, one bridging both on top and one resting on the bridge.
+    let mut falling = Falling::new(DenseSpace::new(4, 10));
+    let brick = [Point::new(0, 0), Point::new(1, 0)];
+    falling.drop(&brick, Point::new(0, 0), straight_down());
+    falling.drop(&brick, Point::new(2, 0), straight_down());
+    falling.drop(&brick, Point::new(1, 0), straight_down());
+    falling.drop(&[Point::EMPTY], Point::new(2, 0), straight_down());
+
+    assert_eq!(falling.units[2], vec![Point::new(1, 8), Point::new(2, 8)]);
+    assert_eq!(
+        falling.supports(),
+        vec![vec![], vec![], vec![0, 1], vec![2]]
+    );
+    assert_eq!(
+        falling.supporting(),
+        vec![vec![2], vec![2], vec![3], vec![]]
+    );
+}
@@ -0,0 +1,66 @@
+use aoc::util::direction::Direction;
+use aoc::util::fixed_grid::*;
+use aoc::util::grid::Grid;
+use aoc::util::point::Point;
+
+#[test]
+fn get_set_test() {
+    let mut grid: FixedGrid<u8, 3, 2> = FixedGrid::new(0);
+    assert_eq!(grid.get_size(), 6);
+
+    grid.set_value(&Point::new(2, 1), 7);
+    assert_eq!(grid.get_value(&Point::new(2, 1)), Some(7));
+    assert_eq!(grid.get_value(&Point::new(3, 1)), None);
+    assert_eq!(grid.get_value(&Point::new(0, -1)), None);
+}
+
+#[test]
+fn from_grid_test() {
+    let grid: Grid<char> = Grid::parse("123\n456\n789\n", None).unwrap();
+    let keypad: FixedGrid<char, 3, 3> = FixedGrid::try_from(&grid).unwrap();
+    assert_eq!(keypad.get_value(&Point::new(1, 2)), Some('8'));
+    assert_eq!(
+        keypad.iter().map(|(_, c)| c).collect::<String>(),
+        "123456789"
+    );
+
+    let wrong: Result<FixedGrid<char, 2, 3>, _> = FixedGrid::try_from(&grid);
+    assert_eq!(
+        wrong,
+        Err(SizeMismatch {
+            width: 3,
+            height: 3
+        })
+    );
+}
+
+#[test]
+fn stride_test() {
+    type Board = FixedGrid<u8, 4, 3>;
+    let mut grid: Board = FixedGrid::from_fn(|point| (point.y * 4 + point.x) as u8);
+
+    let start = Board::index(&Point::new(1, 1));
+    assert_eq!(start, 5);
+    assert_eq!(grid.cells()[start], 5);
+    assert_eq!(Board::stride(Direction::Up), -4);
+    assert_eq!(Board::stride(Direction::LeftDown), 3);
+
+    for direction in [Direction::Right, Direction::LeftUp, Direction::Down] {
+        let next = Board::step(start, direction);
+        assert_eq!(
+            Board::point(next),
+            Point::new(1, 1).add(&Point::from(direction))
+        );
+    }
+
+    grid.cells_mut()[Board::step(start, Direction::RightDown)] = 0;
+    assert_eq!(grid.get_value(&Point::new(2, 2)), Some(0));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "leaves the grid")]
+fn step_wraps_test() {
+    let end_of_row = FixedGrid::<u8, 4, 3>::index(&Point::new(3, 0));
+    FixedGrid::<u8, 4, 3>::step(end_of_row, Direction::Right);
+}
@@ -0,0 +1,186 @@
+use aoc::util::direction::Direction;
+use aoc::util::geometry::*;
+use aoc::util::parse::parse_moves;
+use aoc::util::point::Point;
+use aoc::util::turtle::Turtle;
+
+fn wire(moves: &str) -> Vec<Segment> {
+    let mut turtle = Turtle::new(Point::EMPTY, Direction::Right);
+    turtle.walk_all(&parse_moves(&moves.replace(',', "\n")));
+    turtle
+        .segments()
+        .iter()
+        .map(|&s| Segment::from(s))
+        .collect()
+}
+
+#[test]
+fn intersection_test() {
+    let horizontal = Segment::new(Point::new(0, 2), Point::new(6, 2));
+    let vertical = Segment::new(Point::new(3, 5), Point::new(3, -1));
+    let crossing = horizontal.intersection(&vertical).unwrap();
+    assert!(crossing.is_empty());
+    assert_eq!(crossing.start, Point::new(3, 2));
+
+    let collinear = Segment::new(Point::new(8, 2), Point::new(4, 2));
+    assert_eq!(
+        horizontal.intersection(&collinear),
+        Some(Segment::new(Point::new(4, 2), Point::new(6, 2)))
+    );
+
+    let apart = Segment::new(Point::new(0, 3), Point::new(6, 3));
+    assert_eq!(horizontal.intersection(&apart), None);
+}
+
+#[test]
+fn steps_test() {
+    let path = path(&[Point::EMPTY, Point::new(4, 0), Point::new(4, -3)]);
+    assert_eq!(steps_along(&path, &Point::new(4, -2)), Some(6));
+    assert_eq!(steps_along(&path, &Point::new(5, 0)), None);
+    assert_eq!(path[1].points().count(), 4);
+}
+
+#[test]
+#[should_panic]
+fn diagonal_test() {
+    Segment::new(Point::EMPTY, Point::new(1, 1));
+}
+
+#[test]
+fn crossed_wires_test() {
+    let examples = [
+        ("R8,U5,L5,D3", "U7,R6,D4,L4", 6, 30),
+        (
+            "R75,D30,R83,U83,L12,D49,R71,U7,L72",
+            "U62,R66,U55,R34,D71,R55,D58,R83",
+            159,
+            610,
+        ),
+        (
+            "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51",
+            "U98,R91,D20,R16,D67,R40,U7,R15,U6,R7",
+            135,
+            410,
+        ),
+    ];
+
+    for (first, second, distance, steps) in examples {
+        let (first, second) = (wire(first), wire(second));
+        let closest = closest_crossing(&first, &second, &Point::EMPTY).unwrap();
+        let earliest = earliest_crossing(&first, &second, &Point::EMPTY).unwrap();
+        assert_eq!(closest.1, distance);
+        assert_eq!(earliest.1, steps);
+    }
+}
+
+#[test]
+fn hyperbox_subtract_test() {
+    let a = Hyperbox::new([0, 0, 0], [10, 10, 10]);
+    let b = Hyperbox::new([5, -5, 3], [20, 5, 4]);
+    let pieces = a.subtract(&b);
+
+    assert!(pieces.len() <= 6);
+    let overlap = a.intersection(&b).unwrap();
+    assert_eq!(overlap.volume(), 5 * 5);
+    assert_eq!(
+        pieces.iter().map(Hyperbox::volume).sum::<i64>() + overlap.volume(),
+        a.volume()
+    );
+    for (i, p) in pieces.iter().enumerate() {
+        assert!(p.intersection(&b).is_none());
+        assert!(pieces[i + 1..].iter().all(|q| p.intersection(q).is_none()));
+    }
+    assert_eq!(a.subtract(&Hyperbox::new([20; 3], [30; 3])), vec![a]);
+}
+
+#[test]
+fn reactor_reboot_test() {
+    let steps = [
+        (true, Hyperbox::inclusive([10, 10, 10], [12, 12, 12])),
+        (true, Hyperbox::inclusive([11, 11, 11], [13, 13, 13])),
+        (false, Hyperbox::inclusive([9, 9, 9], [11, 11, 11])),
+        (true, Hyperbox::inclusive([10, 10, 10], [10, 10, 10])),
+    ];
+    assert_eq!(switch_boxes(&steps), 39);
+    assert!(steps[0].1.contains(&[12, 12, 12]));
+    assert!(!steps[0].1.contains(&[13, 12, 12]));
+}
+
+#[test]
+fn quadrant_test() {
+    assert_eq!(quadrant(&Point::UP), 0);
+    assert_eq!(quadrant(&Point::new(3, -1)), 0);
+    assert_eq!(quadrant(&Point::RIGHT), 1);
+    assert_eq!(quadrant(&Point::new(2, 5)), 1);
+    assert_eq!(quadrant(&Point::DOWN), 2);
+    assert_eq!(quadrant(&Point::new(-1, 1)), 2);
+    assert_eq!(quadrant(&Point::LEFT), 3);
+    assert_eq!(quadrant(&Point::new(-4, -4)), 3);
+}
+
+#[test]
+fn sort_by_angle_test() {
+    let origin = Point::new(5, 5);
+    let mut points = vec![
+        Point::new(4, 4),
+        Point::new(5, 9),
+        Point::new(5, 1),
+        Point::new(8, 5),
+        Point::new(5, 3),
+        Point::new(9, 1),
+        Point::new(1, 5),
+        Point::new(7, 7),
+    ];
+    sort_by_angle(&mut points, &origin);
+
+    assert_eq!(
+        points,
+        vec![
+            Point::new(5, 3),
+            Point::new(5, 1),
+            Point::new(9, 1),
+            Point::new(8, 5),
+            Point::new(7, 7),
+            Point::new(5, 9),
+            Point::new(1, 5),
+            Point::new(4, 4),
+        ]
+    );
+}
+
+#[test]
+fn laser_sweep_test() {
+    // Each rotation vaporizes only the nearest asteroid in every direction.
+    let origin = Point::new(8, 3);
+    let map = ".#....#####...#..\n##...##.#####..##\n##...#...#.#####.\n..#.....#...###..\n..#.#.....#....##";
+    let mut asteroids: Vec<Point> = map
+        .lines()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.char_indices()
+                .filter(|&(_, c)| c == '#')
+                .map(move |(x, _)| Point::new(x as i32, y as i32))
+        })
+        .filter(|point| *point != origin)
+        .collect();
+    sort_by_angle(&mut asteroids, &origin);
+
+    let mut ranked: Vec<(usize, Point)> = Vec::new();
+    for (i, asteroid) in asteroids.iter().enumerate() {
+        let direction = asteroid.sub(&origin).reduced();
+        let rank = asteroids[..i]
+            .iter()
+            .filter(|other| other.sub(&origin).reduced() == direction)
+            .count();
+        ranked.push((rank, *asteroid));
+    }
+    ranked.sort_by_key(|&(rank, _)| rank);
+
+    let order: Vec<Point> = ranked.iter().map(|&(_, point)| point).collect();
+    assert_eq!(
+        &order[..3],
+        &[Point::new(8, 1), Point::new(9, 0), Point::new(9, 1)]
+    );
+    assert_eq!(order[8], Point::new(15, 1));
+    assert_eq!(order[35], Point::new(14, 3));
+}
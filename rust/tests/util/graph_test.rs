@@ -0,0 +1,69 @@
+use aoc::util::graph::*;
+
+fn steps() -> Dag {
+    // Step dependencies from 2018 day 7, with A = 0, B = 1 and so on.
+    let mut dag = Dag::new(6);
+    for (from, to) in [
+        ('C', 'A'),
+        ('C', 'F'),
+        ('A', 'B'),
+        ('A', 'D'),
+        ('B', 'E'),
+        ('D', 'E'),
+        ('F', 'E'),
+    ] {
+        dag.add_edge(from as usize - 'A' as usize, to as usize - 'A' as usize, 1);
+    }
+    dag
+}
+
+#[test]
+fn topological_order_test() {
+    let order: String = steps()
+        .topological_order()
+        .unwrap()
+        .into_iter()
+        .map(|node| (b'A' + node as u8) as char)
+        .collect();
+    assert_eq!(order, "CABDFE");
+}
+
+#[test]
+fn topological_order_cycle_test() {
+    let mut dag = Dag::new(3);
+    dag.add_edge(0, 1, 1);
+    dag.add_edge(1, 2, 1);
+    dag.add_edge(2, 0, 1);
+    assert_eq!(dag.topological_order(), None);
+}
+
+#[test]
+fn longest_paths_test() {
+    let distance = steps().longest_paths(2);
+    assert_eq!(distance[4], Some(3));
+    assert_eq!(distance[5], Some(1));
+
+    let distance = steps().longest_paths(0);
+    assert_eq!(distance[2], None);
+    assert_eq!(distance[4], Some(2));
+}
+
+#[test]
+fn count_paths_test() {
+    // Adapter chaining from 2020 day 10, each adapter accepts a gap of at most 3 jolts.
+    let mut adapters = vec![0, 16, 10, 15, 5, 1, 11, 7, 19, 6, 12, 4];
+    adapters.sort_unstable();
+    adapters.push(adapters[adapters.len() - 1] + 3);
+
+    let mut dag = Dag::new(0);
+    for (i, &from) in adapters.iter().enumerate() {
+        for (j, &to) in adapters.iter().enumerate().skip(i + 1) {
+            if to - from <= 3 {
+                dag.add_edge(i, j, 1);
+            }
+        }
+    }
+
+    assert_eq!(dag.count_paths(0, adapters.len() - 1), 8);
+    assert_eq!(steps().count_paths(2, 4), 3);
+}
@@ -0,0 +1,255 @@
+use aoc::util::direction::Direction;
+use aoc::util::grid::*;
+use aoc::util::grid_iterator::GridIterator;
+use aoc::util::point::Point;
+
+#[test]
+fn diff_test() {
+    let before: Grid<char> = Grid::parse("..#\n.^.\n", None).unwrap();
+    let after: Grid<char> = Grid::parse("..#\n^X.\n", None).unwrap();
+
+    assert_eq!(
+        before.diff(&after),
+        vec![(Point::new(0, 1), '.', '^'), (Point::new(1, 1), '^', 'X')]
+    );
+    assert!(before.diff(&before).is_empty());
+}
+
+#[test]
+fn render_diff_test() {
+    let before: Grid<char> = Grid::parse("ab\ncd\n", None).unwrap();
+    let after: Grid<char> = Grid::parse("ab\nxd\n", None).unwrap();
+
+    assert_eq!(before.render_diff(&after), "ab\n\x1b[1m\x1b[31mx\x1b[0md\n");
+}
+
+#[test]
+fn flat_storage_test() {
+    let grid: Grid<char> = Grid::parse("abc\ndef\n", None).unwrap();
+
+    assert_eq!(grid.cells(), ['a', 'b', 'c', 'd', 'e', 'f']);
+    assert_eq!(grid.index(&Point::new(1, 1)), 4);
+    assert_eq!(grid.row(1), ['d', 'e', 'f']);
+    assert_eq!(
+        grid.rows().collect::<Vec<_>>(),
+        vec![['a', 'b', 'c'], ['d', 'e', 'f']]
+    );
+    assert_eq!(
+        grid,
+        Grid::new(vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']], 3)
+    );
+    assert_eq!(Grid::from_cells(grid.cells().to_vec(), 3), grid);
+    assert_eq!(grid.same_size_with('.'), Grid::filled(3, 2, '.'));
+}
+
+#[test]
+#[should_panic(expected = "outside the 3x2 grid")]
+fn set_value_outside_test() {
+    let mut grid: Grid<char> = Grid::parse("abc\ndef\n", None).unwrap();
+    grid.set_value(&Point::new(3, 0), 'x');
+}
+
+#[test]
+#[should_panic(expected = "Rows of unequal width")]
+fn ragged_rows_test() {
+    Grid::new(vec![vec!['a', 'b', 'c'], vec!['d', 'e']], 3);
+}
+
+#[test]
+fn stride_test() {
+    let grid: Grid<char> = Grid::parse("abcd\nefgh\nijkl\n", None).unwrap();
+
+    let start = grid.index(&Point::new(1, 1));
+    assert_eq!(grid.point(start), Point::new(1, 1));
+    assert_eq!(grid.stride(Direction::Up), -4);
+    assert_eq!(grid.stride(Direction::LeftDown), 3);
+
+    for direction in [Direction::Right, Direction::LeftUp, Direction::Down] {
+        let next = grid.step(start, direction);
+        assert_eq!(
+            grid.point(next),
+            Point::new(1, 1).add(&Point::from(direction))
+        );
+    }
+    assert_eq!(grid.cells()[grid.step(start, Direction::RightDown)], 'k');
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "leaves the 4x3 grid")]
+fn step_wraps_test() {
+    let grid: Grid<char> = Grid::parse("abcd\nefgh\nijkl\n", None).unwrap();
+    grid.step(grid.index(&Point::new(3, 0)), Direction::Right);
+}
+
+fn visit(grid: &mut Grid<char>, direction: Direction) -> Vec<Point> {
+    let mut iterator = GridIterator::new(grid, &direction, 1);
+    let mut visited = Vec::new();
+
+    while iterator.have_next() {
+        visited.push(*iterator.get_current_position());
+        iterator.next(true);
+    }
+
+    visited
+}
+
+#[test]
+fn points_reading_order_test() {
+    let grid: Grid<char> = Grid::parse("abc\ndef\n", None).unwrap();
+    let points: Vec<Point> = grid.points().collect();
+
+    assert_eq!(
+        points,
+        vec![
+            Point::new(0, 0),
+            Point::new(1, 0),
+            Point::new(2, 0),
+            Point::new(0, 1),
+            Point::new(1, 1),
+            Point::new(2, 1),
+        ]
+    );
+    let mut sorted = points.clone();
+    sorted.sort_by_key(|point| (point.y, point.x));
+    assert_eq!(points, sorted);
+}
+
+#[test]
+fn iterator_order_test() {
+    let mut grid: Grid<char> = Grid::parse("abc\ndef\n", None).unwrap();
+    let points: Vec<Point> = grid.points().collect();
+
+    assert_eq!(visit(&mut grid, Direction::Right), points);
+
+    let mut reversed = points.clone();
+    reversed.reverse();
+    assert_eq!(visit(&mut grid, Direction::Left), reversed);
+
+    let mut columns = points.clone();
+    columns.sort_by_key(|point| (point.x, point.y));
+    assert_eq!(visit(&mut grid, Direction::Down), columns);
+
+    columns.reverse();
+    assert_eq!(visit(&mut grid, Direction::Up), columns);
+}
+
+#[test]
+fn iterator_snapshot_test() {
+    let mut grid: Grid<char> = Grid::parse("abc\ndef\n", None).unwrap();
+    let mut iterator = GridIterator::new(&mut grid, &Direction::Right, 1);
+    iterator.next(true);
+    iterator.next(true);
+    let snapshot = iterator.snapshot();
+
+    // Branch off along a new line and then down a column until the end
+    iterator.next(true);
+    iterator.change_direction(&Direction::Down);
+    while iterator.have_next() {
+        iterator.next(false);
+    }
+    assert_eq!(iterator.get_current_direction(), &Direction::Down);
+
+    iterator.restore(&snapshot);
+    assert!(iterator.have_next());
+    assert_eq!(iterator.get_current_position(), &Point::new(2, 0));
+    assert_eq!(iterator.get_current_direction(), &Direction::Right);
+    assert_eq!(iterator.next(true), Point::new(0, 1));
+    assert_eq!(iterator.get_current_value(), Some('d'));
+}
+
+#[test]
+fn iterator_count_keeps_state_test() {
+    let mut grid: Grid<char> = Grid::parse("aba\nbab\n", None).unwrap();
+    let mut iterator = GridIterator::new(&mut grid, &Direction::Right, 1);
+    iterator.next(true);
+
+    assert_eq!(iterator.count(&'a'), 2);
+    assert!(iterator.have_next());
+    assert_eq!(iterator.get_current_position(), &Point::new(1, 0));
+}
+
+#[test]
+fn exced_bounds_test() {
+    let grid: Grid<char> = Grid::parse(".....\n.....\n", None).unwrap();
+    let start = Point::new(1, 0);
+
+    assert!(!grid.exced_bounds(&start, &Direction::Right, 3));
+    assert!(grid.exced_bounds(&start, &Direction::Right, 4));
+    assert!(!grid.exced_bounds(&start, &Direction::Left, 1));
+    assert!(grid.exced_bounds(&start, &Direction::RightDown, 2));
+}
+
+fn visit_with(grid: &mut Grid<char>, direction: Direction, chunk: i32, stride: i32) -> Vec<i32> {
+    let mut iterator = GridIterator::new(grid, &direction, chunk).with_stride(stride);
+    let mut visited = Vec::new();
+
+    while iterator.have_next() {
+        let point = iterator.get_current_position();
+        visited.push(point.y * 10 + point.x);
+        iterator.next(true);
+    }
+
+    visited
+}
+
+#[test]
+fn iterator_chunk_and_stride_test() {
+    let mut grid: Grid<char> = Grid::parse(".....\n.....\n", None).unwrap();
+
+    assert_eq!(
+        visit_with(&mut grid, Direction::Right, 1, 2),
+        vec![0, 2, 4, 10, 12, 14]
+    );
+    assert_eq!(
+        visit_with(&mut grid, Direction::Right, 2, 2),
+        vec![0, 2, 10, 12]
+    );
+    assert_eq!(
+        visit_with(&mut grid, Direction::Right, 4, 1),
+        vec![0, 1, 10, 11]
+    );
+    assert_eq!(
+        visit_with(&mut grid, Direction::Left, 1, 3),
+        vec![14, 11, 4, 1]
+    );
+    assert_eq!(
+        visit_with(&mut grid, Direction::Down, 2, 1),
+        vec![0, 1, 2, 3, 4]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Invalid stride 0")]
+fn iterator_invalid_stride_test() {
+    let mut grid: Grid<char> = Grid::parse("..\n", None).unwrap();
+    let _ = GridIterator::new(&mut grid, &Direction::Right, 1).with_stride(0);
+}
+
+#[test]
+fn chunks_along_test() {
+    let grid: Grid<char> = Grid::parse("abc\ndef\n", None).unwrap();
+
+    let right: Vec<_> = grid.chunks_along::<2>(&Direction::Right).collect();
+    assert_eq!(
+        right,
+        vec![
+            (Point::new(0, 0), ['a', 'b']),
+            (Point::new(1, 0), ['b', 'c']),
+            (Point::new(0, 1), ['d', 'e']),
+            (Point::new(1, 1), ['e', 'f']),
+        ]
+    );
+
+    let diagonal: Vec<_> = grid.chunks_along::<2>(&Direction::LeftDown).collect();
+    assert_eq!(
+        diagonal,
+        vec![
+            (Point::new(1, 0), ['b', 'd']),
+            (Point::new(2, 0), ['c', 'e']),
+        ]
+    );
+
+    assert_eq!(grid.chunks_along::<3>(&Direction::Up).count(), 0);
+    assert_eq!(grid.chunks_along::<1>(&Direction::Left).count(), 6);
+}
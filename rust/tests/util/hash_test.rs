@@ -0,0 +1,89 @@
+use aoc::util::hash::*;
+use aoc::util::rng::Rng;
+use std::collections::HashMap;
+
+/// Applies the same random inserts, removals and lookups to a [`U64Map`] and a [`HashMap`].
+///
+/// Keys come from a small range so runs of colliding keys form and removals have to shift
+/// entries back.
+fn check_against_std(seed: u64, load_factor: f64) {
+    let mut rng = Rng::new(seed);
+    let mut map = U64Map::with_load_factor(0, load_factor);
+    let mut expected = HashMap::new();
+
+    for step in 0..5000 {
+        let key = rng.range(0..=300) * 1021;
+        match rng.range(0..=3) {
+            0 | 1 => assert_eq!(map.insert(key, step), expected.insert(key, step)),
+            2 => assert_eq!(map.remove(key), expected.remove(&key)),
+            _ => assert_eq!(map.get(key), expected.get(&key)),
+        }
+        assert_eq!(map.len(), expected.len());
+    }
+
+    for key in 0..=300 * 1021 {
+        assert_eq!(map.contains_key(key), expected.contains_key(&key));
+    }
+    let mut entries: Vec<_> = map.iter().map(|(key, &value)| (key, value)).collect();
+    let mut wanted: Vec<_> = expected.into_iter().collect();
+    entries.sort_unstable();
+    wanted.sort_unstable();
+    assert_eq!(entries, wanted);
+}
+
+#[test]
+fn u64_map_matches_hash_map_test() {
+    for seed in 0..8 {
+        for load_factor in [0.25, 0.5, DEFAULT_LOAD_FACTOR, 0.95] {
+            check_against_std(seed, load_factor);
+        }
+    }
+}
+
+#[test]
+fn u64_map_test() {
+    let mut map: U64Map<u32> = (0..100).map(|key| (key, key as u32)).collect();
+    assert_eq!(map.len(), 100);
+    assert_eq!(map.get(42), Some(&42));
+    assert_eq!(map.get(100), None);
+
+    *map.get_or_insert_with(7, || unreachable!()) += 1;
+    *map.get_or_insert_with(u64::MAX, || 5) += 1;
+    assert_eq!(map.get(7), Some(&8));
+    assert_eq!(map.get(u64::MAX), Some(&6));
+    if let Some(value) = map.get_mut(0) {
+        *value = 9;
+    }
+    assert_eq!(map.values().sum::<u32>(), (0..100).sum::<u32>() + 1 + 6 + 9);
+    assert_eq!(map.keys().max(), Some(u64::MAX));
+
+    map.clear();
+    assert!(map.is_empty());
+    assert_eq!(map.get(42), None);
+}
+
+#[test]
+fn u64_map_capacity_test() {
+    let mut map = U64Map::with_capacity(1000);
+    let capacity = map.capacity();
+    assert!(capacity >= 1000);
+
+    map.extend((0..capacity as u64).map(|key| (key, ())));
+    assert_eq!(map.capacity(), capacity);
+    map.insert(u64::MAX, ());
+    assert!(map.capacity() > capacity);
+    assert_eq!(map.len(), capacity + 1);
+}
+
+#[test]
+#[should_panic(expected = "Invalid load factor")]
+fn u64_map_invalid_load_factor_test() {
+    U64Map::<()>::with_load_factor(10, 1.0);
+}
+
+#[test]
+fn fnv1a_test() {
+    assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+    assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+    assert_eq!(fnv1a(b"foobar"), 0x8594_4171_f739_67e8);
+}
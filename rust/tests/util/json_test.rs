@@ -0,0 +1,69 @@
+use aoc::util::json::*;
+
+#[test]
+fn parse_test() {
+    let json = Json::parse(r#" {"a": [1, -2.5, true, null], "b": "x\"\né"} "#).unwrap();
+
+    assert_eq!(
+        json.get("a"),
+        Some(&Json::Array(vec![
+            Json::Number(1.0),
+            Json::Number(-2.5),
+            Json::Bool(true),
+            Json::Null
+        ]))
+    );
+    assert_eq!(json.get("b").and_then(Json::as_str), Some("x\"\né"));
+    assert_eq!(json.get("c"), None);
+}
+
+#[test]
+fn parse_error_test() {
+    assert_eq!(Json::parse("[1, 2").unwrap_err().offset, 5);
+    assert_eq!(Json::parse("{\"a\" 1}").unwrap_err().offset, 5);
+    assert!(Json::parse("\"open").is_err());
+    assert!(Json::parse("1 2").is_err());
+}
+
+#[test]
+fn unicode_escape_test() {
+    let parse = |text: &str| Json::parse(text).map(|json| json.to_string());
+
+    assert_eq!(parse(r#""\u00e9\u0041""#), Ok("\"éA\"".to_string()));
+    assert_eq!(parse(r#""\ud83d\ude00""#), Ok("\"😀\"".to_string()));
+
+    // Fewer than four hex digits, or a sign that integer parsing would accept
+    assert!(Json::parse(r#""\u41""#).is_err());
+    assert!(Json::parse(r#""\u41x1""#).is_err());
+    assert!(Json::parse(r#""\u+041""#).is_err());
+
+    // Surrogates that are not part of a pair
+    assert!(Json::parse(r#""\ud83d""#).is_err());
+    assert!(Json::parse(r#""\ud83dx""#).is_err());
+    assert!(Json::parse(r#""\ud83d\u0041""#).is_err());
+    assert!(Json::parse(r#""\ude00""#).is_err());
+}
+
+#[test]
+fn round_trip_test() {
+    let json = Json::Object(vec![
+        ("year".to_string(), Json::from(2024_u32)),
+        ("text".to_string(), Json::from("tab\tquote\"\u{1}")),
+        ("empty".to_string(), Json::Array(vec![])),
+    ]);
+    let text = json.to_string();
+
+    assert_eq!(
+        text,
+        r#"{"year":2024,"text":"tab\tquote\"\u0001","empty":[]}"#
+    );
+    assert_eq!(Json::parse(&text), Ok(json));
+}
+
+#[test]
+fn as_u64_test() {
+    assert_eq!(Json::Number(42.0).as_u64(), Some(42));
+    assert_eq!(Json::Number(-1.0).as_u64(), None);
+    assert_eq!(Json::Number(1.5).as_u64(), None);
+    assert_eq!(Json::from("42").as_u64(), None);
+}
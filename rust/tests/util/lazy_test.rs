@@ -0,0 +1,28 @@
+use aoc::util::lazy::*;
+use std::cell::Cell;
+
+#[test]
+fn computed_once_test() {
+    let calls = Cell::new(0);
+    let cached = Cached::new();
+    assert!(!cached.is_computed());
+
+    for _ in 0..3 {
+        let value = cached.get_or_compute(|| {
+            calls.set(calls.get() + 1);
+            42
+        });
+        assert_eq!(*value, 42);
+    }
+
+    assert_eq!(calls.get(), 1);
+    assert_eq!(cached.get(), Some(&42));
+}
+
+#[test]
+fn clone_keeps_value_test() {
+    let cached = Cached::new();
+    cached.get_or_compute(|| vec![1, 2, 3]);
+    assert_eq!(cached.clone().get(), Some(&vec![1, 2, 3]));
+    assert_eq!(format!("{:?}", Cached::<u8>::new()), "Cached(<pending>)");
+}
@@ -0,0 +1,18 @@
+use aoc::util::math::*;
+
+#[test]
+fn gcd_lcm_test() {
+    assert_eq!(gcd(12u32, 18), 6);
+    assert_eq!(gcd(0i64, 7), 7);
+    assert_eq!(lcm(4u64, 6), 12);
+    assert_eq!([23u64, 19, 13, 17].into_iter().fold(1, lcm), 96577);
+}
+
+#[test]
+fn range_sum_test() {
+    assert_eq!(range_sum(0u64, 0), 0);
+    assert_eq!(range_sum(0u64, 5), 10);
+    assert_eq!(range_sum(3u32, 4), 3 + 4 + 5 + 6);
+    assert_eq!(range_sum(-2i64, 5), 0);
+    assert_eq!(run_checksum(9u64, 2, 2), 9 * 2 + 9 * 3);
+}
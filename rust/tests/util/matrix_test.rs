@@ -0,0 +1,62 @@
+use aoc::util::matrix::*;
+use aoc::util::parse::*;
+
+#[test]
+fn rational_test() {
+    let half = Rational::new(3, 6);
+    assert_eq!(half, Rational::new(-1, -2));
+    assert_eq!(half.add(&Rational::new(1, 3)), Some(Rational::new(5, 6)));
+    assert_eq!(half.div(&Rational::ZERO), None);
+    assert_eq!(Rational::new(4, -8).to_string(), "-1/2");
+    assert_eq!(
+        Rational::integer(i128::MAX).mul(&Rational::integer(2)),
+        None
+    );
+}
+
+#[test]
+fn solve_test() {
+    // 2x + y = 5, x - y = 1, with a redundant third equation.
+    let a = [vec![2, 1], vec![1, -1], vec![3, 0]];
+    let b = [5, 1, 6];
+    assert_eq!(
+        solve(&a, &b),
+        Ok(vec![Rational::integer(2), Rational::integer(1)])
+    );
+
+    let fractional = solve(&[vec![2, 0], vec![0, 3]], &[1, 1]).unwrap();
+    assert_eq!(fractional, vec![Rational::new(1, 2), Rational::new(1, 3)]);
+}
+
+#[test]
+fn degenerate_test() {
+    let parallel = [vec![1, 1], vec![2, 2]];
+    assert_eq!(solve(&parallel, &[1, 2]), Err(SolveError::Underdetermined));
+    assert_eq!(solve(&parallel, &[1, 3]), Err(SolveError::Inconsistent));
+}
+
+#[test]
+fn rock_throw_test() {
+    let example = "\
+19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3
+";
+    let hailstones: Vec<_> = example
+        .lines()
+        .map(|line| {
+            let n: Vec<i64> = line.iter_signed().collect();
+            ([n[0], n[1], n[2]], [n[3], n[4], n[5]])
+        })
+        .collect();
+
+    let (position, velocity) = rock_throw(&hailstones).unwrap();
+    assert_eq!(position, [24, 13, 10]);
+    assert_eq!(velocity, [-3, 1, 2]);
+    assert_eq!(
+        rock_throw(&hailstones[..2]),
+        Err(SolveError::Underdetermined)
+    );
+}
@@ -0,0 +1,55 @@
+use aoc::util::monkey::*;
+
+const EXAMPLE: &str = "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1
+";
+
+#[test]
+fn parse_test() {
+    let monkeys = parse(EXAMPLE);
+    assert_eq!(monkeys.len(), 4);
+    assert_eq!(monkeys[2].operation, Operation::Square);
+    assert_eq!(monkeys[1].items, vec![54, 65, 75, 74]);
+    assert_eq!((monkeys[3].if_true, monkeys[3].if_false), (0, 1));
+}
+
+#[test]
+fn relief_test() {
+    let mut troop = Troop::new(parse(EXAMPLE));
+    troop.rounds(20, Relief::Divide(3));
+    assert_eq!(troop.inspections, vec![101, 95, 7, 105]);
+    assert_eq!(troop.monkey_business(), 10605);
+}
+
+#[test]
+fn modular_test() {
+    let mut troop = Troop::new(parse(EXAMPLE));
+    troop.rounds(10_000, Relief::None);
+    assert_eq!(troop.monkey_business(), 2713310158);
+}
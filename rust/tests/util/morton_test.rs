@@ -0,0 +1,33 @@
+use aoc::util::grid::Grid;
+use aoc::util::morton::*;
+use aoc::util::point::Point;
+
+#[test]
+fn encode_test() {
+    assert_eq!(encode(0, 0), 0);
+    assert_eq!(encode(1, 0), 1);
+    assert_eq!(encode(0, 1), 2);
+    assert_eq!(encode(3, 3), 15);
+    assert_eq!(encode(4, 0), 16);
+
+    for (x, y) in [(0, 0), (5, 9), (1023, 1), (65535, 40000)] {
+        assert_eq!(decode(encode(x, y)), (x, y));
+    }
+}
+
+#[test]
+fn grid_test() {
+    let grid: Grid<char> = Grid::parse("abc\ndef\n", None).unwrap();
+    let mut morton = MortonGrid::from(&grid);
+
+    assert_eq!(morton.cells.len(), 16);
+    assert_eq!(morton.get_size(), 6);
+    for point in grid.points() {
+        assert_eq!(morton.get_value(&point), grid.get_value(&point));
+    }
+    assert_eq!(morton.get_value(&Point::new(3, 0)), None);
+    assert_eq!(morton.index(&Point::new(0, 2)), None);
+
+    morton.set_value(&Point::new(1, 1), 'x');
+    assert_eq!(morton.get_value(&Point::new(1, 1)), Some('x'));
+}
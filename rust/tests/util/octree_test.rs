@@ -0,0 +1,51 @@
+use aoc::util::geometry::Cuboid;
+use aoc::util::octree::*;
+use aoc::util::parse::*;
+use aoc::util::point3::Point3;
+
+const EXAMPLE: &str = "\
+pos=<10,12,12>, r=2
+pos=<12,14,12>, r=2
+pos=<16,12,12>, r=4
+pos=<14,14,14>, r=6
+pos=<50,50,50>, r=200
+pos=<10,10,10>, r=5
+";
+
+fn nanobots(input: &str) -> Vec<(Point3, i32)> {
+    input
+        .lines()
+        .map(|line| {
+            let n: Vec<i32> = line.iter_signed().collect();
+            (Point3::new(n[0], n[1], n[2]), n[3])
+        })
+        .collect()
+}
+
+#[test]
+fn nanobots_test() {
+    let bots = nanobots(EXAMPLE);
+    let bounds = bounding_box(bots.iter().map(|(p, _)| p));
+    let in_range = |cuboid: &Cuboid| {
+        bots.iter()
+            .filter(|(p, r)| cuboid.distance_to(p) <= *r)
+            .count()
+    };
+
+    let (point, count) = best_point(bounds, &Point3::EMPTY, in_range);
+    assert_eq!(point, Point3::new(12, 12, 12));
+    assert_eq!(count, 5);
+    assert_eq!(point.manhattan(&Point3::EMPTY), 36);
+}
+
+#[test]
+fn split_test() {
+    let cuboid = Cuboid::new(Point3::EMPTY, Point3::new(3, 0, 4));
+    let octants = cuboid.split();
+    assert_eq!(octants.len(), 4);
+    assert_eq!(
+        octants.iter().map(Cuboid::volume).sum::<i64>(),
+        cuboid.volume()
+    );
+    assert_eq!(cuboid.distance_to(&Point3::new(5, -1, 2)), 3);
+}
@@ -0,0 +1,33 @@
+use aoc::util::direction::Direction;
+use aoc::util::parse::*;
+
+#[test]
+fn parse_moves_test() {
+    let moves = parse_moves("R 4\nU 2\n\nforward 5\ndown 8\nL10\nUp -3\n");
+    assert_eq!(
+        moves,
+        vec![
+            (Direction::Right, 4),
+            (Direction::Up, 2),
+            (Direction::Right, 5),
+            (Direction::Down, 8),
+            (Direction::Left, 10),
+            (Direction::Up, -3),
+        ]
+    );
+}
+
+#[test]
+#[should_panic]
+fn parse_moves_unknown_direction_test() {
+    parse_moves("sideways 3");
+}
+
+#[test]
+fn parse_word_test() {
+    assert_eq!(Direction::parse_word("v"), Some(Direction::Down));
+    assert_eq!(Direction::parse_word("l"), Some(Direction::Left));
+    assert_eq!(Direction::parse_word("BACKWARD"), Some(Direction::Left));
+    assert_eq!(Direction::parse_word("north"), Some(Direction::Up));
+    assert_eq!(Direction::parse_word("sideways"), None);
+}
@@ -0,0 +1,99 @@
+use aoc::util::direction::Direction;
+use aoc::util::grid::Grid;
+use aoc::util::pathfinding::*;
+use aoc::util::point::Point;
+
+const EXAMPLE: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+
+type State = (Point, Direction);
+
+fn maze() -> (Grid<char>, Point, Point) {
+    let grid: Grid<char> = Grid::parse(EXAMPLE, None).unwrap();
+    let (mut start, mut end) = (Point::EMPTY, Point::EMPTY);
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            match grid.get_value(&Point::new(x, y)) {
+                Some('S') => start = Point::new(x, y),
+                Some('E') => end = Point::new(x, y),
+                _ => {}
+            }
+        }
+    }
+    (grid, start, end)
+}
+
+fn moves(grid: &Grid<char>) -> impl FnMut(State) -> Vec<(State, u64)> + '_ {
+    |(point, direction)| {
+        let mut result = vec![
+            ((point, direction.turn_left()), 1000),
+            ((point, direction.turn_right()), 1000),
+        ];
+        let next = point.add(&direction.to_point());
+        if grid.get_value(&next) != Some('#') {
+            result.push(((next, direction), 1));
+        }
+        result
+    }
+}
+
+#[test]
+fn dijkstra_test() {
+    let (grid, start, end) = maze();
+    let (cost, path) =
+        dijkstra((start, Direction::Right), moves(&grid), |(p, _)| p == end).unwrap();
+
+    assert_eq!(cost, 7036);
+    assert_eq!(path.first(), Some(&(start, Direction::Right)));
+    assert_eq!(path.last().map(|&(p, _)| p), Some(end));
+}
+
+#[test]
+fn dijkstra_all_test() {
+    let (grid, start, end) = maze();
+    let paths = dijkstra_all((start, Direction::Right), moves(&grid), |(p, _)| p == end).unwrap();
+
+    let tiles: std::collections::HashSet<_> = paths.nodes().into_iter().map(|(p, _)| p).collect();
+    assert_eq!(paths.cost, 7036);
+    assert_eq!(tiles.len(), 45);
+    assert_eq!(paths.count, 3);
+}
+
+#[test]
+fn dijkstra_all_count_test() {
+    // Lattice paths on a 3x3 grid of moves only right or down: C(4, 2) = 6.
+    let neighbours = |(x, y): (u32, u32)| {
+        let mut result = Vec::new();
+        if x < 2 {
+            result.push(((x + 1, y), 1));
+        }
+        if y < 2 {
+            result.push(((x, y + 1), 1));
+        }
+        result
+    };
+    let paths = dijkstra_all((0, 0), neighbours, |node| node == (2, 2)).unwrap();
+    assert_eq!((paths.cost, paths.count), (4, 6));
+    assert_eq!(paths.nodes().len(), 9);
+}
+
+#[test]
+fn dijkstra_unreachable_test() {
+    assert!(dijkstra(0u32, |n| [((n + 1) % 3, 1)], |n| n == 5).is_none());
+    assert!(dijkstra_all(0u32, |n| [((n + 1) % 3, 1)], |n| n == 5).is_none());
+}
@@ -0,0 +1,49 @@
+use aoc::util::perfect::*;
+use aoc::util::rng::Rng;
+
+#[test]
+fn perfect_index_test() {
+    let index = PerfectIndex::new(["AA", "JK", "x00", "qnf", "AA", "a-very-long-wire-name"]);
+    assert_eq!(index.len(), 5);
+    assert_eq!(
+        index.keys(),
+        ["AA", "JK", "x00", "qnf", "a-very-long-wire-name"]
+    );
+
+    for (i, name) in index.keys().iter().enumerate() {
+        assert_eq!(index.index(name), Some(i));
+        assert_eq!(index.key(i), name);
+    }
+    for missing in [
+        "",
+        "A",
+        "AB",
+        "x01",
+        "a-very-long-wire-nam3",
+        "a-very-long-wire-name2",
+    ] {
+        assert_eq!(index.index(missing), None);
+    }
+}
+
+#[test]
+fn perfect_index_sizes_test() {
+    let mut rng = Rng::new(7);
+    for len in [0, 1, 2, 3, 17, 100, 1000, 5000] {
+        let names: Vec<String> = (0..len)
+            .map(|_| {
+                (0..rng.range(1..=12))
+                    .map(|_| (b'a' + rng.range(0..=25) as u8) as char)
+                    .collect()
+            })
+            .collect();
+        let index = PerfectIndex::new(&names);
+
+        assert!(index.len() <= len);
+        assert_eq!(index.is_empty(), len == 0);
+        for name in &names {
+            assert_eq!(index.key(index.index(name).unwrap()), name);
+        }
+        assert_eq!(index.index("0"), None);
+    }
+}
@@ -0,0 +1,86 @@
+use aoc::util::point3::Point3;
+use aoc::util::point_cloud::*;
+use aoc::util::rng::Rng;
+use std::collections::HashSet;
+
+fn transpose(m: &Rotation) -> Rotation {
+    let mut t = [[0; 3]; 3];
+    for (i, row) in m.iter().enumerate() {
+        for (j, &value) in row.iter().enumerate() {
+            t[j][i] = value;
+        }
+    }
+    t
+}
+
+/// Describes world points as seen by a scanner with the given alignment.
+fn observe(world: &[Point3], alignment: &Alignment) -> Vec<Point3> {
+    let inverse = transpose(&alignment.rotation);
+    world
+        .iter()
+        .map(|p| rotate(&inverse, &p.sub(&alignment.translation)))
+        .collect()
+}
+
+fn beacons(rng: &mut Rng, count: usize) -> Vec<Point3> {
+    let mut coordinate = || rng.range(0..=2000) as i32 - 1000;
+    (0..count)
+        .map(|_| Point3::new(coordinate(), coordinate(), coordinate()))
+        .collect()
+}
+
+#[test]
+fn rotations_test() {
+    let all = rotations();
+    assert_eq!(all.len(), 24);
+    assert_eq!(all.iter().collect::<HashSet<_>>().len(), 24);
+
+    let point = Point3::new(1, 2, 3);
+    assert_eq!(rotate(&all[0], &point), point);
+    let images: HashSet<_> = all.iter().map(|r| rotate(r, &point)).collect();
+    assert_eq!(images.len(), 24);
+}
+
+#[test]
+fn align_test() {
+    let mut rng = Rng::new(19);
+    let world = beacons(&mut rng, 40);
+    let expected = Alignment {
+        rotation: rotations()[17],
+        translation: Point3::new(68, -1246, -43),
+    };
+
+    let reference = &world[..25];
+    let cloud = observe(&world[13..], &expected);
+    assert_eq!(align(reference, &cloud, 12), Some(expected));
+    assert_eq!(align(reference, &cloud, 13), None);
+}
+
+#[test]
+fn merge_test() {
+    let mut rng = Rng::new(2021);
+    let world = beacons(&mut rng, 60);
+    let second = Alignment {
+        rotation: rotations()[5],
+        translation: Point3::new(300, 10, -20),
+    };
+    let third = Alignment {
+        rotation: rotations()[22],
+        translation: Point3::new(-150, 400, 75),
+    };
+
+    // The third scanner only overlaps with the second.
+    let clouds = vec![
+        world[..25].to_vec(),
+        observe(&world[12..45], &second),
+        observe(&world[32..], &third),
+    ];
+    let (points, alignments) = merge(&clouds, 12).unwrap();
+
+    assert_eq!(points.len(), 60);
+    assert_eq!(alignments[1], second);
+    assert_eq!(alignments[2], third);
+
+    let disconnected = vec![world[..20].to_vec(), world[40..].to_vec()];
+    assert!(merge(&disconnected, 12).is_none());
+}
@@ -0,0 +1,46 @@
+use aoc::util::point::Point;
+
+#[test]
+fn reduced_test() {
+    assert_eq!(Point::new(6, -4).reduced(), Point::new(3, -2));
+    assert_eq!(Point::new(0, 7).reduced(), Point::DOWN);
+    assert_eq!(Point::new(-5, 0).reduced(), Point::LEFT);
+    assert_eq!(Point::EMPTY.reduced(), Point::EMPTY);
+}
+
+#[test]
+fn collinear_test() {
+    let a = Point::new(1, 1);
+    assert!(Point::collinear(&a, &Point::new(3, 5), &Point::new(4, 7)));
+    assert!(!Point::collinear(&a, &Point::new(3, 5), &Point::new(4, 8)));
+    assert!(Point::collinear(&a, &a, &Point::new(9, -2)));
+}
+
+#[test]
+fn line_of_sight_test() {
+    // Asteroids hidden behind others share the same reduced direction.
+    let map = ".#..#\n.....\n#####\n....#\n...##";
+    let asteroids: Vec<Point> = map
+        .lines()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.char_indices()
+                .filter(|&(_, c)| c == '#')
+                .map(move |(x, _)| Point::new(x as i32, y as i32))
+        })
+        .collect();
+
+    let visible = |station: &Point| {
+        let mut directions: Vec<Point> = asteroids
+            .iter()
+            .filter(|a| *a != station)
+            .map(|a| a.sub(station).reduced())
+            .collect();
+        directions.sort_by_key(|p| (p.x, p.y));
+        directions.dedup();
+        directions.len()
+    };
+
+    assert_eq!(asteroids.iter().map(visible).max(), Some(8));
+    assert_eq!(visible(&Point::new(3, 4)), 8);
+}
@@ -0,0 +1,73 @@
+use aoc::util::parse::*;
+use aoc::util::rangemap::*;
+
+const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+fn almanac() -> (Vec<i64>, PiecewiseMap) {
+    let (seeds, maps) = EXAMPLE.split_once("\n\n").unwrap();
+    let seeds = seeds.iter_unsigned::<u64>().map(|n| n as i64).collect();
+    (seeds, PiecewiseMap::parse(maps))
+}
+
+#[test]
+fn map_test() {
+    let (seeds, map) = almanac();
+    let locations: Vec<i64> = seeds.iter().map(|&seed| map.map(seed)).collect();
+    assert_eq!(locations, vec![82, 43, 86, 35]);
+}
+
+#[test]
+fn map_ranges_test() {
+    let (seeds, map) = almanac();
+    let ranges = seeds.chunks(2).map(|c| c[0]..c[0] + c[1]).collect();
+    let lowest = map.map_ranges(ranges).iter().map(|r| r.start).min();
+    assert_eq!(lowest, Some(46));
+}
+
+#[test]
+fn split_test() {
+    let mut layer = Layer::new();
+    layer.add(10..20, 100);
+    layer.add(25..30, -25);
+
+    assert_eq!(layer.map(9), 9);
+    assert_eq!(layer.map(10), 110);
+    assert_eq!(layer.map(27), 2);
+    assert_eq!(layer.map_range(5..28), vec![5..10, 110..120, 20..25, 0..3]);
+    assert_eq!(layer.map_range(12..15), vec![112..115]);
+    assert_eq!(layer.map_range(40..50), vec![40..50]);
+}
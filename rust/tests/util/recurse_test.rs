@@ -0,0 +1,42 @@
+use aoc::util::recurse::*;
+use std::ops::ControlFlow::{Break, Continue};
+
+#[test]
+fn trampoline_order_test() {
+    // Pre-order traversal of a complete binary tree of depth 2.
+    let mut visited = Vec::new();
+    let _ = trampoline::<_, (), _>(1, |node, calls| {
+        visited.push(node);
+        if node < 4 {
+            calls.push(node * 2);
+            calls.push(node * 2 + 1);
+        }
+        Continue(())
+    });
+    assert_eq!(visited, vec![1, 2, 4, 5, 3, 6, 7]);
+}
+
+#[test]
+fn trampoline_break_test() {
+    let result = trampoline(0, |n, calls| {
+        if n == 5 {
+            return Break(n);
+        }
+        calls.push(n + 1);
+        Continue(())
+    });
+    assert_eq!(result, Break(5));
+}
+
+#[test]
+fn trampoline_deep_test() {
+    let mut depth = 0;
+    let _ = trampoline::<_, (), _>(0u32, |n, calls| {
+        depth = n;
+        if n < 1_000_000 {
+            calls.push(n + 1);
+        }
+        Continue(())
+    });
+    assert_eq!(depth, 1_000_000);
+}
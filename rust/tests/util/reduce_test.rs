@@ -0,0 +1,27 @@
+use aoc::util::reduce::*;
+
+const EXAMPLE: &[u8] = b"dabAcCaCBAcCcaDA";
+
+#[test]
+fn reduce_polymer_test() {
+    assert_eq!(reduce_polymer(EXAMPLE), b"dabCBAcaDA");
+    assert_eq!(reduce_polymer(b"aA"), b"");
+    assert_eq!(reduce_polymer(b"abBA"), b"");
+    assert_eq!(reduce_polymer(b"abAB"), b"abAB");
+    assert_eq!(reduce_polymer(b"aabAAB"), b"aabAAB");
+}
+
+#[test]
+fn shortest_without_unit_test() {
+    assert_eq!(shortest_without_unit(EXAMPLE), Some((b'c', 4)));
+    assert_eq!(shortest_without_unit(b""), None);
+}
+
+#[test]
+fn reduce_generic_test() {
+    // Matching brackets annihilate, leaving the unbalanced ones.
+    let result = reduce("([])(]".chars(), |a, b| {
+        matches!((a, b), ('(', ')') | ('[', ']'))
+    });
+    assert_eq!(result, vec!['(', ']']);
+}
@@ -0,0 +1,29 @@
+use aoc::util::direction::Direction;
+use aoc::util::parse::parse_moves as moves;
+use aoc::util::point::Point;
+use aoc::util::rope::*;
+
+#[test]
+fn tail_visits_test() {
+    let example = moves("R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2");
+    assert_eq!(tail_visits(&example, 2).len(), 13);
+    assert_eq!(tail_visits(&example, 10).len(), 1);
+}
+
+#[test]
+fn tail_visits_long_rope_test() {
+    let example = moves("R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20");
+    assert_eq!(tail_visits(&example, 10).len(), 36);
+}
+
+#[test]
+fn diagonal_follow_test() {
+    let mut rope = Rope::new(2);
+    rope.step(&Direction::Right);
+    rope.step(&Direction::Up);
+    assert_eq!(rope.tail(), Point::EMPTY);
+
+    rope.step(&Direction::Up);
+    assert_eq!(rope.head(), Point::new(1, -2));
+    assert_eq!(rope.tail(), Point::new(1, -1));
+}
@@ -0,0 +1,99 @@
+use aoc::util::runs::*;
+
+/// The disk map `12345`: files 0, 1 and 2 separated by gaps of two and four blocks.
+fn disk() -> Runs<Option<u64>> {
+    let mut disk = Runs::new();
+    for (index, length) in [1, 2, 3, 4, 5].into_iter().enumerate() {
+        let value = (index % 2 == 0).then_some(index as u64 / 2);
+        disk.push(value, length);
+    }
+    disk
+}
+
+fn file_id(value: &Option<u64>) -> Option<u64> {
+    *value
+}
+
+#[test]
+fn push_test() {
+    let disk = disk();
+
+    assert_eq!(disk.len(), 5);
+    assert_eq!(disk.end(), 15);
+    assert_eq!(
+        *disk.get(2),
+        Run {
+            value: Some(1),
+            start: 3,
+            length: 3
+        }
+    );
+    assert_eq!(disk.get(3).end(), 10);
+}
+
+#[test]
+fn split_test() {
+    let mut disk = disk();
+    let front = disk.split(4, 2);
+
+    assert_eq!((disk.get(front).start, disk.get(front).length), (10, 2));
+    assert_eq!((disk.get(4).start, disk.get(4).length), (12, 3));
+    assert_eq!(disk.get(front).value, Some(2));
+}
+
+#[test]
+#[should_panic]
+fn split_past_run_test() {
+    disk().split(0, 2);
+}
+
+#[test]
+fn relocate_test() {
+    let mut disk = disk();
+    let moved = disk.relocate(2, 3, None);
+
+    assert_eq!(
+        *disk.get(moved),
+        Run {
+            value: Some(1),
+            start: 6,
+            length: 3
+        }
+    );
+    assert_eq!(disk.get(2).value, None);
+    assert_eq!((disk.get(3).start, disk.get(3).length), (9, 1));
+}
+
+#[test]
+fn merge_test() {
+    let mut disk = disk();
+    disk.relocate(2, 3, None);
+    disk.merge();
+
+    let runs: Vec<_> = disk
+        .iter()
+        .map(|run| (run.value, run.start, run.length))
+        .collect();
+    assert_eq!(
+        runs,
+        vec![
+            (Some(0), 0, 1),
+            (None, 1, 5),
+            (Some(1), 6, 3),
+            (None, 9, 1),
+            (Some(2), 10, 5)
+        ]
+    );
+}
+
+#[test]
+fn checksum_test() {
+    let mut disk = disk();
+    // 0..111....22222: 1 * (3 + 4 + 5) + 2 * (10 + 11 + 12 + 13 + 14)
+    assert_eq!(disk.checksum(file_id), 132);
+
+    // 0.....111.22222: 1 * (6 + 7 + 8) + 2 * (10 + 11 + 12 + 13 + 14)
+    disk.relocate(2, 3, None);
+    assert_eq!(disk.checksum(file_id), 141);
+    assert_eq!(disk.checksum(|_| Some(1)), (0..15).sum());
+}
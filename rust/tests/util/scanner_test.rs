@@ -0,0 +1,55 @@
+use aoc::util::scanner::*;
+
+#[test]
+fn classes_test() {
+    assert!(is_digit(b'0') && is_digit(b'9') && !is_digit(b'a'));
+    assert!(is_alpha(b'a') && is_alpha(b'Z') && !is_alpha(b'_'));
+    assert!(is_space(b' ') && is_space(b'\n') && !is_space(b'x'));
+    assert!(is_punct(b'(') && is_punct(b',') && !is_punct(b'7'));
+    assert!((128..=255).all(|byte| CLASSES[byte] == 0));
+
+    const BRACKETS: ByteSet = ByteSet::new(b"()[]");
+    assert!(BRACKETS.contains(b'[') && !BRACKETS.contains(b'{'));
+}
+
+#[test]
+fn scanner_test() {
+    const START: ByteSet = ByteSet::new(b"m");
+    let mut scanner = Scanner::new("xmul(12,4567)!");
+
+    assert!(scanner.skip_until(&START));
+    assert_eq!(scanner.position(), 1);
+    assert!(!scanner.eat(b"mux("));
+    assert!(scanner.eat(b"mul("));
+    assert_eq!(scanner.number(3), Some(12));
+    assert!(scanner.eat(b","));
+    assert_eq!(scanner.number(3), Some(456));
+    assert_eq!(scanner.number(3), Some(7));
+    assert_eq!(scanner.number(3), None);
+    assert_eq!(scanner.peek(), Some(b')'));
+
+    scanner.advance(10);
+    assert!(scanner.is_done());
+    assert!(!scanner.skip_until(&START));
+    assert_eq!(scanner.peek(), None);
+}
+
+#[test]
+fn attempt_test() {
+    let mut scanner = Scanner::new("mul(1,x)");
+    let pair = |scanner: &mut Scanner| {
+        scanner.eat(b"mul(").then_some(())?;
+        let a = scanner.number(3)?;
+        scanner.eat(b",").then_some(())?;
+        Some((a, scanner.number(3)?))
+    };
+
+    assert_eq!(scanner.attempt(pair), None);
+    assert_eq!(scanner.position(), 0);
+    assert_eq!(scanner.attempt(|scanner| scanner.number(3)), None);
+    assert_eq!(
+        scanner.attempt(|scanner| scanner.eat(b"mul").then_some(3)),
+        Some(3)
+    );
+    assert_eq!(scanner.position(), 3);
+}
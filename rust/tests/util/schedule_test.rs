@@ -0,0 +1,42 @@
+use aoc::util::graph::Dag;
+use aoc::util::schedule::*;
+
+fn steps() -> Dag {
+    // Step dependencies from 2018 day 7, with A = 0, B = 1 and so on.
+    let mut dag = Dag::new(6);
+    for (from, to) in [(2, 0), (2, 5), (0, 1), (0, 3), (1, 4), (3, 4), (5, 4)] {
+        dag.add_edge(from, to, 1);
+    }
+    dag
+}
+
+#[test]
+fn simulate_test() {
+    let schedule = simulate(&steps(), 2, |task| task as u64 + 1);
+    assert_eq!(schedule.time, 15);
+    assert_eq!(schedule.order, vec![2, 0, 1, 5, 3, 4]);
+}
+
+#[test]
+fn simulate_single_worker_test() {
+    let schedule = simulate(&steps(), 1, |_| 1);
+    assert_eq!(schedule.time, 6);
+    assert_eq!(schedule.order, steps().topological_order().unwrap());
+}
+
+#[test]
+fn simulate_with_test() {
+    let mut ticks = Vec::new();
+    simulate_with(
+        &steps(),
+        2,
+        |task| task as u64 + 1,
+        |tick| {
+            ticks.push((tick.time, tick.workers.to_vec()));
+        },
+    );
+
+    assert_eq!(ticks.len(), 15);
+    assert_eq!(ticks[0], (0, vec![Some(2), None]));
+    assert_eq!(ticks[3], (3, vec![Some(0), Some(5)]));
+}
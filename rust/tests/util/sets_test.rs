@@ -0,0 +1,54 @@
+use aoc::util::sets::*;
+
+#[test]
+fn slice_algebra_test() {
+    let a = [1, 3, 5, 7];
+    let b = [3, 4, 5, 8];
+    assert_eq!(intersection(&a, &b), vec![3, 5]);
+    assert_eq!(union(&a, &b), vec![1, 3, 4, 5, 7, 8]);
+    assert_eq!(difference(&a, &b), vec![1, 7]);
+    assert_eq!(difference(&b, &a), vec![4, 8]);
+    assert!(is_subset(&[3, 5], &a));
+    assert!(!is_subset(&[3, 4], &a));
+    assert!(is_subset::<i32>(&[], &[]));
+}
+
+#[test]
+fn small_set_test() {
+    let mut set: SmallSet<u8, 4> = SmallSet::new();
+    assert!(set.insert(5));
+    assert!(set.insert(1));
+    assert!(!set.insert(5));
+    assert!(set.insert(3));
+    assert_eq!(set.as_slice(), &[1, 3, 5]);
+    assert!(set.contains(&3));
+    assert!(!set.contains(&4));
+
+    assert!(set.remove(&3));
+    assert!(!set.remove(&3));
+    assert_eq!(set.as_slice(), &[1, 5]);
+}
+
+#[test]
+fn small_set_spill_test() {
+    let mut set: SmallSet<u32, 2> = [9, 2].into_iter().collect();
+    assert!(!set.spilled());
+
+    set.insert(4);
+    assert!(set.spilled());
+    assert_eq!(set.as_slice(), &[2, 4, 9]);
+    assert_eq!(set, [4, 9, 2, 2].into_iter().collect());
+}
+
+#[test]
+fn badge_test() {
+    // Item shared by a group of three rucksacks.
+    let group = [
+        "vJrwpWtwJgWrhcsFMMfFFhFp",
+        "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+        "PmmdzqPrVvPwwTWBwg",
+    ];
+    let sets: Vec<SmallSet<u8, 64>> = group.iter().map(|line| line.bytes().collect()).collect();
+    let badge = sets[0].intersection(&sets[1]).intersection(&sets[2]);
+    assert_eq!(badge.as_slice(), b"r");
+}
@@ -0,0 +1,52 @@
+use aoc::util::grid::Grid;
+use aoc::util::pathfinding::distance_map;
+use aoc::util::point::Point;
+use aoc::util::shortcut::*;
+
+const EXAMPLE: &str = "\
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+";
+
+const START: Point = Point { x: 1, y: 3 };
+const END: Point = Point { x: 5, y: 7 };
+
+fn track() -> Grid<char> {
+    Grid::parse(EXAMPLE, None).unwrap()
+}
+
+#[test]
+fn distance_map_test() {
+    let distance = distance_map(&track(), START, |&c| c != '#');
+    assert_eq!(distance.get_value(&START), Some(0));
+    assert_eq!(distance.get_value(&END), Some(84));
+    assert_eq!(distance.get_value(&Point::new(0, 0)), Some(-1));
+}
+
+#[test]
+fn savings_test() {
+    let savings = savings(&track(), START, END, 2, |&c| c != '#');
+    assert_eq!(savings.len(), 44);
+    assert_eq!(savings.iter().filter(|&&s| s == 64).count(), 1);
+    assert_eq!(savings.iter().filter(|&&s| s == 2).count(), 14);
+}
+
+#[test]
+fn savings_long_test() {
+    let savings = savings(&track(), START, END, 20, |&c| c != '#');
+    assert_eq!(savings.iter().filter(|&&s| s >= 50).count(), 285);
+    assert_eq!(savings.iter().filter(|&&s| s == 76).count(), 3);
+}
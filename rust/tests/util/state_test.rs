@@ -0,0 +1,91 @@
+use aoc::util::state::*;
+
+const EXAMPLE: &str = "\
+########################
+#f.D.E.e.C.b.A.@.a.B.c.#
+######################.#
+#d.....................#
+########################
+";
+
+const X: usize = 0;
+const Y: usize = 1;
+const KEYS: usize = 2;
+
+fn shortest_path(maze: &str, dense: bool) -> Option<u32> {
+    let grid: Vec<&[u8]> = maze.lines().map(str::as_bytes).collect();
+    // Both mazes fit in 32 columns, 8 rows and use keys `a` to `f` only.
+    let layout = Layout::new(&[5, 3, 6]);
+
+    let mut start = 0;
+    let mut all_keys = 0;
+    for (y, row) in grid.iter().enumerate() {
+        for (x, &c) in row.iter().enumerate() {
+            match c {
+                b'@' => start = layout.encode(&[x as u64, y as u64, 0]),
+                b'a'..=b'z' => all_keys |= 1 << (c - b'a'),
+                _ => {}
+            }
+        }
+    }
+
+    let neighbours = |key: u64, next: &mut Vec<u64>| {
+        let (x, y, keys) = (
+            layout.get(key, X),
+            layout.get(key, Y),
+            layout.get(key, KEYS),
+        );
+        for (nx, ny) in [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)] {
+            let c = grid[ny as usize][nx as usize];
+            let keys = match c {
+                b'#' => continue,
+                b'A'..=b'Z' if keys & 1 << (c - b'A') == 0 => continue,
+                b'a'..=b'z' => keys | 1 << (c - b'a'),
+                _ => keys,
+            };
+            next.push(layout.encode(&[nx, ny, keys]));
+        }
+    };
+    let goal = |key: u64| layout.get(key, KEYS) == all_keys;
+
+    if dense {
+        bfs_dense(start, 1 << layout.bits(), neighbours, goal)
+    } else {
+        bfs(start, neighbours, goal)
+    }
+}
+
+#[test]
+fn layout_test() {
+    let layout = Layout::new(&[5, 3, 26]);
+    let key = layout.encode(&[21, 6, 0b101]);
+    assert_eq!(layout.decode(key), vec![21, 6, 0b101]);
+    assert_eq!(layout.get(layout.set(key, 1, 2), 1), 2);
+    assert_eq!(layout.get(layout.set(key, 1, 2), 0), 21);
+    assert_eq!(layout.bits(), 34);
+}
+
+#[test]
+fn bfs_test() {
+    assert_eq!(
+        shortest_path("#########\n#b.A.@.a#\n#########\n", false),
+        Some(8)
+    );
+    assert_eq!(shortest_path(EXAMPLE, false), Some(86));
+}
+
+#[test]
+fn bfs_dense_test() {
+    assert_eq!(
+        shortest_path("#########\n#b.A.@.a#\n#########\n", true),
+        Some(8)
+    );
+}
+
+#[test]
+fn bfs_unreachable_test() {
+    assert_eq!(
+        bfs(0, |key, next| next.push((key + 1) % 5), |key| key == 7),
+        None
+    );
+}
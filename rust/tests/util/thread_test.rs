@@ -0,0 +1,45 @@
+use aoc::util::thread::*;
+
+#[test]
+fn parallel_map_test() {
+    let items: Vec<u64> = (0..100).collect();
+    for threads in [1, 3, 8, 200] {
+        assert_eq!(
+            parallel_map(&items, threads, |n| n * n),
+            items.iter().map(|n| n * n).collect::<Vec<_>>()
+        );
+    }
+    assert!(parallel_map(&[] as &[u64], 4, |n| *n).is_empty());
+}
+
+#[test]
+fn map_reduce_order_test() {
+    // Subtraction is not associative, so any other order gives a different answer.
+    let items: Vec<i64> = (1..=50).collect();
+    let sequential = items.iter().fold(1000, |total, n| total - n * 3);
+
+    for threads in [1, 2, 7] {
+        assert_eq!(
+            map_reduce(&items, threads, |n| n * 3, 1000, |total, n| total - n),
+            sequential
+        );
+    }
+}
+
+#[test]
+fn parallel_sum_test() {
+    let items: Vec<u32> = (1..=1000).collect();
+    assert_eq!(parallel_sum::<_, u64, _>(&items, 4, |&n| n as u64), 500500);
+}
+
+#[test]
+fn parallel_map_init_test() {
+    let items = vec!["a", "b", "c", "d"];
+    let lengths = parallel_map_init(&items, 2, String::new, |scratch, item| {
+        scratch.clear();
+        scratch.push_str(item);
+        scratch.push('!');
+        scratch.len()
+    });
+    assert_eq!(lengths, vec![2, 2, 2, 2]);
+}
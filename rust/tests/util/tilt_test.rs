@@ -0,0 +1,78 @@
+use aoc::util::blockers::Blockers;
+use aoc::util::direction::Direction;
+use aoc::util::grid::Grid;
+use aoc::util::tilt::*;
+
+const EXAMPLE: &str = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+";
+
+fn platform(text: &str) -> Grid<char> {
+    Grid::parse(text, None).unwrap()
+}
+
+/// The load on the north support beams, the answer of 2023 day 14.
+fn load(grid: &Grid<char>) -> i32 {
+    grid.points()
+        .filter(|point| grid.get_value(point) == Some('O'))
+        .map(|point| grid.height - point.y)
+        .sum()
+}
+
+#[test]
+fn tilt_test() {
+    let mut grid = platform("O.#.\n..O.\n.O.O\n");
+    let blockers = Blockers::from_grid(&grid, |&c| c == '#');
+
+    tilt(&mut grid, &blockers, &Direction::Up, &'O', &'.');
+    assert_eq!(grid, platform("OO#O\n..O.\n....\n"));
+
+    tilt(&mut grid, &blockers, &Direction::Right, &'O', &'.');
+    assert_eq!(grid, platform("OO#O\n...O\n....\n"));
+
+    let mut grid = platform(EXAMPLE);
+    let blockers = Blockers::from_grid(&grid, |&c| c == '#');
+    tilt(&mut grid, &blockers, &Direction::Up, &'O', &'.');
+    assert_eq!(load(&grid), 136);
+}
+
+#[test]
+fn spin_test() {
+    let mut grid = platform(EXAMPLE);
+    let blockers = Blockers::from_grid(&grid, |&c| c == '#');
+
+    spin(&mut grid, &blockers, &'O', &'.');
+    assert_eq!(
+        grid.render(),
+        "\
+.....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....
+"
+    );
+}
+
+#[test]
+fn spin_cycles_test() {
+    let grid = platform(EXAMPLE);
+    let blockers = Blockers::from_grid(&grid, |&c| c == '#');
+
+    let spun = spin_cycles(&grid, &blockers, &'O', &'.', 1_000_000_000);
+    assert_eq!(load(&spun), 64);
+}
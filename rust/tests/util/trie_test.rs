@@ -0,0 +1,45 @@
+use aoc::util::trie::*;
+
+const PATTERNS: [&str; 8] = ["r", "wr", "b", "g", "bwu", "rb", "gb", "br"];
+
+fn towels() -> Trie {
+    Trie::from_patterns(PATTERNS.iter().map(|p| p.as_bytes()))
+}
+
+#[test]
+fn contains_test() {
+    let trie = towels();
+    assert!(trie.contains(b"bwu"));
+    assert!(trie.contains(b"r"));
+    assert!(!trie.contains(b"bw"));
+    assert!(!trie.contains(b""));
+}
+
+#[test]
+fn prefix_test() {
+    let trie = towels();
+    assert_eq!(trie.prefixes(b"brwrr").collect::<Vec<_>>(), vec![1, 2]);
+    assert_eq!(trie.shortest_prefix(b"bwurrg"), Some(1));
+    assert_eq!(trie.longest_prefix(b"bwurrg"), Some(3));
+    assert_eq!(trie.longest_prefix(b"ubwu"), None);
+    assert_eq!(trie.longest_prefix(b""), None);
+}
+
+#[test]
+fn count_compositions_test() {
+    let trie = towels();
+    let designs = [
+        ("brwrr", 2),
+        ("bggr", 1),
+        ("gbbr", 4),
+        ("rrbgbr", 6),
+        ("ubwu", 0),
+        ("bwurrg", 1),
+        ("brgr", 2),
+        ("bbrwb", 0),
+    ];
+    for (design, ways) in designs {
+        assert_eq!(trie.count_compositions(design.as_bytes()), ways, "{design}");
+    }
+    assert_eq!(trie.count_compositions(b""), 1);
+}
@@ -0,0 +1,61 @@
+use aoc::util::direction::Direction;
+use aoc::util::parse::parse_moves;
+use aoc::util::point::Point;
+use aoc::util::turtle::*;
+use std::collections::HashSet;
+
+#[test]
+fn relative_commands_test() {
+    // Following R2, L3 from the origin facing north ends 5 blocks away.
+    let mut turtle = Turtle::new(Point::EMPTY, Direction::Up);
+    for command in [
+        Command::TurnRight,
+        Command::Forward(2),
+        Command::TurnLeft,
+        Command::Forward(3),
+    ] {
+        turtle.execute(command);
+    }
+
+    assert_eq!(turtle.position, Point::new(2, -3));
+    assert_eq!(turtle.heading, Direction::Up);
+    assert_eq!(turtle.distance(), 5);
+    assert_eq!(
+        turtle.segments(),
+        &[
+            (Point::EMPTY, Point::new(2, 0)),
+            (Point::new(2, 0), Point::new(2, -3))
+        ]
+    );
+}
+
+#[test]
+fn wire_crossing_test() {
+    let walk = |wire: &str| {
+        let mut turtle = Turtle::new(Point::EMPTY, Direction::Right);
+        turtle.walk_all(&parse_moves(&wire.replace(',', "\n")));
+        turtle
+    };
+    let first = walk("R8,U5,L5,D3");
+    let second = walk("U7,R6,D4,L4");
+
+    let visited: HashSet<_> = first.path()[1..].iter().collect();
+    let closest = second.path()[1..]
+        .iter()
+        .filter(|point| visited.contains(point))
+        .map(|point| point.x.abs() + point.y.abs())
+        .min();
+    assert_eq!(closest, Some(6));
+}
+
+#[test]
+fn backwards_test() {
+    let mut turtle = Turtle::new(Point::EMPTY, Direction::Right);
+    turtle.forward(-2);
+    turtle.forward(0);
+    assert_eq!(
+        turtle.path(),
+        &[Point::EMPTY, Point::LEFT, Point::new(-2, 0)]
+    );
+    assert_eq!(turtle.segments().len(), 1);
+}
@@ -0,0 +1,124 @@
+use aoc::util::grid::Grid;
+use aoc::util::point::Point;
+use aoc::util::viz::*;
+
+#[test]
+fn downsampler_test() {
+    let mut sampler = Downsampler::new(4);
+    let mut rendered = 0;
+    for step in 0..20 {
+        sampler.offer(|| {
+            rendered += 1;
+            step
+        });
+    }
+
+    assert_eq!(sampler.offered(), 20);
+    assert_eq!(sampler.stride(), 8);
+    assert_eq!(sampler.frames(), [0, 8, 16]);
+    assert!(rendered < 20);
+
+    assert_eq!(sampler.take(), vec![0, 8, 16]);
+    assert!(sampler.offer(|| 42));
+    assert_eq!(sampler.frames(), [42]);
+}
+
+#[test]
+fn downsampler_budget_test() {
+    let mut sampler = Downsampler::new(10);
+    (0..1_000_000).for_each(|step| {
+        sampler.offer(|| step);
+    });
+
+    let frames = sampler.frames();
+    assert!(frames.len() < 10);
+    assert!(frames
+        .windows(2)
+        .all(|pair| pair[1] - pair[0] == sampler.stride()));
+}
+
+#[test]
+fn changed_test() {
+    let before = Grid::<char>::parse("...\n...\n...", None).unwrap();
+    let after = Grid::<char>::parse("...\n.#.\n..#", None).unwrap();
+
+    assert_eq!(Region::changed(&before, &before), None);
+    assert_eq!(
+        Region::changed(&before, &after),
+        Some(Region {
+            min: Point::new(1, 1),
+            max: Point::new(2, 2)
+        })
+    );
+}
+
+#[test]
+fn crop_test() {
+    let region = Region {
+        min: Point::new(1, 1),
+        max: Point::new(2, 1),
+    };
+    assert_eq!(region.crop("abcd\nefgh\nijkl\n"), "fg\n");
+    assert_eq!(
+        region.expand(1).crop("abcd\nefgh\nijkl\n"),
+        "abcd\nefgh\nijkl\n"
+    );
+}
+
+#[test]
+fn focus_test() {
+    let frames = vec![
+        "......\n......\n..#...\n".to_string(),
+        "......\n......\n...#..\n".to_string(),
+        "......\n......\n....#.\n".to_string(),
+    ];
+
+    assert_eq!(
+        focus(&frames, 0),
+        vec![
+            "#..\n".to_string(),
+            ".#.\n".to_string(),
+            "..#\n".to_string()
+        ]
+    );
+    assert_eq!(focus(&frames[..1], 0), frames[..1].to_vec());
+}
+
+#[test]
+fn scale_test() {
+    let grid = Grid::<u32>::parse("159\n555", None).unwrap();
+    let scale = Scale::of(&grid);
+
+    assert_eq!(scale, Scale { min: 1, max: 9 });
+    assert_eq!(scale.fraction(5), 0.5);
+    assert_eq!(scale.fraction(20), 1.0);
+    assert_eq!(Scale { min: 3, max: 3 }.fraction(3), 0.0);
+}
+
+#[test]
+fn gradient_test() {
+    assert_eq!(gradient(0.0), [0, 0, 128]);
+    assert_eq!(gradient(0.5), [0, 200, 0]);
+    assert_eq!(gradient(1.0), [160, 0, 0]);
+    assert_eq!(gradient(2.0), gradient(1.0));
+}
+
+#[test]
+fn heatmap_test() {
+    let grid = Grid::<u32>::parse("19\n91", None).unwrap();
+    let rendered = heatmap(&grid);
+    let lines: Vec<&str> = rendered.lines().collect();
+
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("\x1b[48;2;0;0;128m  \x1b[48;2;160;0;0m  "));
+    assert!(lines[2].starts_with("1 ") && lines[2].ends_with(" 9"));
+}
+
+#[test]
+fn ppm_test() {
+    let grid = Grid::<u32>::parse("02", None).unwrap();
+    let image = ppm(&grid);
+
+    assert!(image.starts_with(b"P6\n2 1\n255\n"));
+    assert_eq!(image[image.len() - 6..], [0, 0, 128, 160, 0, 0]);
+}
@@ -0,0 +1,71 @@
+use aoc::util::geometry::Hyperbox;
+use aoc::util::parse::*;
+use aoc::util::workflow::*;
+
+const EXAMPLE: &str = "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}
+";
+
+#[test]
+fn evaluate_test() {
+    let workflows = Workflows::parse(EXAMPLE, "xmas");
+    let tree = workflows.tree("in");
+    let (_, parts) = EXAMPLE.split_once("\n\n").unwrap();
+
+    let total: u64 = parts
+        .lines()
+        .map(|line| line.iter_unsigned().collect::<Vec<u64>>())
+        .filter(|values| tree.evaluate(values))
+        .map(|values| values.iter().sum::<u64>())
+        .sum();
+    assert_eq!(total, 19114);
+}
+
+#[test]
+fn count_test() {
+    let tree = Workflows::parse(EXAMPLE, "xmas").tree("in");
+    let bounds = vec![1..4001; 4];
+    assert_eq!(tree.count(&bounds), 167409079868000);
+}
+
+#[test]
+fn accepted_boxes_test() {
+    let tree = Workflows::parse(EXAMPLE, "xmas").tree("in");
+    let boxes = tree.accepted_boxes(Hyperbox::inclusive([1; 4], [4000; 4]));
+    let total: i64 = boxes.iter().map(Hyperbox::volume).sum();
+    assert_eq!(total, 167409079868000);
+    assert!(boxes
+        .iter()
+        .all(|b| tree.evaluate(&b.min.map(|v| v as u64))));
+}
+
+#[test]
+fn boundary_test() {
+    let tree = Workflows::parse("in{x>10:A,R}", "xy").tree("in");
+    assert!(!tree.evaluate(&[10, 0]));
+    assert!(tree.evaluate(&[11, 0]));
+    assert_eq!(tree.count(&[1..21, 0..2]), 20);
+    assert_eq!(tree.count(&[15..20, 3..3]), 0);
+}
+
+#[test]
+#[should_panic]
+fn loop_test() {
+    Workflows::parse("a{x<5:b,R}\nb{x<3:a,A}", "x").tree("a");
+}
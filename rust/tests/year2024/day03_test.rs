@@ -1,3 +1,4 @@
+use aoc::util::rng::Rng;
 use aoc::year2024::day03::*;
 
 const EXAMPLE: &str = "\
@@ -15,3 +16,56 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 48);
 }
+
+#[test]
+fn end_of_input_test() {
+    assert_eq!(part1(&parse("mul(2,4)")), 8);
+    assert_eq!(part1(&parse("mul(2,4")), 0);
+    assert_eq!(part1(&parse("xmul(2,")), 0);
+    assert_eq!(part1(&parse("mul(2,4)m")), 8);
+    assert_eq!(part2(&parse("mul(2,4)don't()")), 8);
+    assert_eq!(part2(&parse("don't()mul(2,4)do(")), 0);
+}
+
+#[test]
+fn split_by_toggle_test() {
+    // Joining the enabled text around a disabled section would form `mul(2,4)`
+    let input = parse("mul(2,don't()xdo()4)");
+    assert_eq!(part2(&input), 0);
+}
+
+#[path = "../../benches/day03/baseline.rs"]
+mod regex_baseline;
+
+#[test]
+fn regex_baseline_test() {
+    let mut rng = Rng::new(2024);
+    // The baseline joins the enabled text, so toggles keep a space on either side to not form
+    // instructions across a disabled section
+    let pieces = [
+        "mul(",
+        ",",
+        ")",
+        " do() ",
+        " don't() ",
+        "do(",
+        "don't",
+        "1",
+        "23",
+        "456",
+        "7890",
+        "x",
+        " ",
+    ];
+
+    for _ in 0..100 {
+        let mut memory = String::new();
+        while memory.len() < 500 {
+            memory.push_str(pieces[rng.range(0..=pieces.len() as u64 - 1) as usize]);
+        }
+
+        let input = parse(&memory);
+        assert_eq!(part1(&input), regex_baseline::part1(&memory), "{memory}");
+        assert_eq!(part2(&input), regex_baseline::part2(&memory), "{memory}");
+    }
+}
@@ -1,4 +1,9 @@
+use aoc::util::debugger;
+use aoc::util::direction::Direction;
+use aoc::util::point::Point;
 use aoc::year2024::day06::*;
+use std::collections::HashSet;
+use std::sync::{Mutex, MutexGuard, PoisonError};
 
 const EXAMPLE: &str = "\
 ....#.....
@@ -13,14 +18,66 @@ const EXAMPLE: &str = "\
 ......#...
 ";
 
+/// Serializes the day06 tests, as recording frames is global and would otherwise pick up the
+/// walks of the other tests running at the same time.
+fn lock() -> MutexGuard<'static, ()> {
+    static DEBUGGER: Mutex<()> = Mutex::new(());
+    DEBUGGER.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
 #[test]
 fn part1_test() {
+    let _lock = lock();
     let input = parse(EXAMPLE);
-    assert_eq!(part1(&input), 42);
+    assert_eq!(part1(&input), 41);
 }
 
 #[test]
 fn part2_test() {
+    let _lock = lock();
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 6);
 }
+
+#[test]
+fn solve_both_test() {
+    let _lock = lock();
+    let input = parse(EXAMPLE);
+    assert_eq!(solve_both(&input), (41, 6));
+}
+
+#[test]
+fn path_test() {
+    let _lock = lock();
+    let input = parse(EXAMPLE);
+    let path = path(&input.grid);
+
+    assert_eq!(path[0], (Point::new(4, 6), Direction::Up));
+    assert_eq!(path[5], (Point::new(4, 1), Direction::Up));
+    assert_eq!(path[6], (Point::new(4, 1), Direction::Right));
+    assert_eq!(path.last(), Some(&(Point::new(7, 9), Direction::Down)));
+
+    let distinct: HashSet<Point> = path.iter().map(|&(point, _)| point).collect();
+    assert_eq!(distinct.len(), 41);
+    assert!(path
+        .windows(2)
+        .all(|pair| pair[0].0 == pair[1].0 || pair[0].0.add(&pair[0].1.to_point()) == pair[1].0));
+}
+
+#[test]
+fn record_test() {
+    let _lock = lock();
+    let input = parse(EXAMPLE);
+    debugger::take_frames();
+    debugger::record(true);
+    part1(&input);
+    debugger::record(false);
+
+    let frames = debugger::take_frames();
+    let path = path(&input.grid);
+    let (start, facing) = path[0];
+    assert!(frames.len() >= path.len());
+    assert!(frames
+        .iter()
+        .any(|frame| frame.label == format!("{start:?} facing {facing:?}")));
+}
@@ -26,3 +26,10 @@ fn part2_test() {
     let input = parse(EXAMPLE);
     assert_eq!(part2(&input), 34);
 }
+
+#[test]
+fn part2_harmonics_test() {
+    // Antennas two cells apart resonate at the cell between them as well.
+    let input = parse("a.a..\n.....\n");
+    assert_eq!(part2(&input), 5);
+}
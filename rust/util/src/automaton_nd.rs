@@ -0,0 +1,117 @@
+//! Sparse cellular automata in any number of dimensions.
+//!
+//! Cells are `[i32; D]` coordinates and only active cells are stored, so the space is unbounded
+//! and grows as needed. The dimension is a const generic parameter, which lets the same code run
+//! 3D and 4D versions of Conway's Game of Life without hand written neighbour loops.
+//!
+//! Each step counts the active neighbours of every cell adjacent to an active cell, then applies
+//! the rule to decide which cells are active in the next generation. Cells without any active
+//! neighbour are never considered, so rules must keep them inactive, as Conway style rules do.
+use std::collections::{HashMap, HashSet};
+
+/// Returns the offsets to all `3^D - 1` neighbours, including diagonals in every dimension.
+pub fn offsets<const D: usize>() -> Vec<[i32; D]> {
+    let mut result = vec![[0; D]];
+
+    for axis in 0..D {
+        result = result
+            .into_iter()
+            .flat_map(|offset| {
+                [-1, 0, 1].map(|delta| {
+                    let mut next = offset;
+                    next[axis] = delta;
+                    next
+                })
+            })
+            .collect();
+    }
+
+    result.retain(|offset| offset.iter().any(|&delta| delta != 0));
+    result
+}
+
+/// Returns every neighbour of the cell.
+pub fn neighbours<const D: usize>(cell: &[i32; D]) -> impl Iterator<Item = [i32; D]> + '_ {
+    offsets::<D>().into_iter().map(move |offset| {
+        let mut next = *cell;
+        next.iter_mut().zip(offset).for_each(|(c, d)| *c += d);
+        next
+    })
+}
+
+/// The classic Conway rule used by most puzzles: an active cell stays active with 2 or 3 active
+/// neighbours, an inactive cell becomes active with exactly 3.
+pub fn conway(active: bool, neighbours: usize) -> bool {
+    matches!((active, neighbours), (true, 2 | 3) | (false, 3))
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Automaton<const D: usize> {
+    pub active: HashSet<[i32; D]>,
+    offsets: Vec<[i32; D]>,
+}
+
+impl<const D: usize> Automaton<D> {
+    pub fn new(active: HashSet<[i32; D]>) -> Self {
+        Self {
+            active,
+            offsets: offsets::<D>(),
+        }
+    }
+
+    /// Parses a 2D slice where `on` marks active cells. Remaining coordinates are zero.
+    ///
+    /// # Panics
+    /// Panics if `D` is less than two.
+    pub fn parse(input: &str, on: char) -> Self {
+        assert!(D >= 2, "A 2D slice needs at least two dimensions");
+        let mut active = HashSet::new();
+
+        for (y, line) in input.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if c == on {
+                    let mut cell = [0; D];
+                    cell[0] = x as i32;
+                    cell[1] = y as i32;
+                    active.insert(cell);
+                }
+            }
+        }
+
+        Self::new(active)
+    }
+
+    /// Advances one generation.
+    ///
+    /// # Arguments
+    /// * `rule` - Decides the next state from the current state and the number of active
+    ///   neighbours.
+    pub fn step<F>(&mut self, rule: F)
+    where
+        F: Fn(bool, usize) -> bool,
+    {
+        let mut counts: HashMap<[i32; D], usize> = HashMap::with_capacity(self.active.len() * 4);
+
+        for cell in &self.active {
+            for offset in &self.offsets {
+                let mut next = *cell;
+                next.iter_mut().zip(offset).for_each(|(c, d)| *c += d);
+                *counts.entry(next).or_insert(0) += 1;
+            }
+        }
+
+        self.active = counts
+            .into_iter()
+            .filter(|(cell, count)| rule(self.active.contains(cell), *count))
+            .map(|(cell, _)| cell)
+            .collect();
+    }
+
+    pub fn len(&self) -> usize {
+        self.active.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+}
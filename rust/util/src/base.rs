@@ -0,0 +1,162 @@
+//! Conversions between integers and positional numerals in unusual bases.
+//!
+//! Besides plain radix notation this covers two systems that show up in puzzles:
+//!
+//! * **Balanced** bases have an odd number of digits centred on zero, such as balanced ternary
+//!   `-0+` or the balanced quinary "SNAFU" numbers `=-012`. Negative numbers need no sign.
+//! * **Bijective** bases have no zero digit, such as spreadsheet columns `A..Z, AA..`. Every
+//!   positive number has exactly one representation and zero is the empty string.
+//!
+//! Digit symbols are given as ASCII strings, lowest value first.
+
+const DIGITS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// Formats a number in a radix between 2 and 36, using lowercase letters above nine.
+///
+/// # Panics
+/// Panics if the radix is out of range.
+pub fn to_radix(mut value: u64, radix: u32) -> String {
+    assert!((2..=36).contains(&radix), "Unsupported radix {radix}");
+    let radix = radix as u64;
+    let mut result = Vec::new();
+
+    loop {
+        result.push(DIGITS[(value % radix) as usize]);
+        value /= radix;
+        if value == 0 {
+            break;
+        }
+    }
+
+    result.reverse();
+    String::from_utf8(result).unwrap()
+}
+
+/// Parses a number in a radix between 2 and 36, accepting either letter case.
+///
+/// # Returns
+/// * The value, or `None` if the text is empty, has invalid digits or overflows.
+pub fn from_radix(text: &str, radix: u32) -> Option<u64> {
+    u64::from_str_radix(text, radix).ok()
+}
+
+/// A balanced base, where digit values range from `-(n / 2)` to `n / 2` for `n` symbols.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Balanced {
+    symbols: &'static [u8],
+}
+
+/// Balanced ternary with digits `-1`, `0` and `1`.
+pub const BALANCED_TERNARY: Balanced = Balanced::new("-0+");
+
+/// Balanced quinary as written by SNAFU fuel counters, with `=` for `-2` and `-` for `-1`.
+pub const SNAFU: Balanced = Balanced::new("=-012");
+
+impl Balanced {
+    /// # Panics
+    /// Panics if the number of symbols is not odd and at least three.
+    pub const fn new(symbols: &'static str) -> Self {
+        let symbols = symbols.as_bytes();
+        assert!(
+            symbols.len() >= 3 && symbols.len() % 2 == 1,
+            "Balanced base needs an odd number of digits"
+        );
+        Self { symbols }
+    }
+
+    fn radix(&self) -> i64 {
+        self.symbols.len() as i64
+    }
+
+    fn half(&self) -> i64 {
+        self.radix() / 2
+    }
+
+    /// Formats a number, negative ones included, in this base.
+    pub fn encode(&self, mut value: i64) -> String {
+        let mut result = Vec::new();
+
+        loop {
+            let mut digit = value.rem_euclid(self.radix());
+            if digit > self.half() {
+                digit -= self.radix();
+            }
+            result.push(self.symbols[(digit + self.half()) as usize]);
+            value = (value - digit) / self.radix();
+            if value == 0 {
+                break;
+            }
+        }
+
+        result.reverse();
+        String::from_utf8(result).unwrap()
+    }
+
+    /// Parses a number written in this base.
+    ///
+    /// # Returns
+    /// * The value, or `None` if the text is empty, has unknown symbols or overflows.
+    pub fn decode(&self, text: &str) -> Option<i64> {
+        if text.is_empty() {
+            return None;
+        }
+
+        text.bytes().try_fold(0i64, |value, symbol| {
+            let index = self.symbols.iter().position(|&s| s == symbol)?;
+            value
+                .checked_mul(self.radix())?
+                .checked_add(index as i64 - self.half())
+        })
+    }
+}
+
+/// A bijective base, where the symbols stand for the digits `1..=n`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Bijective {
+    symbols: &'static [u8],
+}
+
+/// Spreadsheet column names: `A` is 1, `Z` is 26 and `AA` is 27.
+pub const COLUMNS: Bijective = Bijective::new("ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+
+impl Bijective {
+    /// # Panics
+    /// Panics if there are no symbols.
+    pub const fn new(symbols: &'static str) -> Self {
+        assert!(
+            !symbols.is_empty(),
+            "Bijective base needs at least one digit"
+        );
+        Self {
+            symbols: symbols.as_bytes(),
+        }
+    }
+
+    /// Formats a number in this base. Zero is the empty string.
+    pub fn encode(&self, mut value: u64) -> String {
+        let radix = self.symbols.len() as u64;
+        let mut result = Vec::new();
+
+        while value > 0 {
+            value -= 1;
+            result.push(self.symbols[(value % radix) as usize]);
+            value /= radix;
+        }
+
+        result.reverse();
+        String::from_utf8(result).unwrap()
+    }
+
+    /// Parses a number written in this base.
+    ///
+    /// # Returns
+    /// * The value, or `None` if the text has unknown symbols or overflows.
+    pub fn decode(&self, text: &str) -> Option<u64> {
+        let radix = self.symbols.len() as u64;
+
+        text.bytes().try_fold(0u64, |value, symbol| {
+            let index = self.symbols.iter().position(|&s| s == symbol)?;
+            value.checked_mul(radix)?.checked_add(index as u64 + 1)
+        })
+    }
+}
@@ -0,0 +1,97 @@
+//! Bingo boards: parsing, marking drawn numbers and detecting wins.
+//!
+//! Boards are square blocks of numbers separated by blank lines, preceded by a line of comma
+//! separated draws. A board wins once every number in any row or column has been drawn.
+//! Diagonals do not count. Boards are generic over their size and stored in a [`FixedGrid`].
+use crate::fixed_grid::FixedGrid;
+use crate::parse::*;
+use crate::point::Point;
+
+/// A square `N` by `N` board with the numbers drawn so far.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Board<const N: usize> {
+    pub numbers: FixedGrid<u32, N, N>,
+    pub marked: FixedGrid<bool, N, N>,
+}
+
+impl<const N: usize> Board<N> {
+    /// Reads the first `N * N` numbers of the text in reading order.
+    ///
+    /// # Returns
+    /// * `None` if the text contains fewer than `N * N` numbers.
+    pub fn parse(block: &str) -> Option<Self> {
+        let values: Vec<u32> = block.iter_unsigned().take(N * N).collect();
+        if values.len() < N * N {
+            return None;
+        }
+
+        Some(Self {
+            numbers: FixedGrid::from_fn(|p| values[p.y as usize * N + p.x as usize]),
+            marked: FixedGrid::new(false),
+        })
+    }
+
+    /// Marks every cell containing `value`.
+    ///
+    /// # Returns
+    /// * `true` if the board has a complete row or column after marking.
+    pub fn mark(&mut self, value: u32) -> bool {
+        for (point, number) in self.numbers.iter() {
+            if number == value {
+                self.marked.set_value(&point, true);
+            }
+        }
+        self.has_won()
+    }
+
+    /// Checks if any row or column is completely marked.
+    pub fn has_won(&self) -> bool {
+        let marked = |x: usize, y: usize| self.marked.data[y][x];
+        (0..N).any(|i| (0..N).all(|j| marked(j, i)) || (0..N).all(|j| marked(i, j)))
+    }
+
+    /// Sums the numbers that have not been drawn yet.
+    pub fn unmarked_sum(&self) -> u32 {
+        self.numbers
+            .iter()
+            .filter(|(point, _)| !self.is_marked(point))
+            .map(|(_, number)| number)
+            .sum()
+    }
+
+    pub fn is_marked(&self, point: &Point) -> bool {
+        self.marked.get_value(point).unwrap_or(false)
+    }
+}
+
+/// Parses the draws on the first line followed by boards separated by blank lines.
+pub fn parse_game<const N: usize>(input: &str) -> (Vec<u32>, Vec<Board<N>>) {
+    let mut blocks = input.split("\n\n");
+    let draws = blocks.next().unwrap_or_default().iter_unsigned().collect();
+    let boards = blocks.filter_map(Board::parse).collect();
+    (draws, boards)
+}
+
+/// Plays every draw until all boards have won.
+///
+/// # Returns
+/// * `(board index, score)` for each board in the order they won, where the score is the sum
+///   of unmarked numbers times the winning draw.
+pub fn play<const N: usize>(draws: &[u32], boards: &mut [Board<N>]) -> Vec<(usize, u32)> {
+    let mut winners = Vec::new();
+    let mut won = vec![false; boards.len()];
+
+    for &draw in draws {
+        for (index, board) in boards.iter_mut().enumerate() {
+            if !won[index] && board.mark(draw) {
+                won[index] = true;
+                winners.push((index, board.unmarked_sum() * draw));
+            }
+        }
+        if winners.len() == boards.len() {
+            break;
+        }
+    }
+
+    winners
+}
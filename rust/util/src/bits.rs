@@ -0,0 +1,96 @@
+//! Column-wise bit frequency analysis, e.g. for diagnostic reports of binary numbers.
+//!
+//! Values are stored as `u32` together with the width of the original binary strings. Bits are
+//! numbered from the least significant, so the leftmost column of a `width` wide string is bit
+//! `width - 1`.
+//!
+//! When zeros and ones are equally common, the caller decides the winner with the `tie`
+//! argument, as different puzzles (and different parts of the same puzzle) use different rules.
+
+/// Which bit wins a vote in each column.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Criteria {
+    /// The most common bit, or `tie` if both are equally common.
+    MostCommon { tie: u32 },
+    /// The least common bit, or `tie` if both are equally common.
+    LeastCommon { tie: u32 },
+}
+
+impl Criteria {
+    /// Picks the winning bit, `0` or `1`, given how often each occurs.
+    pub fn select(&self, zeros: usize, ones: usize) -> u32 {
+        match *self {
+            _ if zeros == ones => self.tie(),
+            Criteria::MostCommon { .. } => (ones > zeros) as u32,
+            Criteria::LeastCommon { .. } => (ones < zeros) as u32,
+        }
+    }
+
+    fn tie(&self) -> u32 {
+        match *self {
+            Criteria::MostCommon { tie } | Criteria::LeastCommon { tie } => tie,
+        }
+    }
+}
+
+/// Parses lines of `0` and `1` characters.
+///
+/// # Returns
+/// * The parsed values and the width of the longest line.
+///
+/// # Panics
+/// Panics if a line contains other characters or is wider than 32 bits.
+pub fn parse_binary(input: &str) -> (Vec<u32>, usize) {
+    let mut width = 0;
+    let values = input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            width = width.max(line.len());
+            u32::from_str_radix(line, 2).unwrap_or_else(|_| panic!("Invalid binary \"{line}\""))
+        })
+        .collect();
+
+    (values, width)
+}
+
+/// Counts how many values have the given bit set.
+pub fn count_ones(values: &[u32], bit: usize) -> usize {
+    values
+        .iter()
+        .filter(|&&value| value >> bit & 1 == 1)
+        .count()
+}
+
+/// Builds a number from the winning bit of every column.
+///
+/// With `MostCommon` this is the "gamma rate" and with `LeastCommon` the "epsilon rate".
+pub fn vote(values: &[u32], width: usize, criteria: Criteria) -> u32 {
+    (0..width).fold(0, |result, bit| {
+        let ones = count_ones(values, bit);
+        result | criteria.select(values.len() - ones, ones) << bit
+    })
+}
+
+/// Repeatedly keeps only values matching the winning bit, starting from the leftmost column,
+/// until a single value remains.
+///
+/// # Returns
+/// * The remaining value, or `None` if the list is empty or several identical values remain.
+pub fn filter_by_criteria(values: &[u32], width: usize, criteria: Criteria) -> Option<u32> {
+    let mut remaining = values.to_vec();
+
+    for bit in (0..width).rev() {
+        if remaining.len() <= 1 {
+            break;
+        }
+        let ones = count_ones(&remaining, bit);
+        let keep = criteria.select(remaining.len() - ones, ones);
+        remaining.retain(|&value| value >> bit & 1 == keep);
+    }
+
+    match remaining.as_slice() {
+        [value] => Some(*value),
+        _ => None,
+    }
+}
@@ -0,0 +1,174 @@
+//! Sorted indexes of the obstacles in a grid, for jumping straight to where a walk stops.
+//!
+//! Guards, lasers and rolling rocks move in a straight line until something blocks them. Stepping
+//! cell by cell costs one grid lookup per cell walked, so [`Blockers`] instead keeps the obstacles
+//! of every row and every column in sorted order. Finding the first obstacle in a direction is
+//! then a binary search of a single row or column:
+//!
+//! ```none
+//! let blockers = Blockers::from_grid(&grid, |&c| c == '#');
+//! match blockers.next_in_direction(&guard, &Direction::Up) {
+//!     Some(obstacle) => turn in front of the obstacle
+//!     None => the guard walks off the grid
+//! }
+//! ```
+//!
+//! Obstacles can be added and removed afterwards, which lets a puzzle try out an extra obstacle
+//! without rebuilding the indexes. Each change shifts the rest of its row and column.
+use crate::conversions::FromChar;
+use crate::direction::Direction;
+use crate::grid::Grid;
+use crate::point::Point;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+/// The obstacles of a grid, indexed by row and by column.
+///
+/// # Fields
+/// - `width`: The number of columns of the grid.
+/// - `height`: The number of rows of the grid.
+/// - `rows`: The x coordinates of the obstacles in each row, in increasing order.
+/// - `columns`: The y coordinates of the obstacles in each column, in increasing order.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Blockers {
+    pub width: i32,
+    pub height: i32,
+    rows: Vec<Vec<i32>>,
+    columns: Vec<Vec<i32>>,
+}
+
+impl Blockers {
+    /// Creates the indexes of a `width` by `height` area without any obstacles.
+    pub fn new(width: i32, height: i32) -> Self {
+        Blockers {
+            width,
+            height,
+            rows: vec![Vec::new(); height.max(0) as usize],
+            columns: vec![Vec::new(); width.max(0) as usize],
+        }
+    }
+
+    /// Indexes every cell of the grid whose value blocks movement.
+    pub fn from_grid<T, F>(grid: &Grid<T>, blocks: F) -> Self
+    where
+        T: Default + Clone + Debug + PartialEq,
+        T: FromStr + FromChar,
+        <T as FromStr>::Err: Debug,
+        <T as FromChar>::Err: Debug,
+        F: Fn(&T) -> bool,
+    {
+        let mut blockers = Blockers::new(grid.width, grid.height);
+        // Points come in reading order, so every row and column is filled already sorted
+        for point in grid.points() {
+            if grid.get_value(&point).is_some_and(|value| blocks(&value)) {
+                blockers.rows[point.y as usize].push(point.x);
+                blockers.columns[point.x as usize].push(point.y);
+            }
+        }
+        blockers
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        self.row(point.y)
+            .is_some_and(|row| row.binary_search(&point.x).is_ok())
+    }
+
+    /// Adds an obstacle, returning false if there already was one.
+    ///
+    /// # Panics
+    /// Panics if the point is outside the grid.
+    pub fn insert(&mut self, point: &Point) -> bool {
+        let row = &mut self.rows[point.y as usize];
+        let Err(index) = row.binary_search(&point.x) else {
+            return false;
+        };
+        row.insert(index, point.x);
+
+        let column = &mut self.columns[point.x as usize];
+        let index = column.partition_point(|&y| y < point.y);
+        column.insert(index, point.y);
+        true
+    }
+
+    /// Removes an obstacle, returning false if there was none.
+    pub fn remove(&mut self, point: &Point) -> bool {
+        let Some(row) = self.rows.get_mut(point.y as usize) else {
+            return false;
+        };
+        let Ok(index) = row.binary_search(&point.x) else {
+            return false;
+        };
+        row.remove(index);
+
+        let column = &mut self.columns[point.x as usize];
+        let index = column.partition_point(|&y| y < point.y);
+        column.remove(index);
+        true
+    }
+
+    /// Finds the first obstacle met when walking from a point in an orthogonal direction.
+    ///
+    /// # Arguments
+    /// * `point` - Where the walk starts. An obstacle on this point is ignored.
+    /// * `direction` - One of the four orthogonal directions.
+    ///
+    /// # Returns
+    /// * The nearest obstacle in that direction, or `None` if the walk leaves the grid first.
+    ///
+    /// # Panics
+    /// Panics if the direction is diagonal or `Stop`.
+    pub fn next_in_direction(&self, point: &Point, direction: &Direction) -> Option<Point> {
+        match direction {
+            Direction::Right => {
+                let row = self.row(point.y)?;
+                let x = row.get(row.partition_point(|&x| x <= point.x))?;
+                Some(Point::new(*x, point.y))
+            }
+            Direction::Left => {
+                let row = self.row(point.y)?;
+                let index = row.partition_point(|&x| x < point.x).checked_sub(1)?;
+                Some(Point::new(row[index], point.y))
+            }
+            Direction::Down => {
+                let column = self.column(point.x)?;
+                let y = column.get(column.partition_point(|&y| y <= point.y))?;
+                Some(Point::new(point.x, *y))
+            }
+            Direction::Up => {
+                let column = self.column(point.x)?;
+                let index = column.partition_point(|&y| y < point.y).checked_sub(1)?;
+                Some(Point::new(point.x, column[index]))
+            }
+            _ => panic!("Blockers only answer orthogonal directions, not {direction:?}"),
+        }
+    }
+
+    /// Finds where a walk from a point in an orthogonal direction comes to rest.
+    ///
+    /// # Returns
+    /// * The cell in front of the first obstacle, or the last cell inside the grid if there is no
+    ///   obstacle. This is the starting point itself when the walk can not move at all.
+    ///
+    /// # Panics
+    /// Panics if the direction is diagonal or `Stop`.
+    pub fn slide(&self, point: &Point, direction: &Direction) -> Point {
+        let step = direction.to_point();
+        match self.next_in_direction(point, direction) {
+            Some(obstacle) => obstacle.sub(&step),
+            None => match direction {
+                Direction::Right => Point::new(self.width - 1, point.y),
+                Direction::Left => Point::new(0, point.y),
+                Direction::Down => Point::new(point.x, self.height - 1),
+                _ => Point::new(point.x, 0),
+            },
+        }
+    }
+
+    fn row(&self, y: i32) -> Option<&Vec<i32>> {
+        usize::try_from(y).ok().and_then(|y| self.rows.get(y))
+    }
+
+    fn column(&self, x: i32) -> Option<&Vec<i32>> {
+        usize::try_from(x).ok().and_then(|x| self.columns.get(x))
+    }
+}
@@ -0,0 +1,92 @@
+//! Settles falling 3D bricks and analyses which bricks hold up which.
+//!
+//! Bricks are processed from the lowest to the highest, tracking the top of the stack for every
+//! `(x, y)` column. Each brick drops until it rests one above the highest column under its
+//! footprint, and every brick reaching that height supports it. The result is a [`Dag`] with an
+//! edge from each supporting brick to the bricks resting on it, so the graph algorithms can be
+//! reused to answer questions about removing bricks.
+use crate::geometry::Cuboid;
+use crate::graph::Dag;
+use crate::hash::*;
+use crate::point3::Point3;
+use std::collections::VecDeque;
+
+/// Drops every brick until it lands on the ground at `z = 1` or on another brick.
+///
+/// # Arguments
+/// * `bricks` - Bricks in any order, updated in place with their settled positions.
+///
+/// # Returns
+/// * A graph with an edge from `a` to `b` if brick `a` directly supports brick `b`, using
+///   indices into `bricks`.
+pub fn settle(bricks: &mut [Cuboid]) -> Dag {
+    let mut order: Vec<usize> = (0..bricks.len()).collect();
+    order.sort_by_key(|&i| bricks[i].min.z);
+
+    let mut tops: FastMap<(i32, i32), (i32, usize)> = FastMap::new();
+    let mut graph = Dag::new(bricks.len());
+
+    for index in order {
+        let brick = bricks[index];
+        let footprint = || {
+            (brick.min.x..=brick.max.x)
+                .flat_map(move |x| (brick.min.y..=brick.max.y).map(move |y| (x, y)))
+        };
+
+        let floor = footprint()
+            .filter_map(|column| tops.get(&column))
+            .map(|&(z, _)| z)
+            .max()
+            .unwrap_or(0);
+        let mut supporters: Vec<usize> = footprint()
+            .filter_map(|column| tops.get(&column))
+            .filter(|&&(z, _)| z == floor)
+            .map(|&(_, below)| below)
+            .collect();
+        supporters.sort_unstable();
+        supporters.dedup();
+        for below in supporters {
+            graph.add_edge(below, index, 1);
+        }
+
+        let settled = brick.translate(&Point3::new(0, 0, floor + 1 - brick.min.z));
+        for column in footprint() {
+            tops.insert(column, (settled.max.z, index));
+        }
+        bricks[index] = settled;
+    }
+
+    graph
+}
+
+/// Returns the bricks that can be removed without any other brick falling, in index order.
+pub fn removable(graph: &Dag) -> Vec<usize> {
+    let supporters = graph.in_degrees();
+    (0..graph.len())
+        .filter(|&brick| {
+            graph
+                .neighbours(brick)
+                .iter()
+                .all(|&(above, _)| supporters[above] > 1)
+        })
+        .collect()
+}
+
+/// Counts how many other bricks fall in a chain reaction when `brick` is removed.
+pub fn chain_reaction(graph: &Dag, brick: usize) -> usize {
+    let mut remaining = graph.in_degrees();
+    let mut queue = VecDeque::from([brick]);
+    let mut fallen = 0;
+
+    while let Some(current) = queue.pop_front() {
+        for &(above, _) in graph.neighbours(current) {
+            remaining[above] -= 1;
+            if remaining[above] == 0 {
+                fallen += 1;
+                queue.push_back(above);
+            }
+        }
+    }
+
+    fallen
+}
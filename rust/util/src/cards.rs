@@ -0,0 +1,128 @@
+//! Playing card hands with configurable rank order and joker rules.
+//!
+//! Hands are classified purely by the shape of the multiset of their cards, e.g. `[3, 2]` is a
+//! full house regardless of which cards form it. Ties between hands of the same type are
+//! broken card by card in the order they were dealt, which is what Camel Cards style puzzles
+//! use (rather than poker's sorting by group size).
+//!
+//! A joker is a wildcard that joins the largest group of other cards, which always produces
+//! the strongest possible type. Its own strength for tie breaking comes from its position in
+//! the rank order, so joker rules usually move it to the front.
+
+/// Hand types, ordered from weakest to strongest.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl HandType {
+    /// Classifies a hand from the sizes of its groups of equal cards, largest first.
+    pub fn from_shape(shape: &[usize]) -> Self {
+        let first = shape.first().copied().unwrap_or(0);
+        let second = shape.get(1).copied().unwrap_or(0);
+
+        match (first, second) {
+            (5.., _) => HandType::FiveOfAKind,
+            (4, _) => HandType::FourOfAKind,
+            (3, 2..) => HandType::FullHouse,
+            (3, _) => HandType::ThreeOfAKind,
+            (2, 2..) => HandType::TwoPair,
+            (2, _) => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+}
+
+/// A classified hand. Ordering compares the type first, then card ranks in dealt order.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Hand {
+    pub kind: HandType,
+    pub ranks: Vec<u8>,
+}
+
+/// Rank order of card labels, optionally with a joker acting as a wildcard.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Ranking {
+    ranks: [Option<u8>; 128],
+    joker: Option<char>,
+}
+
+impl Ranking {
+    /// Standard order from weakest to strongest, with `T` for ten.
+    pub const STANDARD: &'static str = "23456789TJQKA";
+    /// Standard order where `J` is a joker and therefore the weakest card.
+    pub const JOKER: &'static str = "J23456789TQKA";
+
+    /// Creates a ranking from card labels listed from weakest to strongest.
+    ///
+    /// # Panics
+    /// Panics if a label is not ASCII.
+    pub fn new(order: &str) -> Self {
+        let mut ranks = [None; 128];
+        for (rank, label) in order.chars().enumerate() {
+            assert!(label.is_ascii(), "Card labels must be ASCII, got {label:?}");
+            ranks[label as usize] = Some(rank as u8);
+        }
+        Self { ranks, joker: None }
+    }
+
+    /// Creates a ranking where `joker` is a wildcard. It must also appear in `order`.
+    pub fn with_joker(order: &str, joker: char) -> Self {
+        Self {
+            joker: Some(joker),
+            ..Self::new(order)
+        }
+    }
+
+    /// Returns the rank of a card label, `0` being the weakest.
+    ///
+    /// # Panics
+    /// Panics if the label is not part of the ranking.
+    pub fn rank(&self, card: char) -> u8 {
+        self.ranks
+            .get(card as usize)
+            .copied()
+            .flatten()
+            .unwrap_or_else(|| panic!("Unknown card {card:?}"))
+    }
+
+    /// Returns the sizes of the groups of equal cards, largest first, with jokers added to
+    /// the largest group.
+    pub fn shape(&self, cards: &str) -> Vec<usize> {
+        let mut counts = [0; 128];
+        let mut jokers = 0;
+
+        for card in cards.chars() {
+            if Some(card) == self.joker {
+                jokers += 1;
+            } else {
+                counts[self.rank(card) as usize] += 1;
+            }
+        }
+
+        let mut shape: Vec<usize> = counts.into_iter().filter(|&c| c > 0).collect();
+        shape.sort_unstable_by(|a, b| b.cmp(a));
+
+        match shape.first_mut() {
+            Some(largest) => *largest += jokers,
+            None if jokers > 0 => shape.push(jokers),
+            None => {}
+        }
+
+        shape
+    }
+
+    /// Parses and classifies a hand such as `"KTJJT"`.
+    pub fn hand(&self, cards: &str) -> Hand {
+        Hand {
+            kind: HandType::from_shape(&self.shape(cards)),
+            ranks: cards.chars().map(|card| self.rank(card)).collect(),
+        }
+    }
+}
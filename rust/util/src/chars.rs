@@ -0,0 +1,76 @@
+//! Letter arithmetic for puzzles that shift, score or count ASCII letters.
+//!
+//! Letters map to `0..26` regardless of case. Everything else is left alone, so the helpers can
+//! be applied to whole lines without filtering punctuation first.
+
+/// The position of a letter in the alphabet, ignoring case.
+///
+/// # Returns
+/// * `Some(0..26)` for ASCII letters, `None` otherwise.
+pub fn index(c: char) -> Option<u32> {
+    c.is_ascii_alphabetic()
+        .then(|| (c.to_ascii_lowercase() as u8 - b'a') as u32)
+}
+
+/// The lowercase letter at a position in the alphabet.
+///
+/// # Panics
+/// Panics if `index` is not in `0..26`.
+pub fn from_index(index: u32) -> char {
+    assert!(index < 26, "Letter index {index} out of range");
+    (b'a' + index as u8) as char
+}
+
+/// Shifts a letter through the alphabet, wrapping around and keeping its case.
+///
+/// Negative shifts rotate backwards. Characters other than ASCII letters are returned unchanged.
+pub fn rotate(c: char, shift: i64) -> char {
+    match index(c) {
+        Some(index) => {
+            let rotated = from_index((index as i64 + shift).rem_euclid(26) as u32);
+            if c.is_ascii_uppercase() {
+                rotated.to_ascii_uppercase()
+            } else {
+                rotated
+            }
+        }
+        None => c,
+    }
+}
+
+/// Applies [`rotate`] to every character of the text, like a Caesar cipher.
+pub fn rotate_str(text: &str, shift: i64) -> String {
+    text.chars().map(|c| rotate(c, shift)).collect()
+}
+
+/// The rucksack priority of a letter: `a..=z` score `1..=26` and `A..=Z` score `27..=52`.
+///
+/// # Returns
+/// * The priority, or `None` for anything but ASCII letters.
+pub fn priority(c: char) -> Option<u32> {
+    let index = index(c)?;
+    Some(if c.is_ascii_lowercase() {
+        index + 1
+    } else {
+        index + 27
+    })
+}
+
+/// The set of priorities of the letters in the text, as bits of a mask.
+///
+/// Bit `n` is set when a letter with priority `n` occurs, so the items common to several
+/// texts are the `&` of their masks and the priority of a single one is `trailing_zeros`.
+pub fn priority_mask(text: &str) -> u64 {
+    text.chars()
+        .filter_map(priority)
+        .fold(0, |mask, priority| mask | (1 << priority))
+}
+
+/// Counts the occurrences of each letter, ignoring case and other characters.
+pub fn frequencies(text: &str) -> [usize; 26] {
+    let mut counts = [0; 26];
+    text.chars()
+        .filter_map(index)
+        .for_each(|index| counts[index as usize] += 1);
+    counts
+}
@@ -0,0 +1,214 @@
+//! Boolean logic circuits built from wire definitions.
+//!
+//! Each line defines exactly one wire, either from a signal or a gate over other wires:
+//!
+//! ```none
+//!   123 -> x
+//!   x AND y -> d
+//!   NOT x -> h
+//!   x LSHIFT 2 -> f
+//!   x00: 1
+//! ```
+//!
+//! The last form sets an initial value, as used by logic gate puzzles. Wires are evaluated
+//! lazily on request and memoized, so only the part of the circuit feeding the requested wire
+//! is computed. Evaluation uses an explicit stack, so long chains of wires cannot overflow the
+//! call stack. Signals are masked to the circuit width, 16 bits unless configured otherwise.
+use std::collections::{HashMap, HashSet};
+
+/// Input of a gate, either another wire or a constant signal.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Operand {
+    Wire(String),
+    Value(u64),
+}
+
+impl Operand {
+    fn parse(token: &str) -> Self {
+        match token.parse() {
+            Ok(value) => Operand::Value(value),
+            Err(_) => Operand::Wire(token.to_string()),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Gate {
+    Assign(Operand),
+    Not(Operand),
+    And(Operand, Operand),
+    Or(Operand, Operand),
+    Xor(Operand, Operand),
+    LShift(Operand, Operand),
+    RShift(Operand, Operand),
+}
+
+impl Gate {
+    fn operands(&self) -> Vec<&Operand> {
+        match self {
+            Gate::Assign(a) | Gate::Not(a) => vec![a],
+            Gate::And(a, b)
+            | Gate::Or(a, b)
+            | Gate::Xor(a, b)
+            | Gate::LShift(a, b)
+            | Gate::RShift(a, b) => vec![a, b],
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Circuit {
+    gates: HashMap<String, Gate>,
+    overrides: HashMap<String, u64>,
+    cache: HashMap<String, u64>,
+    mask: u64,
+}
+
+impl Circuit {
+    /// Parses wire definitions for a 16 bit circuit.
+    ///
+    /// # Panics
+    /// Panics if a line is not a valid definition.
+    pub fn parse(input: &str) -> Self {
+        Self::parse_with_width(input, 16)
+    }
+
+    /// Parses wire definitions for a circuit with signals of `bits` bits.
+    pub fn parse_with_width(input: &str, bits: u32) -> Self {
+        let mut gates = HashMap::new();
+
+        for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let (name, gate) = if let Some((expression, name)) = line.split_once(" -> ") {
+                (name, parse_gate(expression))
+            } else if let Some((name, value)) = line.split_once(": ") {
+                (name, Gate::Assign(Operand::parse(value)))
+            } else {
+                panic!("Invalid wire definition \"{line}\"");
+            };
+            gates.insert(name.to_string(), gate);
+        }
+
+        Self {
+            gates,
+            overrides: HashMap::new(),
+            cache: HashMap::new(),
+            mask: if bits >= 64 {
+                u64::MAX
+            } else {
+                (1 << bits) - 1
+            },
+        }
+    }
+
+    /// Returns the names of every defined wire, sorted.
+    pub fn wires(&self) -> Vec<&str> {
+        let mut wires: Vec<_> = self.gates.keys().map(String::as_str).collect();
+        wires.sort_unstable();
+        wires
+    }
+
+    pub fn gate(&self, wire: &str) -> Option<&Gate> {
+        self.gates.get(wire)
+    }
+
+    /// Forces a wire to a signal, ignoring its definition. Clears memoized values.
+    pub fn set_override(&mut self, wire: &str, value: u64) {
+        self.overrides.insert(wire.to_string(), value & self.mask);
+        self.cache.clear();
+    }
+
+    /// Removes every override and memoized value.
+    pub fn reset(&mut self) {
+        self.overrides.clear();
+        self.cache.clear();
+    }
+
+    /// Computes the signal of a wire.
+    ///
+    /// # Returns
+    /// * The signal, or `None` if the wire or one of the wires it depends on is undefined.
+    ///
+    /// # Panics
+    /// Panics if the wire depends on itself.
+    pub fn evaluate(&mut self, wire: &str) -> Option<u64> {
+        let mut stack = vec![wire.to_string()];
+        let mut visiting = HashSet::new();
+
+        while let Some(current) = stack.last().cloned() {
+            if self.cache.contains_key(&current) {
+                stack.pop();
+                continue;
+            }
+            if let Some(&value) = self.overrides.get(&current) {
+                self.cache.insert(current, value);
+                stack.pop();
+                continue;
+            }
+
+            let gate = self.gates.get(&current)?;
+            let pending: Vec<String> = gate
+                .operands()
+                .into_iter()
+                .filter_map(|operand| match operand {
+                    Operand::Wire(name) if !self.cache.contains_key(name) => Some(name.clone()),
+                    _ => None,
+                })
+                .collect();
+
+            if pending.is_empty() {
+                let value = self.apply(gate);
+                visiting.remove(&current);
+                self.cache.insert(current, value);
+                stack.pop();
+            } else {
+                assert!(
+                    visiting.insert(current.clone()),
+                    "Wire {current} depends on itself"
+                );
+                stack.extend(pending);
+            }
+        }
+
+        self.cache.get(wire).copied()
+    }
+
+    fn apply(&self, gate: &Gate) -> u64 {
+        let value = |operand: &Operand| match operand {
+            Operand::Wire(name) => self.cache[name],
+            Operand::Value(value) => *value,
+        };
+
+        let result = match gate {
+            Gate::Assign(a) => value(a),
+            Gate::Not(a) => !value(a),
+            Gate::And(a, b) => value(a) & value(b),
+            Gate::Or(a, b) => value(a) | value(b),
+            Gate::Xor(a, b) => value(a) ^ value(b),
+            Gate::LShift(a, b) => value(a).checked_shl(value(b) as u32).unwrap_or(0),
+            Gate::RShift(a, b) => value(a).checked_shr(value(b) as u32).unwrap_or(0),
+        };
+
+        result & self.mask
+    }
+}
+
+fn parse_gate(expression: &str) -> Gate {
+    let tokens: Vec<_> = expression.split_whitespace().collect();
+
+    match tokens.as_slice() {
+        [a] => Gate::Assign(Operand::parse(a)),
+        ["NOT", a] => Gate::Not(Operand::parse(a)),
+        [a, op, b] => {
+            let (a, b) = (Operand::parse(a), Operand::parse(b));
+            match *op {
+                "AND" => Gate::And(a, b),
+                "OR" => Gate::Or(a, b),
+                "XOR" => Gate::Xor(a, b),
+                "LSHIFT" => Gate::LShift(a, b),
+                "RSHIFT" => Gate::RShift(a, b),
+                _ => panic!("Unknown gate \"{op}\""),
+            }
+        }
+        _ => panic!("Invalid gate expression \"{expression}\""),
+    }
+}
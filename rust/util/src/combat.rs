@@ -0,0 +1,245 @@
+//! Turn based combat between units on a grid map.
+//!
+//! Every round, units take turns in reading order (top to bottom, then left to right). A unit
+//! with no enemies left ends the combat. Otherwise it moves one step towards the nearest square
+//! in range of an enemy, unless it is already in range, and then attacks the adjacent enemy with
+//! the fewest hit points.
+//!
+//! All ties are broken by reading order: between equally near target squares, between equally
+//! short first steps and between equally weak enemies. Distances come from the BFS
+//! [`distance_map`], computed once from the unit to pick the target square and once from that
+//! square back to pick the step.
+//!
+//! Maps use `#` for walls, `.` for open floor and any other character for a unit of that kind,
+//! so the classic `E`lves against `G`oblins works as well as battles between more factions.
+use crate::grid::Grid;
+use crate::pathfinding::distance_map;
+use crate::point::Point;
+
+/// Neighbouring squares in reading order.
+const READING: [Point; 4] = [Point::UP, Point::LEFT, Point::RIGHT, Point::DOWN];
+
+/// Hit points of a unit when combat begins.
+pub const HIT_POINTS: i32 = 200;
+
+/// Attack power of a unit unless changed with [`Battle::set_attack`].
+pub const ATTACK: i32 = 3;
+
+const OPEN: char = '.';
+
+/// A single combatant.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Unit {
+    pub kind: char,
+    pub position: Point,
+    pub hit_points: i32,
+    pub attack: i32,
+}
+
+impl Unit {
+    pub fn is_alive(&self) -> bool {
+        self.hit_points > 0
+    }
+}
+
+/// The result of a finished combat.
+///
+/// # Fields
+/// - `rounds`: Number of full rounds completed before a unit found no enemies.
+/// - `hit_points`: Total hit points of the surviving units.
+/// - `winner`: The kind of the surviving units.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Outcome {
+    pub rounds: u32,
+    pub hit_points: i32,
+    pub winner: char,
+}
+
+impl Outcome {
+    /// The puzzle score: full rounds multiplied by the remaining hit points.
+    pub fn score(&self) -> i64 {
+        self.rounds as i64 * self.hit_points as i64
+    }
+}
+
+/// The state of a combat: the map with units drawn on it and the units themselves.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Battle {
+    pub map: Grid<char>,
+    pub units: Vec<Unit>,
+}
+
+impl Battle {
+    /// Reads the map, creating a unit for every character other than `#` and `.`.
+    ///
+    /// # Panics
+    /// Panics if the rows of the map have different lengths.
+    pub fn parse(input: &str) -> Self {
+        let map = Grid::parse(input.trim(), None).expect("Invalid combat map");
+        let mut units = Vec::new();
+
+        for (y, row) in map.rows().enumerate() {
+            for (x, &kind) in row.iter().enumerate() {
+                if kind != '#' && kind != OPEN {
+                    units.push(Unit {
+                        kind,
+                        position: Point::new(x as i32, y as i32),
+                        hit_points: HIT_POINTS,
+                        attack: ATTACK,
+                    });
+                }
+            }
+        }
+
+        Self { map, units }
+    }
+
+    /// Changes the attack power of every unit of a kind.
+    pub fn set_attack(&mut self, kind: char, attack: i32) {
+        self.units
+            .iter_mut()
+            .filter(|unit| unit.kind == kind)
+            .for_each(|unit| unit.attack = attack);
+    }
+
+    /// The number of units of a kind that have died.
+    pub fn losses(&self, kind: char) -> usize {
+        self.units
+            .iter()
+            .filter(|unit| unit.kind == kind && !unit.is_alive())
+            .count()
+    }
+
+    /// Plays one round.
+    ///
+    /// # Returns
+    /// * `true` if every unit took its turn, `false` if combat ended during the round.
+    pub fn round(&mut self) -> bool {
+        self.units
+            .sort_by_key(|unit| (unit.position.y, unit.position.x));
+
+        for index in 0..self.units.len() {
+            if !self.units[index].is_alive() {
+                continue;
+            }
+            if !self.units.iter().any(|other| self.is_enemy(index, other)) {
+                return false;
+            }
+
+            if self.target(index).is_none() {
+                self.step(index);
+            }
+            if let Some(target) = self.target(index) {
+                self.attack(index, target);
+            }
+        }
+
+        true
+    }
+
+    /// Plays rounds until only one kind of unit is left.
+    pub fn fight(&mut self) -> Outcome {
+        let mut rounds = 0;
+        while self.round() {
+            rounds += 1;
+        }
+
+        let survivors = self.units.iter().filter(|unit| unit.is_alive());
+        Outcome {
+            rounds,
+            hit_points: survivors.clone().map(|unit| unit.hit_points).sum(),
+            winner: survivors.map(|unit| unit.kind).next().unwrap_or(OPEN),
+        }
+    }
+
+    /// Draws the map with the living units.
+    pub fn render(&self) -> String {
+        self.map.render()
+    }
+
+    fn is_enemy(&self, index: usize, other: &Unit) -> bool {
+        other.is_alive() && other.kind != self.units[index].kind
+    }
+
+    /// The weakest adjacent enemy, ties broken in reading order.
+    fn target(&self, index: usize) -> Option<usize> {
+        let position = self.units[index].position;
+
+        READING
+            .iter()
+            .filter_map(|offset| {
+                let square = position.add(offset);
+                self.units
+                    .iter()
+                    .position(|other| self.is_enemy(index, other) && other.position == square)
+            })
+            .min_by_key(|&target| self.units[target].hit_points)
+    }
+
+    /// Moves one step along a shortest path to the nearest square in range of an enemy.
+    fn step(&mut self, index: usize) {
+        let position = self.units[index].position;
+        let open = |value: &char| *value == OPEN;
+
+        let distances = distance_map(&self.map, position, open);
+        let Some((_, destination)) = self
+            .units
+            .iter()
+            .filter(|other| self.is_enemy(index, other))
+            .flat_map(|enemy| READING.map(|offset| enemy.position.add(&offset)))
+            .filter_map(|square| match distances.get_value(&square) {
+                Some(distance) if distance > 0 => Some((distance, square)),
+                _ => None,
+            })
+            .min_by_key(|&(distance, square)| (distance, square.y, square.x))
+        else {
+            return;
+        };
+
+        let back = distance_map(&self.map, destination, open);
+        let next = READING
+            .iter()
+            .map(|offset| position.add(offset))
+            .filter_map(|square| match back.get_value(&square) {
+                Some(distance) if distance >= 0 && self.map.get_value(&square) == Some(OPEN) => {
+                    Some((distance, square))
+                }
+                _ => None,
+            })
+            .min_by_key(|&(distance, _)| distance)
+            .map(|(_, square)| square)
+            .expect("Destination reachable from unit but not from its neighbours");
+
+        self.map.set_value(&position, OPEN);
+        self.map.set_value(&next, self.units[index].kind);
+        self.units[index].position = next;
+    }
+
+    fn attack(&mut self, index: usize, target: usize) {
+        let attack = self.units[index].attack;
+        let enemy = &mut self.units[target];
+
+        enemy.hit_points -= attack;
+        if !enemy.is_alive() {
+            let position = enemy.position;
+            self.map.set_value(&position, OPEN);
+        }
+    }
+}
+
+/// Finds the smallest attack power letting a kind win without a single loss.
+///
+/// # Returns
+/// * The attack power and the outcome of that combat.
+pub fn flawless_victory(input: &str, kind: char) -> (i32, Outcome) {
+    let battle = Battle::parse(input);
+
+    (ATTACK + 1..)
+        .find_map(|attack| {
+            let mut battle = battle.clone();
+            battle.set_attack(kind, attack);
+            let outcome = battle.fight();
+            (battle.losses(kind) == 0).then_some((attack, outcome))
+        })
+        .unwrap()
+}
@@ -0,0 +1,239 @@
+//! Minimal reader for the `aoc.toml` configuration file.
+//!
+//! Supports the small subset of TOML the runner needs: `[section]` headers, `key = value` pairs,
+//! `#` comments and blank lines. Keys and values are either double quoted strings or bare tokens
+//! such as numbers and booleans, all stored as text for the caller to interpret.
+//!
+//! A configuration is written back with [`Display`](fmt::Display) or [`Config::save`], quoting
+//! only what would not survive as a bare token, so files the runner maintains itself (such as
+//! stored answers) read back exactly as they were written. Comments are not preserved.
+use std::fmt;
+use std::fs::{read_to_string, write};
+use std::io;
+use std::path::Path;
+
+/// Parsed configuration, keeping sections and keys in file order.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Config {
+    sections: Vec<(String, Vec<(String, String)>)>,
+}
+
+/// A malformed line, numbered from one.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ConfigError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl Config {
+    /// Reads a configuration file, returning an empty configuration if the file does not exist.
+    pub fn load(path: impl AsRef<Path>) -> Result<Config, ConfigError> {
+        match read_to_string(path) {
+            Ok(text) => Config::parse(&text),
+            Err(_) => Ok(Config::default()),
+        }
+    }
+
+    /// Parses configuration text. Keys before the first header belong to the `""` section.
+    pub fn parse(text: &str) -> Result<Config, ConfigError> {
+        let mut config = Config::default();
+        let mut current = String::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let error = |message: &str| ConfigError {
+                line: index + 1,
+                message: message.to_string(),
+            };
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let name = header
+                    .strip_suffix(']')
+                    .ok_or_else(|| error("Unterminated section header"))?;
+                current = name.trim().to_string();
+                config.section_index(&current);
+                continue;
+            }
+
+            let (key, value) = match line.strip_prefix('"') {
+                Some(quoted) => {
+                    let (key, rest) = parse_string(quoted).ok_or_else(|| error("Invalid key"))?;
+                    let value = rest
+                        .trim_start()
+                        .strip_prefix('=')
+                        .ok_or_else(|| error("Expected key = value"))?;
+                    (key, value)
+                }
+                None => {
+                    let (key, value) = line
+                        .split_once('=')
+                        .ok_or_else(|| error("Expected key = value"))?;
+                    (key.trim().to_string(), value)
+                }
+            };
+            if key.is_empty() {
+                return Err(error("Missing key"));
+            }
+            let value = parse_value(value.trim()).ok_or_else(|| error("Invalid value"))?;
+
+            config.insert(&current, &key, &value);
+        }
+
+        Ok(config)
+    }
+
+    /// Writes the configuration to a file, replacing its contents.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        write(path, self.to_string())
+    }
+
+    /// Returns the value of `key` in `section`.
+    pub fn get(&self, section: &str, key: &str) -> Option<&str> {
+        self.section(section)
+            .find(|(k, _)| *k == key)
+            .map(|(_, value)| value)
+    }
+
+    /// Iterates over the keys and values of a section in file order.
+    pub fn section<'a>(&'a self, name: &str) -> impl Iterator<Item = (&'a str, &'a str)> {
+        let name = name.to_string();
+        self.sections
+            .iter()
+            .filter(move |(section, _)| *section == name)
+            .flat_map(|(_, entries)| entries.iter())
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// Iterates over the names of all sections in file order.
+    pub fn sections(&self) -> impl Iterator<Item = &str> {
+        self.sections.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Sets a value, replacing any previous value for the same key.
+    pub fn insert(&mut self, section: &str, key: &str, value: &str) {
+        let index = self.section_index(section);
+        let entries = &mut self.sections[index].1;
+
+        match entries.iter_mut().find(|(k, _)| k == key) {
+            Some((_, existing)) => *existing = value.to_string(),
+            None => entries.push((key.to_string(), value.to_string())),
+        }
+    }
+
+    /// Removes a key, returning its value if it was present. The section itself is kept.
+    pub fn remove(&mut self, section: &str, key: &str) -> Option<String> {
+        let (_, entries) = self.sections.iter_mut().find(|(name, _)| name == section)?;
+        let index = entries.iter().position(|(k, _)| k == key)?;
+        Some(entries.remove(index).1)
+    }
+
+    /// Finds a section by name, adding it if missing.
+    fn section_index(&mut self, section: &str) -> usize {
+        match self.sections.iter().position(|(name, _)| name == section) {
+            Some(index) => index,
+            None => {
+                self.sections.push((section.to_string(), Vec::new()));
+                self.sections.len() - 1
+            }
+        }
+    }
+}
+
+impl fmt::Display for Config {
+    /// Writes keys outside of any section first, then every section with its header.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut sections: Vec<_> = self.sections.iter().collect();
+        sections.sort_by_key(|(name, _)| !name.is_empty());
+
+        let mut written = false;
+        for (name, entries) in sections {
+            if !name.is_empty() {
+                if written {
+                    writeln!(f)?;
+                }
+                writeln!(f, "[{name}]")?;
+                written = true;
+            }
+            for (key, value) in entries {
+                writeln!(f, "{} = {}", format_token(key), format_token(value))?;
+                written = true;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Strips quotes and trailing comments from a raw value.
+fn parse_value(raw: &str) -> Option<String> {
+    let Some(quoted) = raw.strip_prefix('"') else {
+        let bare = raw.split('#').next().unwrap_or_default().trim();
+        return (!bare.is_empty()).then(|| bare.to_string());
+    };
+
+    let (value, rest) = parse_string(quoted)?;
+    let rest = rest.trim();
+    (rest.is_empty() || rest.starts_with('#')).then_some(value)
+}
+
+/// Reads a quoted string after its opening quote, returning it with the text after the closing
+/// quote.
+fn parse_string(quoted: &str) -> Option<(String, &str)> {
+    let mut value = String::new();
+    let mut chars = quoted.chars();
+
+    loop {
+        match chars.next()? {
+            '"' => break,
+            '\\' => match chars.next()? {
+                'n' => value.push('\n'),
+                'r' => value.push('\r'),
+                't' => value.push('\t'),
+                c @ ('"' | '\\') => value.push(c),
+                _ => return None,
+            },
+            c => value.push(c),
+        }
+    }
+
+    Some((value, chars.as_str()))
+}
+
+/// Writes a key or value bare if it reads back unchanged, quoted and escaped otherwise.
+fn format_token(token: &str) -> String {
+    let bare = !token.is_empty()
+        && token
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "_-.+:/{}".contains(c));
+    if bare {
+        return token.to_string();
+    }
+
+    let mut quoted = String::from('"');
+    for c in token.chars() {
+        match c {
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
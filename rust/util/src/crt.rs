@@ -0,0 +1,162 @@
+//! Cycle-accurate execution of instruction streams, sampling the state during every cycle.
+//!
+//! Some puzzles care about a register's value *while* an instruction executes rather than after
+//! it: instructions take several cycles and only update the state when they complete. [`execute`]
+//! runs any such program and hands the state during each cycle to a callback, so sampling a
+//! signal or painting a screen is just a different callback.
+//!
+//! The `noop`/`addx` CPU driving a cathode-ray tube is built on top: [`signal_strength`] samples
+//! the register at given cycles and [`draw`] paints a [`BitGrid`] whose rows can be read as
+//! capital letters.
+
+/// Runs a program, calling `during` with the 1-based cycle number and the state of every cycle.
+///
+/// # Arguments
+/// * `program` - The instructions, executed once in order.
+/// * `state` - The initial state.
+/// * `cycles` - How many cycles an instruction takes.
+/// * `apply` - The state after an instruction completes.
+/// * `during` - Called once per cycle, before the instruction occupying it has taken effect.
+///
+/// # Returns
+/// * The state after the last instruction.
+pub fn execute<I, S, C, A, D>(program: &[I], mut state: S, cycles: C, apply: A, mut during: D) -> S
+where
+    S: Copy,
+    C: Fn(&I) -> usize,
+    A: Fn(&I, S) -> S,
+    D: FnMut(usize, S),
+{
+    let mut cycle = 1;
+
+    for instruction in program {
+        for _ in 0..cycles(instruction) {
+            during(cycle, state);
+            cycle += 1;
+        }
+        state = apply(instruction, state);
+    }
+
+    state
+}
+
+/// An instruction of the CRT's CPU, which has a single register `X` starting at `1`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Instruction {
+    /// Does nothing for one cycle.
+    Noop,
+    /// Adds to `X` after two cycles.
+    Addx(i64),
+}
+
+impl Instruction {
+    pub fn cycles(&self) -> usize {
+        match self {
+            Instruction::Noop => 1,
+            Instruction::Addx(_) => 2,
+        }
+    }
+
+    pub fn apply(&self, x: i64) -> i64 {
+        match self {
+            Instruction::Noop => x,
+            Instruction::Addx(value) => x + value,
+        }
+    }
+}
+
+/// Parses one `noop` or `addx <value>` instruction per line.
+///
+/// # Panics
+/// Panics on any other instruction.
+pub fn parse(input: &str) -> Vec<Instruction> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(
+            |line| match line.split_whitespace().collect::<Vec<_>>()[..] {
+                ["noop"] => Instruction::Noop,
+                ["addx", value] => Instruction::Addx(value.parse().expect("Invalid addx value")),
+                _ => panic!("Unknown instruction {line:?}"),
+            },
+        )
+        .collect()
+}
+
+/// Runs the CPU program, calling `during` with the cycle number and `X` during every cycle.
+pub fn run<F: FnMut(usize, i64)>(program: &[Instruction], during: F) -> i64 {
+    execute(program, 1, Instruction::cycles, Instruction::apply, during)
+}
+
+/// Sums `cycle * X` during each of the sampled cycles.
+pub fn signal_strength(program: &[Instruction], samples: &[usize]) -> i64 {
+    let mut total = 0;
+    run(program, |cycle, x| {
+        if samples.contains(&cycle) {
+            total += cycle as i64 * x;
+        }
+    });
+    total
+}
+
+/// Paints the screen: the beam draws one pixel per cycle in reading order, lighting it if the
+/// three pixel wide sprite centred on `X` covers the beam's column.
+pub fn draw(program: &[Instruction], width: usize, height: usize) -> BitGrid {
+    let mut screen = BitGrid::new(width, height);
+    run(program, |cycle, x| {
+        let (row, column) = ((cycle - 1) / width, (cycle - 1) % width);
+        if row < height && (x - column as i64).abs() <= 1 {
+            screen.set(column, row, true);
+        }
+    });
+    screen
+}
+
+/// A fixed size grid of pixels, one `u64` per row.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct BitGrid {
+    pub width: usize,
+    pub height: usize,
+    rows: Vec<u64>,
+}
+
+impl BitGrid {
+    /// # Panics
+    /// Panics if wider than 64 pixels.
+    pub fn new(width: usize, height: usize) -> Self {
+        assert!(width <= 64, "BitGrid rows hold at most 64 pixels");
+        Self {
+            width,
+            height,
+            rows: vec![0; height],
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height && self.rows[y] & (1 << x) != 0
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, value: bool) {
+        if value {
+            self.rows[y] |= 1 << x;
+        } else {
+            self.rows[y] &= !(1 << x);
+        }
+    }
+
+    /// The number of lit pixels.
+    pub fn count(&self) -> u32 {
+        self.rows.iter().map(|row| row.count_ones()).sum()
+    }
+
+    /// The pixels as text, `#` for lit and `.` for dark, one string per row.
+    pub fn lines(&self) -> Vec<String> {
+        (0..self.height)
+            .map(|y| {
+                (0..self.width)
+                    .map(|x| if self.get(x, y) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,135 @@
+//! Cycle detection for sequences of states produced by repeatedly applying a step function.
+//!
+//! Any such sequence over a finite state space eventually repeats: after `start` steps it enters
+//! a loop of `length` states. Both detectors return this as a [`Cycle`], which maps any step
+//! count, however large, to an equivalent step inside the first loop.
+//!
+//! * [`brent`] uses [Brent's algorithm] with constant memory. States only need equality, but
+//!   the step function is applied up to three times per state.
+//! * [`detect`] keeps a history of every state and its index in a hash map. It steps each state
+//!   once and returns the history, so later lookups are free. Prefer it when states are cheap to
+//!   store.
+//!
+//! [`nth_with_cycle`] builds on [`detect`] to answer "the state after a billion steps" directly.
+//!
+//! [Brent's algorithm]: https://en.wikipedia.org/wiki/Cycle_detection#Brent's_algorithm
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A sequence that enters a loop of `length` states after `start` states.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Maps step `n` to the equivalent step before the end of the first loop.
+    pub fn index(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// Finds the cycle with Brent's algorithm, using constant memory.
+///
+/// The sequence is `initial`, `step(initial)`, `step(step(initial))` and so on.
+pub fn brent<T, F>(initial: T, step: F) -> Cycle
+where
+    T: Clone + PartialEq,
+    F: Fn(&T) -> T,
+{
+    // Find the cycle length by searching successive powers of two.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // Find the start with two pointers `length` steps apart.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// Finds the cycle by remembering every state.
+///
+/// # Returns
+/// * The cycle and the history of states, `start + length` long. The state at step `n` is
+///   `history[cycle.index(n)]`.
+pub fn detect<T, F>(initial: T, mut step: F) -> (Cycle, Vec<T>)
+where
+    T: Clone + Eq + Hash,
+    F: FnMut(&T) -> T,
+{
+    let mut seen = HashMap::new();
+    let mut history = Vec::new();
+    let mut state = initial;
+
+    loop {
+        if let Some(&start) = seen.get(&state) {
+            let cycle = Cycle {
+                start,
+                length: history.len() - start,
+            };
+            return (cycle, history);
+        }
+
+        seen.insert(state.clone(), history.len());
+        let next = step(&state);
+        history.push(state);
+        state = next;
+    }
+}
+
+/// Finds the first repetition in an already stored sequence.
+///
+/// # Returns
+/// * The cycle formed by the first element equal to an earlier one, or `None` if every element
+///   is distinct.
+pub fn period<T>(sequence: &[T]) -> Option<Cycle>
+where
+    T: Eq + Hash,
+{
+    let mut seen = HashMap::new();
+
+    sequence.iter().enumerate().find_map(|(i, state)| {
+        seen.insert(state, i).map(|start| Cycle {
+            start,
+            length: i - start,
+        })
+    })
+}
+
+/// Returns the state after `n` steps, short-circuiting through the cycle.
+pub fn nth_with_cycle<T, F>(initial: T, step: F, n: usize) -> T
+where
+    T: Clone + Eq + Hash,
+    F: FnMut(&T) -> T,
+{
+    let (cycle, mut history) = detect(initial, step);
+    history.swap_remove(cycle.index(n))
+}
@@ -0,0 +1,122 @@
+//! Interactive step-through debugging for simulations.
+//!
+//! Simulation based solutions call [`tick`] once per step with a closure rendering the current
+//! state. When stepping is disabled, which is the default, this is a single atomic load and the
+//! closure is never evaluated. The runner enables stepping with the `--step` flag.
+//!
+//! After every tick the rendered state is shown and a command is read from stdin, followed by
+//! `Enter`:
+//! * `n` or empty - advance to the next tick.
+//! * `c` - continue running without stopping.
+//! * `q` - quit the process.
+//!
+//! Independently of stepping, ticks can be recorded with [`record`] so a run can be replayed
+//! later without recomputing it. Recording keeps at most [`MAX_FRAMES`] frames by default,
+//! sampled evenly over the whole run with a [`Downsampler`].
+use crate::ansi::*;
+use crate::viz::Downsampler;
+use std::io::{stdin, stdout, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+/// Default upper bound on recorded frames, keeping long simulations from exhausting memory.
+pub const MAX_FRAMES: usize = 10_000;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static RECORDING: AtomicBool = AtomicBool::new(false);
+static FRAMES: Mutex<Downsampler<Frame>> = Mutex::new(Downsampler::new(MAX_FRAMES));
+
+/// A rendered step of a simulation.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Frame {
+    pub label: String,
+    pub image: String,
+}
+
+/// Enables stepping for every subsequent tick.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Disables stepping, letting simulations run at full speed.
+pub fn disable() {
+    ENABLED.store(false, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Starts or stops keeping the frame of every subsequent tick.
+pub fn record(recording: bool) {
+    RECORDING.store(recording, Ordering::Relaxed);
+}
+
+pub fn is_recording() -> bool {
+    RECORDING.load(Ordering::Relaxed)
+}
+
+/// Changes the frame budget of recording, discarding frames recorded so far.
+///
+/// # Panics
+/// Panics if `max_frames` is less than two.
+pub fn set_max_frames(max_frames: usize) {
+    *FRAMES.lock().unwrap() = Downsampler::new(max_frames);
+}
+
+/// Returns the frames recorded so far, clearing them.
+pub fn take_frames() -> Vec<Frame> {
+    FRAMES.lock().unwrap().take()
+}
+
+/// Pauses the simulation after a step if stepping is enabled, and keeps the frame if recording.
+///
+/// # Arguments
+/// * `label` - A short description of the step, e.g. the current position.
+/// * `render` - Produces the frame to display. Only called when stepping or recording.
+pub fn tick<F>(label: &str, render: F)
+where
+    F: FnOnce() -> String,
+{
+    let recording = is_recording();
+    if !recording && !is_enabled() {
+        return;
+    }
+
+    if !is_enabled() {
+        FRAMES.lock().unwrap().offer(|| Frame {
+            label: label.to_string(),
+            image: render(),
+        });
+        return;
+    }
+
+    let image = render();
+    if recording {
+        FRAMES.lock().unwrap().offer(|| Frame {
+            label: label.to_string(),
+            image: image.clone(),
+        });
+    }
+    pause(label, &image);
+}
+
+/// Shows a frame and waits for a stepping command.
+fn pause(label: &str, image: &str) {
+    print!("{HOME}{CLEAR}{image}");
+    print!("{BOLD}{YELLOW}{label}{RESET} [n]ext [c]ontinue [q]uit: ");
+    let _ = stdout().flush();
+
+    let mut command = String::new();
+    if stdin().read_line(&mut command).unwrap_or(0) == 0 {
+        // Nothing left to read, e.g. stdin is not a terminal.
+        disable();
+        return;
+    }
+
+    match command.trim() {
+        "c" => disable(),
+        "q" => std::process::exit(0),
+        _ => {}
+    }
+}
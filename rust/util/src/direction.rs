@@ -0,0 +1,182 @@
+use crate::point::Point;
+use std::fmt;
+use std::str::FromStr;
+
+/// Describes the direction of the movement
+/// Designed to be used with the Grid struct.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Direction {
+    Right,
+    Left,
+    Up,
+    Down,
+    RightDown,
+    RightUp,
+    LeftDown,
+    LeftUp,
+    Stop,
+}
+
+pub const ORTHOGONAL: [Direction; 4] = [
+    Direction::Right,
+    Direction::Down,
+    Direction::Left,
+    Direction::Up,
+];
+
+/// Every direction, including `Stop`, used to look up the direction of a step.
+const ALL: [Direction; 9] = [
+    Direction::Right,
+    Direction::Left,
+    Direction::Up,
+    Direction::Down,
+    Direction::RightDown,
+    Direction::RightUp,
+    Direction::LeftDown,
+    Direction::LeftUp,
+    Direction::Stop,
+];
+
+impl Direction {
+    /// Get the unit step for the direction, same as `Point::from(direction)`.
+    /// # Returns
+    /// * The point
+    /// * If the direction is Stop, returns Point::EMPTY
+    pub fn to_point(&self) -> Point {
+        Point::from(*self)
+    }
+
+    /// Check if the direction is diagonal
+    /// # Returns
+    /// * True if the direction is diagonal
+    /// * False if the direction is not diagonal
+    pub fn is_diagonal(&self) -> bool {
+        matches!(
+            self,
+            Direction::RightDown | Direction::RightUp | Direction::LeftDown | Direction::LeftUp
+        )
+    }
+
+    /// Parses a single character, see [`TryFrom<char>`](#impl-TryFrom<char>-for-Direction).
+    pub fn parse(c: char) -> Option<Self> {
+        Direction::try_from(c).ok()
+    }
+
+    /// Parses a direction written as a word, such as `up` or `forward`, or anything else
+    /// accepted by [`FromStr`](#impl-FromStr-for-Direction).
+    ///
+    /// Submarine style scripts move `forward` along the x axis, so it maps to `Right` and
+    /// `backward` to `Left`.
+    pub fn parse_word(word: &str) -> Option<Self> {
+        match word.to_ascii_lowercase().as_str() {
+            "forward" => Some(Direction::Right),
+            "backward" | "back" => Some(Direction::Left),
+            _ => word.parse().ok(),
+        }
+    }
+
+    pub fn turn_right(&self) -> Self {
+        match self {
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+            Direction::Up => Direction::Right,
+            Direction::Stop => Direction::Stop,
+            _ => panic!("Invalid direction {:?}", self),
+        }
+    }
+
+    pub fn turn_left(&self) -> Self {
+        match self {
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+            Direction::Up => Direction::Left,
+            Direction::Stop => Direction::Stop,
+            _ => panic!("Invalid direction {:?}", self),
+        }
+    }
+}
+
+/// The single mapping between directions and unit steps. `TryFrom<Point>` is its inverse.
+impl From<Direction> for Point {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Right => Point::RIGHT,
+            Direction::Left => Point::LEFT,
+            Direction::Up => Point::UP,
+            Direction::Down => Point::DOWN,
+            Direction::RightDown => Point::RIGHT_DOWN,
+            Direction::RightUp => Point::RIGHT_UP,
+            Direction::LeftDown => Point::LEFT_DOWN,
+            Direction::LeftUp => Point::LEFT_UP,
+            Direction::Stop => Point::EMPTY,
+        }
+    }
+}
+
+/// Finds the direction of a unit step. Longer steps such as `(2, 0)` are rejected, use
+/// [`Point::signum`] first to get the direction of an arbitrary offset.
+impl TryFrom<Point> for Direction {
+    type Error = Point;
+
+    fn try_from(point: Point) -> Result<Self, Self::Error> {
+        ALL.into_iter()
+            .find(|&direction| Point::from(direction) == point)
+            .ok_or(point)
+    }
+}
+
+/// Returned when text does not name a direction.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseDirectionError(pub String);
+
+impl fmt::Display for ParseDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid direction \"{}\"", self.0)
+    }
+}
+
+impl std::error::Error for ParseDirectionError {}
+
+/// Accepts the initials `R`, `L`, `U`, `D`, the compass points `E`, `W`, `N`, `S` in either case,
+/// and the arrows `>`, `<`, `^`, `v`.
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'R' | 'r' | 'E' | 'e' | '>' => Ok(Direction::Right),
+            'L' | 'l' | 'W' | 'w' | '<' => Ok(Direction::Left),
+            'U' | 'u' | 'N' | 'n' | '^' => Ok(Direction::Up),
+            'D' | 'd' | 'S' | 's' | 'v' => Ok(Direction::Down),
+            _ => Err(ParseDirectionError(c.to_string())),
+        }
+    }
+}
+
+/// Accepts any single character allowed by `TryFrom<char>`, the names `right`, `left`, `up`,
+/// `down` and the compass names `east`, `west`, `north`, `south`, case insensitive.
+/// Diagonals are written as compass points (`NE`, `northwest`) or joined names (`up-right`).
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return Direction::try_from(c);
+        }
+
+        match s.to_ascii_lowercase().as_str() {
+            "right" | "east" => Ok(Direction::Right),
+            "left" | "west" => Ok(Direction::Left),
+            "up" | "north" => Ok(Direction::Up),
+            "down" | "south" => Ok(Direction::Down),
+            "ne" | "northeast" | "up-right" | "right-up" => Ok(Direction::RightUp),
+            "nw" | "northwest" | "up-left" | "left-up" => Ok(Direction::LeftUp),
+            "se" | "southeast" | "down-right" | "right-down" => Ok(Direction::RightDown),
+            "sw" | "southwest" | "down-left" | "left-down" => Ok(Direction::LeftDown),
+            _ => Err(ParseDirectionError(s.to_string())),
+        }
+    }
+}
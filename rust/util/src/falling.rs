@@ -0,0 +1,233 @@
+//! Drops shapes into a space until they come to rest.
+//!
+//! Sand, falling rocks and bricks all follow the same loop: a unit starts somewhere, a movement
+//! rule proposes where it goes next given which cells are free, and once the rule has nowhere
+//! left to go the unit settles and becomes an obstacle for the following ones. Rules are plain
+//! closures, with [`sand`], [`straight_down`] and [`jets`] covering the usual puzzles.
+//!
+//! The y axis points down, matching [`Point::DOWN`]. Every settled unit keeps an id, so after
+//! the simulation [`Falling::supports`] can tell which units rest on which.
+use crate::hash::*;
+use crate::point::Point;
+
+/// Occupant id of static terrain, such as rock walls, which never supports counts.
+pub const TERRAIN: usize = usize::MAX;
+
+/// Storage for occupied cells.
+pub trait Space {
+    /// Returns the id of the unit occupying the cell, or `TERRAIN`.
+    fn occupant(&self, point: &Point) -> Option<usize>;
+    fn place(&mut self, point: &Point, id: usize);
+
+    fn is_free(&self, point: &Point) -> bool {
+        self.occupant(point).is_none()
+    }
+}
+
+/// Unbounded space stored in a hash map, with an optional infinite floor.
+#[derive(Clone, Debug, Default)]
+pub struct SparseSpace {
+    pub cells: FastMap<Point, usize>,
+    /// Cells at or below this row are treated as terrain.
+    pub floor: Option<i32>,
+}
+
+impl Space for SparseSpace {
+    fn occupant(&self, point: &Point) -> Option<usize> {
+        if self.floor.is_some_and(|floor| point.y >= floor) {
+            return Some(TERRAIN);
+        }
+        self.cells.get(point).copied()
+    }
+
+    fn place(&mut self, point: &Point, id: usize) {
+        self.cells.insert(*point, id);
+    }
+}
+
+/// A `width` by `height` box stored in a vector. The sides and bottom are terrain, while the
+/// space above the top row is open so units can start there.
+#[derive(Clone, Debug)]
+pub struct DenseSpace {
+    pub width: i32,
+    pub height: i32,
+    cells: Vec<Option<usize>>,
+}
+
+impl DenseSpace {
+    pub fn new(width: i32, height: i32) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![None; (width * height) as usize],
+        }
+    }
+}
+
+impl Space for DenseSpace {
+    fn occupant(&self, point: &Point) -> Option<usize> {
+        if point.x < 0 || point.x >= self.width || point.y >= self.height {
+            return Some(TERRAIN);
+        }
+        if point.y < 0 {
+            return None;
+        }
+        self.cells[(point.y * self.width + point.x) as usize]
+    }
+
+    /// # Panics
+    /// Panics if the point is outside of the box.
+    fn place(&mut self, point: &Point, id: usize) {
+        assert!(point.y >= 0, "Unit settled above the top of the space");
+        self.cells[(point.y * self.width + point.x) as usize] = Some(id);
+    }
+}
+
+/// What a movement rule decides for the current position.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Step {
+    /// Keep falling from the new position.
+    Move(Point),
+    /// Come to rest at the position, which may differ from the current one.
+    Settle(Point),
+}
+
+/// Sand falls straight down, otherwise diagonally down and left, then down and right.
+pub fn sand() -> impl FnMut(&dyn Fn(Point) -> bool, Point) -> Step {
+    |fits, position| {
+        [Point::DOWN, Point::LEFT_DOWN, Point::RIGHT_DOWN]
+            .map(|step| position.add(&step))
+            .into_iter()
+            .find(|&next| fits(next))
+            .map_or(Step::Settle(position), Step::Move)
+    }
+}
+
+/// Falls straight down until blocked.
+pub fn straight_down() -> impl FnMut(&dyn Fn(Point) -> bool, Point) -> Step {
+    |fits, position| {
+        let next = position.add(&Point::DOWN);
+        if fits(next) {
+            Step::Move(next)
+        } else {
+            Step::Settle(position)
+        }
+    }
+}
+
+/// Pushed sideways by the next jet of a repeating pattern of `<` and `>`, if possible, then
+/// falls one step. The jet position is shared between units, so pass the same `next_jet`.
+pub fn jets<'a>(
+    pattern: &'a [u8],
+    next_jet: &'a mut usize,
+) -> impl FnMut(&dyn Fn(Point) -> bool, Point) -> Step + 'a {
+    move |fits, position| {
+        let push = if pattern[*next_jet] == b'<' {
+            Point::LEFT
+        } else {
+            Point::RIGHT
+        };
+        *next_jet = (*next_jet + 1) % pattern.len();
+
+        let pushed = position.add(&push);
+        let position = if fits(pushed) { pushed } else { position };
+        let down = position.add(&Point::DOWN);
+        if fits(down) {
+            Step::Move(down)
+        } else {
+            Step::Settle(position)
+        }
+    }
+}
+
+/// A space together with the cells of every unit that has settled in it.
+#[derive(Clone, Debug)]
+pub struct Falling<S> {
+    pub space: S,
+    /// The cells of each settled unit, indexed by id.
+    pub units: Vec<Vec<Point>>,
+    /// Units with any cell below this row have fallen out and are discarded.
+    pub abyss: Option<i32>,
+}
+
+impl<S: Space> Falling<S> {
+    pub fn new(space: S) -> Self {
+        Self {
+            space,
+            units: Vec::new(),
+            abyss: None,
+        }
+    }
+
+    /// Drops a shape, given as offsets from its reference point, starting at `start`.
+    ///
+    /// # Returns
+    /// * The id of the settled unit.
+    /// * `None` if the start is already blocked or the unit fell into the abyss.
+    pub fn drop(
+        &mut self,
+        shape: &[Point],
+        start: Point,
+        mut rule: impl FnMut(&dyn Fn(Point) -> bool, Point) -> Step,
+    ) -> Option<usize> {
+        let space = &self.space;
+        let fits = |position: Point| {
+            shape
+                .iter()
+                .all(|offset| space.is_free(&position.add(offset)))
+        };
+        if !fits(start) {
+            return None;
+        }
+
+        let lowest = shape.iter().map(|offset| offset.y).max().unwrap_or(0);
+        let mut position = start;
+
+        let rest = loop {
+            match rule(&fits, position) {
+                Step::Move(next) => position = next,
+                Step::Settle(rest) => break rest,
+            }
+            if self.abyss.is_some_and(|abyss| position.y + lowest > abyss) {
+                return None;
+            }
+        };
+
+        let id = self.units.len();
+        let cells: Vec<Point> = shape.iter().map(|offset| rest.add(offset)).collect();
+        for cell in &cells {
+            self.space.place(cell, id);
+        }
+        self.units.push(cells);
+        Some(id)
+    }
+
+    /// For every unit, the ids of the units directly beneath it, sorted and without duplicates.
+    pub fn supports(&self) -> Vec<Vec<usize>> {
+        self.units
+            .iter()
+            .enumerate()
+            .map(|(id, cells)| {
+                let mut below: Vec<usize> = cells
+                    .iter()
+                    .filter_map(|cell| self.space.occupant(&cell.add(&Point::DOWN)))
+                    .filter(|&other| other != id && other != TERRAIN)
+                    .collect();
+                below.sort_unstable();
+                below.dedup();
+                below
+            })
+            .collect()
+    }
+
+    /// For every unit, the ids of the units resting directly on it. The inverse of `supports`.
+    pub fn supporting(&self) -> Vec<Vec<usize>> {
+        let mut above = vec![Vec::new(); self.units.len()];
+        for (id, below) in self.supports().into_iter().enumerate() {
+            for other in below {
+                above[other].push(id);
+            }
+        }
+        above
+    }
+}
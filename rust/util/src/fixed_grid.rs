@@ -0,0 +1,182 @@
+//! Grid with dimensions known at compile time, stored inline without heap allocation.
+//!
+//! Keypads, bingo boards and other tiny puzzles pay more for the allocation and `i32` bounds
+//! math of [`Grid`] than for the actual work. `FixedGrid` keeps the same [`Point`] based API,
+//! but stores its cells in a nested array so the whole board is `Copy` when `T` is.
+//!
+//! The nested array is laid out row after row without gaps, so hot loops can also skip points
+//! entirely and walk [`cells`](FixedGrid::cells) by index. Moving one cell in a direction adds
+//! the fixed [`stride`](FixedGrid::stride) of that direction, see [`step`](FixedGrid::step).
+use crate::conversions::FromChar;
+use crate::direction::Direction;
+use crate::grid::Grid;
+use crate::point::Point;
+use std::fmt;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+/// A `W` by `H` grid stored on the stack.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct FixedGrid<T, const W: usize, const H: usize> {
+    pub data: [[T; W]; H],
+}
+
+/// Returned when converting a [`Grid`] whose size does not match the fixed dimensions.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SizeMismatch {
+    pub width: i32,
+    pub height: i32,
+}
+
+impl fmt::Display for SizeMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Grid of size {}x{} does not fit",
+            self.width, self.height
+        )
+    }
+}
+
+impl std::error::Error for SizeMismatch {}
+
+impl<T: Copy, const W: usize, const H: usize> FixedGrid<T, W, H> {
+    pub const WIDTH: i32 = W as i32;
+    pub const HEIGHT: i32 = H as i32;
+
+    /// Creates a grid with every cell set to `value`.
+    pub fn new(value: T) -> Self {
+        Self {
+            data: [[value; W]; H],
+        }
+    }
+
+    /// Creates a grid by calling `f` for every point.
+    pub fn from_fn(mut f: impl FnMut(Point) -> T) -> Self {
+        Self {
+            data: std::array::from_fn(|y| {
+                std::array::from_fn(|x| f(Point::new(x as i32, y as i32)))
+            }),
+        }
+    }
+
+    /// Retrieves the value at the specified point, or `None` if the point is out of bounds.
+    pub fn get_value(&self, point: &Point) -> Option<T> {
+        self.contains(point)
+            .then(|| self.data[point.y as usize][point.x as usize])
+    }
+
+    /// Sets the value at the specified point.
+    ///
+    /// # Panics
+    /// Panics if the point is out of bounds.
+    pub fn set_value(&mut self, point: &Point, value: T) {
+        self.data[point.y as usize][point.x as usize] = value;
+    }
+
+    /// Checks if the given point is within the grid boundaries.
+    pub fn contains(&self, point: &Point) -> bool {
+        (0..Self::WIDTH).contains(&point.x) && (0..Self::HEIGHT).contains(&point.y)
+    }
+
+    /// Returns the total number of elements in the grid.
+    pub fn get_size(&self) -> i32 {
+        Self::WIDTH * Self::HEIGHT
+    }
+
+    /// The cells in reading order, so the cell of `point` is at [`index`](Self::index).
+    pub fn cells(&self) -> &[T] {
+        self.data.as_flattened()
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        self.data.as_flattened_mut()
+    }
+
+    /// The position of a point in [`cells`](Self::cells).
+    ///
+    /// # Panics
+    /// Panics in debug builds if the point is out of bounds.
+    pub fn index(point: &Point) -> usize {
+        debug_assert!(
+            (0..Self::WIDTH).contains(&point.x) && (0..Self::HEIGHT).contains(&point.y),
+            "Point {point:?} out of bounds"
+        );
+        point.y as usize * W + point.x as usize
+    }
+
+    /// The point at a position in [`cells`](Self::cells).
+    pub fn point(index: usize) -> Point {
+        Point::new((index % W) as i32, (index / W) as i32)
+    }
+
+    /// The change of index when moving one cell in a direction, `dx + dy * W`.
+    pub const fn stride(direction: Direction) -> isize {
+        let w = W as isize;
+        match direction {
+            Direction::Right => 1,
+            Direction::Left => -1,
+            Direction::Up => -w,
+            Direction::Down => w,
+            Direction::RightDown => w + 1,
+            Direction::RightUp => 1 - w,
+            Direction::LeftDown => w - 1,
+            Direction::LeftUp => -w - 1,
+            Direction::Stop => 0,
+        }
+    }
+
+    /// Moves an index one cell in a direction without any bounds checks in release builds.
+    ///
+    /// Stepping off the left or right edge does not leave the cells but wraps into the
+    /// neighbouring row, so callers must know the step stays inside, for example from a border
+    /// of walls around the grid.
+    ///
+    /// # Panics
+    /// Panics in debug builds if the step leaves the grid or wraps around a row.
+    pub fn step(index: usize, direction: Direction) -> usize {
+        let next = index.wrapping_add_signed(Self::stride(direction));
+        debug_assert!(
+            {
+                let delta = Point::from(direction);
+                let point = Self::point(index);
+                let moved = Point::new(point.x + delta.x, point.y + delta.y);
+                index < W * H
+                    && (0..Self::WIDTH).contains(&moved.x)
+                    && (0..Self::HEIGHT).contains(&moved.y)
+            },
+            "Step {direction:?} from index {index} leaves the grid"
+        );
+        next
+    }
+
+    /// Iterates over every point and its value in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, T)> + '_ {
+        self.data.iter().enumerate().flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .map(move |(x, &value)| (Point::new(x as i32, y as i32), value))
+        })
+    }
+}
+
+impl<T, const W: usize, const H: usize> TryFrom<&Grid<T>> for FixedGrid<T, W, H>
+where
+    T: Copy + Default + Debug + PartialEq,
+    T: FromStr + FromChar,
+    <T as FromStr>::Err: Debug,
+    <T as FromChar>::Err: Debug,
+{
+    type Error = SizeMismatch;
+
+    fn try_from(grid: &Grid<T>) -> Result<Self, Self::Error> {
+        if grid.width != Self::WIDTH || grid.height != Self::HEIGHT {
+            return Err(SizeMismatch {
+                width: grid.width,
+                height: grid.height,
+            });
+        }
+
+        Ok(Self::from_fn(|point| grid.cells()[grid.index(&point)]))
+    }
+}
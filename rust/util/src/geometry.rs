@@ -0,0 +1,388 @@
+//! Axis-aligned segments, boxes and the crossings of paths made from them.
+//!
+//! The points of an axis-aligned segment are exactly those of its bounding box, which has zero
+//! width or height. Intersecting two segments is then the same as intersecting two boxes: the
+//! result is empty, a single crossing point, or a shorter segment where collinear segments
+//! overlap. [`Cuboid`] applies the same idea to 3D boxes.
+//!
+//! Radial sweeps order points by angle without floats: a quadrant bucket first, then the sign of
+//! the cross product inside the quadrant, where it is transitive.
+use crate::point::Point;
+use crate::point3::Point3;
+use std::cmp::Ordering;
+
+/// A horizontal or vertical segment including both end points.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Segment {
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Segment {
+    /// # Panics
+    /// Panics if the segment is diagonal.
+    pub fn new(start: Point, end: Point) -> Self {
+        assert!(
+            start.x == end.x || start.y == end.y,
+            "Segment from {start:?} to {end:?} is not axis-aligned"
+        );
+        Self { start, end }
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        self.start.y == self.end.y
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        self.start.x == self.end.x
+    }
+
+    /// The number of steps from start to end.
+    pub fn len(&self) -> i32 {
+        self.start.manhattan(&self.end)
+    }
+
+    /// Checks if the segment is a single point.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// The smallest and largest corner of the bounding box.
+    fn bounds(&self) -> (Point, Point) {
+        let min = Point::new(self.start.x.min(self.end.x), self.start.y.min(self.end.y));
+        let max = Point::new(self.start.x.max(self.end.x), self.start.y.max(self.end.y));
+        (min, max)
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        let (min, max) = self.bounds();
+        (min.x..=max.x).contains(&point.x) && (min.y..=max.y).contains(&point.y)
+    }
+
+    /// Returns the common part of two segments.
+    ///
+    /// # Returns
+    /// * `None` if the segments do not touch.
+    /// * A segment with equal end points if they cross at a single point.
+    /// * The overlap, ordered from the smaller to the larger corner, if they are collinear.
+    pub fn intersection(&self, other: &Segment) -> Option<Segment> {
+        let (min1, max1) = self.bounds();
+        let (min2, max2) = other.bounds();
+        let min = Point::new(min1.x.max(min2.x), min1.y.max(min2.y));
+        let max = Point::new(max1.x.min(max2.x), max1.y.min(max2.y));
+
+        (min.x <= max.x && min.y <= max.y).then(|| Segment::new(min, max))
+    }
+
+    /// Number of steps from the start of the segment to a point on it.
+    pub fn steps_to(&self, point: &Point) -> Option<i32> {
+        self.contains(point).then(|| self.start.manhattan(point))
+    }
+
+    /// Every point of the segment from start to end.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let step = self.end.sub(&self.start).signum();
+        let start = self.start;
+        (0..=self.len()).map(move |i| start.add(&Point::new(step.x * i, step.y * i)))
+    }
+}
+
+impl From<(Point, Point)> for Segment {
+    fn from((start, end): (Point, Point)) -> Self {
+        Segment::new(start, end)
+    }
+}
+
+/// Turns consecutive corners of a path into segments.
+pub fn path(corners: &[Point]) -> Vec<Segment> {
+    corners
+        .windows(2)
+        .map(|pair| Segment::new(pair[0], pair[1]))
+        .collect()
+}
+
+/// Finds every point where two paths cross, in the order they are reached along `first`.
+/// Collinear overlaps contribute every point they share. Points may repeat when either path
+/// crosses itself.
+pub fn crossings(first: &[Segment], second: &[Segment]) -> Vec<Point> {
+    let mut result = Vec::new();
+
+    for a in first {
+        let mut points: Vec<Point> = second
+            .iter()
+            .filter_map(|b| a.intersection(b))
+            .flat_map(|overlap| overlap.points())
+            .collect();
+        points.sort_by_key(|point| a.start.manhattan(point));
+        result.extend(points);
+    }
+
+    result
+}
+
+/// Distance along a path to the first time it reaches `point`, counting steps from the start of
+/// the path rather than in a straight line.
+///
+/// # Returns
+/// * `None` if the path never reaches the point.
+pub fn steps_along(path: &[Segment], point: &Point) -> Option<i32> {
+    let mut walked = 0;
+
+    for segment in path {
+        if let Some(steps) = segment.steps_to(point) {
+            return Some(walked + steps);
+        }
+        walked += segment.len();
+    }
+
+    None
+}
+
+/// The crossing closest to `origin` by Manhattan distance, ignoring the origin itself.
+///
+/// # Returns
+/// * `(point, distance)` of the closest crossing, or `None` if the paths never cross.
+pub fn closest_crossing(
+    first: &[Segment],
+    second: &[Segment],
+    origin: &Point,
+) -> Option<(Point, i32)> {
+    crossings(first, second)
+        .into_iter()
+        .filter(|point| point != origin)
+        .map(|point| (point, point.manhattan(origin)))
+        .min_by_key(|&(_, distance)| distance)
+}
+
+/// The crossing reached with the fewest combined steps along both paths, ignoring `origin`.
+///
+/// # Returns
+/// * `(point, steps)` of the earliest crossing, or `None` if the paths never cross.
+pub fn earliest_crossing(
+    first: &[Segment],
+    second: &[Segment],
+    origin: &Point,
+) -> Option<(Point, i32)> {
+    crossings(first, second)
+        .into_iter()
+        .filter(|point| point != origin)
+        .filter_map(|point| {
+            let steps = steps_along(first, &point)? + steps_along(second, &point)?;
+            Some((point, steps))
+        })
+        .min_by_key(|&(_, steps)| steps)
+}
+
+/// The quadrant of a direction, counted clockwise from straight up with `y` growing downwards.
+///
+/// Each quadrant includes its starting axis, so up is `0`, right `1`, down `2` and left `3`.
+/// `Point::EMPTY` has no direction and is put in quadrant `0`.
+pub fn quadrant(delta: &Point) -> u8 {
+    match (delta.x, delta.y) {
+        (x, y) if x >= 0 && y < 0 => 0,
+        (x, y) if x > 0 && y >= 0 => 1,
+        (x, y) if x <= 0 && y > 0 => 2,
+        (x, _) if x < 0 => 3,
+        _ => 0,
+    }
+}
+
+/// Compares two points by their clockwise angle around `origin`, starting straight up.
+///
+/// Points in the same direction are ordered by distance from `origin`, nearest first.
+pub fn compare_angle(a: &Point, b: &Point, origin: &Point) -> Ordering {
+    let (da, db) = (a.sub(origin), b.sub(origin));
+    let cross = da.x as i64 * db.y as i64 - da.y as i64 * db.x as i64;
+
+    quadrant(&da)
+        .cmp(&quadrant(&db))
+        .then(0.cmp(&cross))
+        .then(a.manhattan(origin).cmp(&b.manhattan(origin)))
+}
+
+/// Sorts points clockwise around `origin`, starting straight up, like a rotating laser.
+///
+/// # Arguments
+/// * `points` - The points to sort in place.
+/// * `origin` - The centre of the sweep.
+pub fn sort_by_angle(points: &mut [Point], origin: &Point) {
+    points.sort_by(|a, b| compare_angle(a, b, origin));
+}
+
+/// A 3D box including both corners, with `min <= max` on every axis.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Cuboid {
+    pub min: Point3,
+    pub max: Point3,
+}
+
+impl Cuboid {
+    /// Creates the box spanned by two opposite corners given in any order.
+    pub fn new(a: Point3, b: Point3) -> Self {
+        Self {
+            min: a.min(&b),
+            max: a.max(&b),
+        }
+    }
+
+    pub fn contains(&self, point: &Point3) -> bool {
+        self.min.max(point) == *point && self.max.min(point) == *point
+    }
+
+    /// Checks if the boxes share at least one point.
+    pub fn overlaps(&self, other: &Cuboid) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Returns the box of points contained in both, if any.
+    pub fn intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        let min = self.min.max(&other.min);
+        let max = self.max.min(&other.max);
+        (min.x <= max.x && min.y <= max.y && min.z <= max.z).then_some(Cuboid { min, max })
+    }
+
+    /// The number of integer points inside the box.
+    pub fn volume(&self) -> i64 {
+        let size = self.max.sub(&self.min);
+        (size.x as i64 + 1) * (size.y as i64 + 1) * (size.z as i64 + 1)
+    }
+
+    /// Manhattan distance from a point to the nearest point of the box, zero if inside.
+    pub fn distance_to(&self, point: &Point3) -> i32 {
+        let nearest = point.max(&self.min).min(&self.max);
+        nearest.manhattan(point)
+    }
+
+    /// Splits the box into up to eight octants by halving every axis longer than one.
+    pub fn split(&self) -> Vec<Cuboid> {
+        let halves = |min: i32, max: i32| {
+            if min == max {
+                vec![(min, max)]
+            } else {
+                let mid = min + (max - min) / 2;
+                vec![(min, mid), (mid + 1, max)]
+            }
+        };
+        let mut result = Vec::with_capacity(8);
+
+        for &(x1, x2) in &halves(self.min.x, self.max.x) {
+            for &(y1, y2) in &halves(self.min.y, self.max.y) {
+                for &(z1, z2) in &halves(self.min.z, self.max.z) {
+                    result.push(Cuboid::new(
+                        Point3::new(x1, y1, z1),
+                        Point3::new(x2, y2, z2),
+                    ));
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Moves the box by an offset.
+    pub fn translate(&self, offset: &Point3) -> Cuboid {
+        Cuboid {
+            min: self.min.add(offset),
+            max: self.max.add(offset),
+        }
+    }
+}
+
+/// An `N` dimensional box, half open on every axis: `min[i] <= x[i] < max[i]`.
+///
+/// Half open bounds make splitting exact, as the pieces of a box share no points and their
+/// volumes add up without off by one corrections.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Hyperbox<const N: usize> {
+    pub min: [i64; N],
+    pub max: [i64; N],
+}
+
+impl<const N: usize> Hyperbox<N> {
+    pub fn new(min: [i64; N], max: [i64; N]) -> Self {
+        Self { min, max }
+    }
+
+    /// Creates a box from inclusive bounds, as most puzzle inputs give them.
+    pub fn inclusive(min: [i64; N], max: [i64; N]) -> Self {
+        Self {
+            min,
+            max: max.map(|m| m + 1),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        (0..N).any(|i| self.min[i] >= self.max[i])
+    }
+
+    /// The number of integer points inside the box.
+    pub fn volume(&self) -> i64 {
+        (0..N).map(|i| (self.max[i] - self.min[i]).max(0)).product()
+    }
+
+    pub fn contains(&self, point: &[i64; N]) -> bool {
+        (0..N).all(|i| self.min[i] <= point[i] && point[i] < self.max[i])
+    }
+
+    /// Returns the box of points contained in both, if any.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let result = Self {
+            min: std::array::from_fn(|i| self.min[i].max(other.min[i])),
+            max: std::array::from_fn(|i| self.max[i].min(other.max[i])),
+        };
+        (!result.is_empty()).then_some(result)
+    }
+
+    /// Removes `other` from this box.
+    ///
+    /// # Returns
+    /// * At most `2 * N` disjoint boxes covering exactly the points of `self` not in `other`.
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        let Some(overlap) = self.intersection(other) else {
+            return vec![*self];
+        };
+        let mut result = Vec::new();
+        let mut rest = *self;
+
+        // Peel off the slabs below and above the overlap one axis at a time.
+        for axis in 0..N {
+            if rest.min[axis] < overlap.min[axis] {
+                let mut below = rest;
+                below.max[axis] = overlap.min[axis];
+                result.push(below);
+            }
+            if overlap.max[axis] < rest.max[axis] {
+                let mut above = rest;
+                above.min[axis] = overlap.max[axis];
+                result.push(above);
+            }
+            rest.min[axis] = overlap.min[axis];
+            rest.max[axis] = overlap.max[axis];
+        }
+
+        result
+    }
+}
+
+/// Applies a sequence of steps turning every point in a box on or off, starting all off.
+///
+/// Keeps a list of disjoint boxes that are on, subtracting each new box from all of them and
+/// adding it back when turning on.
+///
+/// # Returns
+/// * The number of points that are on at the end.
+pub fn switch_boxes<const N: usize>(steps: &[(bool, Hyperbox<N>)]) -> i64 {
+    let mut on: Vec<Hyperbox<N>> = Vec::new();
+
+    for (turn_on, cuboid) in steps {
+        on = on
+            .iter()
+            .flat_map(|existing| existing.subtract(cuboid))
+            .collect();
+        if *turn_on {
+            on.push(*cuboid);
+        }
+    }
+
+    on.iter().map(Hyperbox::volume).sum()
+}
@@ -0,0 +1,138 @@
+//! Directed acyclic graph algorithms.
+//!
+//! Nodes are dense indices `0..len`, which keeps lookups to a plain vector access. Puzzles with
+//! named nodes (letters, labels) map names to indices while parsing. All algorithms process nodes
+//! in topological order, computed with [Kahn's algorithm], so each runs in `O(V + E)`.
+//!
+//! Ties in the topological order are broken by the smallest index first. This makes the order
+//! deterministic and matches dependency puzzles that require alphabetical ordering of steps
+//! that become available at the same time.
+//!
+//! [Kahn's algorithm]: https://en.wikipedia.org/wiki/Topological_sorting#Kahn's_algorithm
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// A directed graph with weighted edges, expected to be acyclic.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Dag {
+    edges: Vec<Vec<(usize, i64)>>,
+}
+
+impl Dag {
+    /// Creates a graph with `len` nodes and no edges.
+    pub fn new(len: usize) -> Self {
+        Self {
+            edges: vec![Vec::new(); len],
+        }
+    }
+
+    /// Adds an edge, growing the graph if either node does not exist yet.
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: i64) {
+        let len = from.max(to) + 1;
+        if self.edges.len() < len {
+            self.edges.resize(len, Vec::new());
+        }
+        self.edges[from].push((to, weight));
+    }
+
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+
+    /// Returns the outgoing edges of a node as `(to, weight)` pairs.
+    pub fn neighbours(&self, node: usize) -> &[(usize, i64)] {
+        &self.edges[node]
+    }
+
+    /// Returns the number of incoming edges of every node.
+    pub fn in_degrees(&self) -> Vec<usize> {
+        let mut degrees = vec![0; self.len()];
+        self.edges
+            .iter()
+            .flatten()
+            .for_each(|&(to, _)| degrees[to] += 1);
+        degrees
+    }
+
+    /// Orders the nodes so that every edge points forward, smallest index first on ties.
+    ///
+    /// # Returns
+    /// * `Some(order)` containing every node, or `None` if the graph has a cycle.
+    pub fn topological_order(&self) -> Option<Vec<usize>> {
+        let mut degrees = self.in_degrees();
+        let mut ready: BinaryHeap<_> = (0..self.len())
+            .filter(|&node| degrees[node] == 0)
+            .map(Reverse)
+            .collect();
+        let mut order = Vec::with_capacity(self.len());
+
+        while let Some(Reverse(node)) = ready.pop() {
+            order.push(node);
+
+            for &(to, _) in &self.edges[node] {
+                degrees[to] -= 1;
+                if degrees[to] == 0 {
+                    ready.push(Reverse(to));
+                }
+            }
+        }
+
+        (order.len() == self.len()).then_some(order)
+    }
+
+    /// Computes the heaviest path from `start` to every node.
+    ///
+    /// # Returns
+    /// * A vector indexed by node with the total weight of the longest path, or `None` if the
+    ///   node is not reachable from `start`.
+    ///
+    /// # Panics
+    /// Panics if the graph has a cycle.
+    pub fn longest_paths(&self, start: usize) -> Vec<Option<i64>> {
+        let order = self.topological_order().expect("Graph has a cycle");
+        let mut distance = vec![None; self.len()];
+        distance[start] = Some(0);
+
+        for node in order {
+            let Some(current) = distance[node] else {
+                continue;
+            };
+            for &(to, weight) in &self.edges[node] {
+                let candidate = current + weight;
+                if distance[to].is_none_or(|d| d < candidate) {
+                    distance[to] = Some(candidate);
+                }
+            }
+        }
+
+        distance
+    }
+
+    /// Counts the distinct paths from `start` to `end`.
+    ///
+    /// Paths are counted with dynamic programming over the topological order, which is the
+    /// iterative equivalent of memoized recursion.
+    ///
+    /// # Panics
+    /// Panics if the graph has a cycle.
+    pub fn count_paths(&self, start: usize, end: usize) -> u64 {
+        let order = self.topological_order().expect("Graph has a cycle");
+        let mut ways = vec![0u64; self.len()];
+        ways[start] = 1;
+
+        for node in order {
+            if ways[node] == 0 {
+                continue;
+            }
+            for &(to, _) in &self.edges[node] {
+                ways[to] += ways[node];
+            }
+        }
+
+        ways[end]
+    }
+}
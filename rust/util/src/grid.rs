@@ -0,0 +1,509 @@
+use crate::ansi::*;
+use crate::conversions::FromChar;
+use crate::direction::Direction;
+use crate::point::Point;
+use std::error::Error;
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+/// A generic grid structure for managing data organized in a 2D grid format.
+///
+/// The `Grid<T>` struct represents a two-dimensional grid of items of type `T`. It provides
+/// essential functionalities for grid-based operations such as calculating starting
+/// points and checking boundary limits for grid traversal.
+///
+/// # Type Parameters
+/// - `T`: The type of elements contained within the grid.
+///
+/// # Derivable Traits
+/// - `Clone`: Allows creating a duplicate of the grid.
+/// - `PartialEq`, `Eq`: Supports equality comparisons between grids.
+/// - `Hash`: Allows the grid to be used in hashed collections like `HashMap`.
+/// - `Debug`: Enables formatting for debugging.
+///
+/// # Fields
+/// - `width`: The number of columns in the grid. This defines the horizontal size.
+/// - `height`: The number of rows in the grid, dynamically determined by the number of elements.
+/// - `data`: A private vector containing the grid's elements row after row, managed in a
+///   single contiguous memory block. The cell `(x, y)` is at [`index`](Grid::index)
+///   `y * width + x` of [`cells`](Grid::cells), while [`rows`](Grid::rows) and
+///   [`row`](Grid::row) read it row by row.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grid<T> {
+    pub width: i32,
+    pub height: i32,
+    data: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// The cells in reading order, so the cell of `point` is at [`index`](Grid::index).
+    pub fn cells(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// The position in [`cells`](Grid::cells) of a point inside the grid.
+    ///
+    /// Accessors taking points check [`contains`](Grid::contains) first, so the bounds are only
+    /// asserted again in debug builds.
+    ///
+    /// # Panics
+    /// Panics in debug builds if the point is outside the grid, rather than wrapping into a
+    /// neighbouring row.
+    #[inline]
+    pub fn index(&self, point: &Point) -> usize {
+        debug_assert!(
+            0 <= point.x && point.x < self.width && 0 <= point.y && point.y < self.height,
+            "Point {point:?} outside the {}x{} grid",
+            self.width,
+            self.height
+        );
+        (point.y * self.width + point.x) as usize
+    }
+
+    /// The point at a position in [`cells`](Grid::cells), the inverse of [`index`](Grid::index).
+    #[inline]
+    pub fn point(&self, index: usize) -> Point {
+        let width = self.width as usize;
+        Point::new((index % width) as i32, (index / width) as i32)
+    }
+
+    /// The change of index when moving one cell in a direction, `dx + dy * width`.
+    #[inline]
+    pub fn stride(&self, direction: Direction) -> isize {
+        let delta = Point::from(direction);
+        (delta.x + delta.y * self.width) as isize
+    }
+
+    /// Moves an index one cell in a direction, for hot loops that avoid point arithmetic.
+    ///
+    /// Stepping off the left or right edge does not leave the cells but wraps into the neighbouring
+    /// row, so callers must know the step stays inside, for example from a border of walls.
+    ///
+    /// # Panics
+    /// Panics in debug builds if the step leaves the grid or wraps around a row.
+    #[inline]
+    pub fn step(&self, index: usize, direction: Direction) -> usize {
+        debug_assert!(
+            index < self.data.len() && {
+                let delta = Point::from(direction);
+                let point = self.point(index);
+                let moved = Point::new(point.x + delta.x, point.y + delta.y);
+                0 <= moved.x && moved.x < self.width && 0 <= moved.y && moved.y < self.height
+            },
+            "Step {direction:?} from index {index} leaves the {}x{} grid",
+            self.width,
+            self.height
+        );
+        index.wrapping_add_signed(self.stride(direction))
+    }
+
+    /// Iterates over the rows of the grid from top to bottom, each as a slice of `width` cells.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.data
+            .chunks(self.width.max(1) as usize)
+            .take(self.height as usize)
+    }
+
+    /// Returns a row of the grid.
+    ///
+    /// # Panics
+    /// Panics if the row is outside the grid.
+    pub fn row(&self, y: i32) -> &[T] {
+        assert!(0 <= y && y < self.height, "Row {y} outside the grid");
+        let start = (y * self.width) as usize;
+        &self.data[start..start + self.width as usize]
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Default + Clone + Debug + PartialEq,
+    T: FromStr + FromChar,
+    <T as FromStr>::Err: Debug,
+    <T as FromChar>::Err: Debug,
+{
+    /// Creates a grid from its rows, which must all be `width` cells long.
+    ///
+    /// # Panics
+    /// Panics if the rows do not hold `width` cells each.
+    pub fn new(data: Vec<Vec<T>>, width: i32) -> Self {
+        let height = data.len() as i32;
+        let data: Vec<T> = data.into_iter().flatten().collect();
+        assert_eq!(
+            data.len(),
+            (width * height) as usize,
+            "Rows of unequal width"
+        );
+        Self {
+            width,
+            height,
+            data,
+        }
+    }
+
+    /// Creates a grid from its cells in reading order.
+    ///
+    /// # Panics
+    /// Panics if the number of cells is not a multiple of `width`.
+    pub fn from_cells(data: Vec<T>, width: i32) -> Self {
+        let height = if width > 0 {
+            data.len() as i32 / width
+        } else {
+            0
+        };
+        assert_eq!(
+            data.len(),
+            (width * height) as usize,
+            "{} cells do not fill rows of {width}",
+            data.len()
+        );
+        Self {
+            width,
+            height,
+            data,
+        }
+    }
+
+    /// Creates a `width` by `height` grid with every cell set to `value`.
+    pub fn filled(width: i32, height: i32, value: T) -> Self {
+        Self {
+            width,
+            height,
+            data: vec![value; (width * height) as usize],
+        }
+    }
+
+    /// Determines the starting point for a search based on the provided direction.
+    ///
+    /// This function calculates an initial point within a grid, determined by the
+    /// specified direction for the search operation. The starting point reflects
+    /// common patterns needed to navigate typical data structures or graphical grids
+    /// that employ directional traversal.
+    ///
+    /// # Directional Starting Points
+    /// - **Right**, **Down**, **RightDown**: Start from the **top-left** corner at `(0, 0)`.
+    /// - **Left**, **Up**, **LeftUp**: Start from the **bottom-right** corner, computed as `(width - 1, height - 1)`.
+    /// - **RightUp**: Start from the **bottom-left** corner at `(0, height - 1)`.
+    /// - **LeftDown**: Start from the **top-right** corner, computed as `(width - 1, 0)`.
+    ///
+    /// # Arguments
+    /// * `direction` - A reference to a `Direction` enum, indicating the intended search direction.
+    ///
+    /// # Returns
+    /// * A `Point` representing the calculated starting position in the form of `(x, y)`.
+    pub fn get_starting_point(&self, direction: &Direction) -> Point {
+        match direction {
+            Direction::Right | Direction::Down | Direction::RightDown => Point::new(0, 0),
+            Direction::Left | Direction::Up | Direction::LeftUp => {
+                Point::new(self.width - 1, self.height - 1)
+            }
+            Direction::RightUp => Point::new(0, self.height - 1),
+            Direction::LeftDown => Point::new(self.width - 1, 0),
+            _ => Point::new(0, 0), // Default case
+        }
+    }
+
+    /// Determines if the point a given distance away in a direction lies outside of the grid.
+    ///
+    /// This function checks whether moving `distance` cells from a given `Point` in the specified
+    /// `Direction` leaves the grid. A distance of `1` checks the neighbour, while checking if a
+    /// chunk of `n` cells starting at the point fits takes a distance of `n - 1`. This is useful
+    /// for validating movements, ensuring that operations do not attempt to access grid locations
+    /// that do not exist.
+    ///
+    /// # Boundary Conditions
+    /// Directions describe how to extend the current point, and this function calculates:
+    /// - **Right**: Extending beyond the right edge, where `current_point.x + distance >= self.width`.
+    /// - **Left**: Extending beyond the left edge, where `current_point.x - distance < 0`.
+    /// - **Up**: Extending beyond the top edge, where `current_point.y - distance < 0`.
+    /// - **Down**: Extending beyond the bottom edge, where `current_point.y + distance >= self.height`.
+    /// - **RightUp**: Exceeding either the right edge or the top edge.
+    /// - **RightDown**: Exceeding either the right edge or the bottom edge.
+    /// - **LeftUp**: Exceeding either the left edge or the top edge.
+    /// - **LeftDown**: Exceeding either the left edge or the bottom edge.
+    ///
+    /// # Arguments
+    /// * `current_point` - A reference to a `Point` representing the current position in the grid.
+    /// * `direction` - A reference to a `Direction` enum, indicating the direction of movement from the current point.
+    /// * `distance` - The number of cells to move from the current point along the direction.
+    ///
+    /// # Returns
+    /// * `true` if moving from `current_point` in the specified `direction` by `distance` results in exceeding the grid's boundaries.
+    /// * `false` otherwise.
+    ///
+    /// # Panics
+    /// Panics if given an invalid direction not covered by the defined `Direction` variants.
+    pub fn exced_bounds(
+        &self,
+        current_point: &Point,
+        direction: &Direction,
+        distance: i32,
+    ) -> bool {
+        let offset = distance;
+        match direction {
+            Direction::Right => current_point.x + offset >= self.width,
+            Direction::Left => current_point.x - offset < 0,
+            Direction::Up => current_point.y - offset < 0,
+            Direction::Down => current_point.y + offset >= self.height,
+            Direction::RightUp => {
+                current_point.x + offset >= self.width || current_point.y - offset < 0
+            }
+            Direction::RightDown => {
+                current_point.x + offset >= self.width || current_point.y + offset >= self.height
+            }
+            Direction::LeftUp => current_point.x - offset < 0 || current_point.y - offset < 0,
+            Direction::LeftDown => {
+                current_point.x - offset < 0 || current_point.y + offset >= self.height
+            }
+            _ => panic!("Invalid direction {:?}", direction),
+        }
+    }
+
+    /// Parses a string into a `Grid` struct.
+    ///
+    /// This function converts a string representation of grid data into a `Grid` object.
+    /// It can handle parsing individual characters or segments of strings separated by
+    /// a specified delimiter.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - A string slice containing the grid data.
+    /// * `delimiter` - An optional character used to split each line into segments. If `None`,
+    ///   each character is treated as a separate element.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self, Box<dyn Error>>` - A `Result` containing the parsed `Grid` or an error if
+    ///   parsing fails or if the input's width is inconsistent across lines.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the width of the grid is inconsistent across lines.
+    /// * Returns an error if the conversion from a character or string segment to `T` fails.
+    pub fn parse(input: &str, delimiter: Option<char>) -> Result<Self, Box<dyn Error>> {
+        let mut data: Vec<T> = Vec::new();
+        let mut width = None;
+
+        for line in input.lines() {
+            let elements: Vec<T> = if let Some(delim) = delimiter {
+                // Parse using delimiter
+                line.split(delim)
+                    .map(|s| {
+                        T::from_str(s).map_err(|e| format!("Conversion error: {:?}", e).into())
+                    })
+                    .collect::<Result<Vec<T>, Box<dyn Error>>>()? // Specify the error type
+            } else {
+                // Parse character by character
+                line.chars()
+                    .map(|c| {
+                        T::from_char(c).map_err(|e| format!("Conversion error: {:?}", e).into())
+                    })
+                    .collect::<Result<Vec<T>, Box<dyn Error>>>()? // Specify the error type
+            };
+
+            let tmp_width = elements.len() as i32;
+
+            if width.is_none() {
+                width = Some(tmp_width);
+            }
+
+            if tmp_width != width.unwrap() {
+                return Err("Invalid input. Width is not consistent".into());
+            }
+
+            data.extend(elements);
+        }
+
+        Ok(Grid::from_cells(data, width.unwrap()))
+    }
+
+    /// Retrieves the value at the specified point in the grid.
+    /// If the point is out of bounds, returns `None`.
+    /// If the value is the default value of `T`, returns `None`.
+    /// Otherwise, returns the value at the specified point.
+    ///
+    /// # Arguments
+    /// * `point` - A reference to a `Point` representing the position in the grid.
+    ///
+    /// # Returns
+    /// * An `Option<T>` containing the value at the specified point, or `None` if the point is out of bounds or the value is the default value of `T`.
+    pub fn get_value(&self, point: &Point) -> Option<T> {
+        if !self.contains(point) {
+            return None;
+        }
+        let val = self.data[self.index(point)].clone();
+        Some(val)
+    }
+
+    /// Sets the value at the specified point in the grid.
+    ///
+    /// # Arguments
+    /// * `point` - A reference to a `Point` representing the position in the grid.
+    /// * `value` - The value to set at the specified point.
+    ///
+    /// # Panics
+    /// Panics if the point is outside the grid.
+    pub fn set_value(&mut self, point: &Point, value: T) {
+        assert!(
+            self.contains(point),
+            "Point {point:?} outside the {}x{} grid",
+            self.width,
+            self.height
+        );
+        let index = self.index(point);
+        self.data[index] = value;
+    }
+
+    /// Creates new grid with the same size filled by predefined value.
+    ///
+    /// # Aeguments
+    /// * `value` - The value to set at all points
+    ///
+    pub fn same_size_with<U>(&self, value: U) -> Grid<U>
+    where
+        U: Default + Clone + Debug + PartialEq,
+        U: FromStr + FromChar,
+        <U as FromStr>::Err: Debug,
+        <U as FromChar>::Err: Debug,
+    {
+        Grid::filled(self.width, self.height, value)
+    }
+
+    /// Checks if the given point is within the grid boundaries.
+    ///
+    /// # Aeguments
+    /// * `point` - A reference to a `Point` representing the position in the grid.
+    ///
+    pub fn contains(&self, point: &Point) -> bool {
+        point.y >= 0 && point.x >= 0 && self.width > point.x && self.height > point.y
+    }
+
+    /// Returns the total number of elements in the grid.
+    pub fn get_size(&self) -> i32 {
+        self.width * self.height
+    }
+
+    /// Iterates over every point of the grid in reading order.
+    ///
+    /// Reading order runs left to right along each row, rows from top to bottom, so `(x, y)`
+    /// comes before `(x + 1, y)` and every point of row `y` before any point of row `y + 1`.
+    /// Puzzles that break ties by reading order can rely on this.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Iterates over every run of `N` consecutive values along a direction.
+    ///
+    /// Each item is the first point of a run with its values in the order of the direction, so
+    /// a word can be matched with a single array comparison. Only runs that fit in the grid
+    /// completely are yielded, in reading order of their first point.
+    ///
+    /// # Arguments
+    /// * `direction` - The direction each run extends in from its first point.
+    ///
+    /// # Returns
+    /// * An iterator of `(first_point, values)`, e.g. `width - N + 1` runs per row going `Right`.
+    ///
+    /// # Panics
+    /// Panics if `N` is zero or the direction is `Stop`.
+    pub fn chunks_along<const N: usize>(
+        &self,
+        direction: &Direction,
+    ) -> impl Iterator<Item = (Point, [T; N])> + '_ {
+        assert!(N > 0, "Chunks must not be empty");
+        let direction = *direction;
+        let step = Point::from(direction);
+
+        self.points()
+            .filter(move |point| !self.exced_bounds(point, &direction, N as i32 - 1))
+            .map(move |start| {
+                let values = std::array::from_fn(|i| {
+                    let point =
+                        Point::new(start.x + step.x * i as i32, start.y + step.y * i as i32);
+                    self.data[self.index(&point)].clone()
+                });
+                (start, values)
+            })
+    }
+
+    /// Renders the grid as text, one line per row.
+    pub fn render(&self) -> String
+    where
+        T: Display,
+    {
+        let mut result = String::new();
+
+        for row in self.rows() {
+            row.iter()
+                .for_each(|value| result.push_str(&value.to_string()));
+            result.push('\n');
+        }
+
+        result
+    }
+
+    /// Compares this grid cell by cell with another one.
+    ///
+    /// Only points contained in both grids are compared, so grids of different sizes can still
+    /// be diffed over their overlapping area.
+    ///
+    /// # Arguments
+    /// * `other` - The grid to compare against.
+    ///
+    /// # Returns
+    /// * A vector of `(point, self_value, other_value)` for every differing cell, in reading order.
+    pub fn diff(&self, other: &Grid<T>) -> Vec<(Point, T, T)> {
+        let mut result = Vec::new();
+
+        for y in 0..self.height.min(other.height) {
+            for x in 0..self.width.min(other.width) {
+                let point = Point::new(x, y);
+                let left = &self.data[self.index(&point)];
+                let right = &other.data[other.index(&point)];
+                if left != right {
+                    result.push((point, left.clone(), right.clone()));
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Renders `other` highlighting the cells that differ from this grid.
+    ///
+    /// Differing cells are printed in bold red, unchanged cells as is. Cells outside of this
+    /// grid are treated as changed.
+    ///
+    /// # Arguments
+    /// * `other` - The grid to render, usually the newer state.
+    ///
+    /// # Returns
+    /// * A string with one line per row, containing ANSI escape codes.
+    pub fn render_diff(&self, other: &Grid<T>) -> String
+    where
+        T: Display,
+    {
+        let mut result = String::new();
+
+        for y in 0..other.height {
+            for x in 0..other.width {
+                let point = Point::new(x, y);
+                let value = &other.data[other.index(&point)];
+
+                if self.get_value(&point).as_ref() == Some(value) {
+                    result.push_str(&value.to_string());
+                } else {
+                    result.push_str(&format!("{BOLD}{RED}{value}{RESET}"));
+                }
+            }
+            result.push('\n');
+        }
+
+        result
+    }
+}
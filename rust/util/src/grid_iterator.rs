@@ -1,7 +1,7 @@
-use crate::util::conversions::FromChar;
-use crate::util::direction::Direction;
-use crate::util::grid::Grid;
-use crate::util::point::Point;
+use crate::conversions::FromChar;
+use crate::direction::Direction;
+use crate::grid::Grid;
+use crate::point::Point;
 use std::fmt::Debug;
 use std::str::FromStr;
 
@@ -21,16 +21,56 @@ use std::str::FromStr;
 /// - `line_start`: The starting point for the current line of iteration.
 /// - `current`: The current position of the iterator within the grid.
 /// - `direction`: The direction in which the iterator is moving.
-/// - `offset`: The step size or distance used during iteration.
+/// - `chunk`: The number of cells, starting at each visited point, that must fit in the grid.
+/// - `stride`: The number of cells advanced along the direction by each step.
 /// - `can_change_axis`: Indicates whether the axis of movement can change (for diagonal movement).
 /// - `have_next`: Indicates if further elements remain in the iteration.
+///
+/// # Ordering
+/// With wrapping enabled and a chunk and stride of `1`, the order of the visited points depends
+/// on the direction:
+/// - **Right**: Reading order, the same as [`Grid::points`].
+/// - **Left**: Reverse reading order, from the bottom-right corner.
+/// - **Down**: Column by column from the left, each from top to bottom.
+/// - **Up**: Column by column from the right, each from bottom to top.
+/// - Diagonals: One diagonal line after another, as needed for word searches.
+///
+/// Code that depends on reading order should use `Direction::Right` or [`Grid::points`] directly.
+///
+/// # Chunks and strides
+/// The two only affect movement along the direction, moving to the next line is always a single
+/// cell:
+/// - A `chunk` of `n` visits only the points where `n` cells in the direction, the point itself
+///   included, fit in the grid. Word searches use it to visit every possible start of a word.
+/// - A `stride` of `n` visits every `n`th cell of each line, starting from the first one.
+///
+/// For example a chunk of `2` and a stride of `2` over a row of five cells visits the first and
+/// third cell. The fifth cell is on the stride, but a chunk starting there does not fit.
+///
+/// Internally both combine into the distance checked with [`Grid::exced_bounds`] before each
+/// step, `stride + chunk - 1`, the distance to the last cell of the next chunk.
 #[derive(PartialEq, Eq, Hash, Debug)]
 pub struct GridIterator<'i, T> {
     grid: &'i mut Grid<T>,
     line_start: Point,
     current: Point,
     direction: Direction,
-    offset: i32,
+    chunk: i32,
+    stride: i32,
+    can_change_axis: bool,
+    have_next: bool,
+}
+
+/// The traversal state of a [`GridIterator`], without the grid.
+///
+/// Taken with [`GridIterator::snapshot`] and put back with [`GridIterator::restore`], so a
+/// search can branch off the current position and backtrack afterwards. Changes made to the
+/// grid in the meantime are not undone.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Snapshot {
+    line_start: Point,
+    current: Point,
+    direction: Direction,
     can_change_axis: bool,
     have_next: bool,
 }
@@ -45,20 +85,20 @@ where
     /// Constructs a new `GridIterator`.
     ///
     /// Initializes a `GridIterator` for a given grid, allowing navigation in the specified
-    /// direction. The iterator is set up to use a given offset as a step size, making it possible
-    /// to traverse the grid in flexible patterns.
+    /// direction. Only points where a chunk of the given size fits are visited, see
+    /// [Chunks and strides](GridIterator#chunks-and-strides). The stride starts at `1`.
     ///
     /// # Arguments
     /// * `grid` - A reference to the `Grid` struct that contains the data to iterate over.
     /// * `direction` - A reference to the `Direction` enum indicating the iteration's direction.
-    /// * `offset` - An integer specifying the step size for each iteration movement.
+    /// * `chunk` - The number of cells from each visited point that must fit, `1` for every point.
     ///
     /// # Returns
     /// A newly constructed instance of `GridIterator`.
     ///
     /// # Panics
     /// Panics if the direction provided results in invalid initial configuration.
-    pub fn new(grid: &'i mut Grid<T>, direction: &Direction, offset: i32) -> Self {
+    pub fn new(grid: &'i mut Grid<T>, direction: &Direction, chunk: i32) -> Self {
         let can_change_axis = direction.is_diagonal();
         let starting_point = grid.get_starting_point(direction);
         Self {
@@ -66,12 +106,23 @@ where
             line_start: starting_point,
             current: starting_point,
             direction: *direction,
-            offset,
+            chunk,
+            stride: 1,
             can_change_axis,
             have_next: true,
         }
     }
 
+    /// Visits only every `stride`th cell along the direction.
+    ///
+    /// # Panics
+    /// Panics if `stride` is less than `1`.
+    pub fn with_stride(mut self, stride: i32) -> Self {
+        assert!(stride >= 1, "Invalid stride {stride}");
+        self.stride = stride;
+        self
+    }
+
     /// Advances the iterator to the next point.
     ///
     /// This skips to the next valid point in its trajectory across the grid. If there is no valid
@@ -85,12 +136,15 @@ where
     ///
     /// # Panics
     /// Panics if an invalid direction leads to unwarranted behavior. Ensure directions are correct to prevent this.
-    pub fn next<'n>(&mut self, wrap_enabled: bool) -> Point {
+    pub fn next(&mut self, wrap_enabled: bool) -> Point {
         if !self
             .grid
-            .exced_bounds(&self.current, &self.direction, self.offset)
+            .exced_bounds(&self.current, &self.direction, self.reach())
         {
-            self.current = self.current.add(&self.direction.to_point());
+            let step = self.direction.to_point();
+            self.current = self
+                .current
+                .add(&Point::new(step.x * self.stride, step.y * self.stride));
             return self.current;
         }
 
@@ -99,10 +153,10 @@ where
         }
 
         match self.direction {
-            Direction::Right => self.handle_one_direction(&Direction::Right, &Direction::Down),
-            Direction::Down => self.handle_one_direction(&Direction::Down, &Direction::Right),
-            Direction::Left => self.handle_one_direction(&Direction::Left, &Direction::Up),
-            Direction::Up => self.handle_one_direction(&Direction::Up, &Direction::Left),
+            Direction::Right => self.handle_one_direction(&Direction::Down),
+            Direction::Down => self.handle_one_direction(&Direction::Right),
+            Direction::Left => self.handle_one_direction(&Direction::Up),
+            Direction::Up => self.handle_one_direction(&Direction::Left),
             Direction::RightDown => {
                 self.handle_diagonal_direction(&Direction::Right, &Direction::Down)
             }
@@ -160,7 +214,7 @@ where
         self.can_change_axis = direction.is_diagonal();
         self.have_next = !self
             .grid
-            .exced_bounds(&self.current, &self.direction, self.offset);
+            .exced_bounds(&self.current, &self.direction, self.reach());
         self.line_start = self.current
     }
 
@@ -176,7 +230,7 @@ where
     ///   - `&Grid<T>`: A reference to the grid.
     ///   - `&mut Point`: A mutable reference to the current point in the grid, allowing modifications.
     ///   - `Point`: A point representing the direction derived from the `direction` parameter.
-    ///   - `i32`: An integer representing an offset, which can be used in calculations within the closure.
+    ///   - `i32`: The chunk size, the number of cells from the current point known to fit.
     ///
     /// * `direction` - The direction in which to iterate over the grid. This parameter
     ///   is used to determine the step size and direction of iteration.
@@ -195,8 +249,7 @@ where
     where
         F: Fn(&Grid<T>, &Point, &Point, &i32) -> bool,
     {
-        let original_position = self.current;
-        let original_direction = self.direction;
+        let snapshot = self.snapshot();
 
         let step = self.direction.to_point();
         let mut count = 0;
@@ -206,7 +259,7 @@ where
                 break;
             }
 
-            let is_valid = f(self.grid, &self.current, &step, &self.offset);
+            let is_valid = f(self.grid, &self.current, &step, &self.chunk);
 
             if is_valid {
                 count += 1;
@@ -215,7 +268,7 @@ where
             self.next(true);
         }
 
-        self.restore(&original_position, &original_direction);
+        self.restore(&snapshot);
 
         count
     }
@@ -231,8 +284,7 @@ where
     ///
     /// * i32 - The count of points that satisfy the condition specified by `f`.
     pub fn count(&mut self, value: &T) -> i32 {
-        let original_position = self.current;
-        let original_direction = self.direction;
+        let snapshot = self.snapshot();
 
         let mut count = 0;
 
@@ -250,7 +302,7 @@ where
             self.next(true);
         }
 
-        self.restore(&original_position, &original_direction);
+        self.restore(&snapshot);
 
         count
     }
@@ -265,9 +317,9 @@ where
     ///
     /// * `f` - A closure or function that defines the condition to be checked for each value.
     ///   It takes the following parameters:
-    ///   - `&Vec<T>`: A reference to a vector containing the values to find.
+    ///   - `&[T]`: A slice containing the values to find.
     ///   - `T`: The current value from the grid to be checked against the condition.
-    /// * `find` - A vector of values to search for within the grid.
+    /// * `find` - A slice of values to search for within the grid.
     ///
     /// # Returns
     ///
@@ -278,13 +330,12 @@ where
     ///
     /// * The function will panic if the current value in the grid cannot be unwrapped. Ensure
     ///   that the grid is properly initialized and contains valid values to prevent this panic.
-    pub fn find<F>(&mut self, f: F, find: &Vec<T>) -> Option<(T, Point)>
+    pub fn find<F>(&mut self, f: F, find: &[T]) -> Option<(T, Point)>
     where
-        F: Fn(&Vec<T>, T) -> bool,
+        F: Fn(&[T], T) -> bool,
         T: Clone, // Ensure T can be cloned
     {
-        let original_position = self.current;
-        let original_direction = self.direction;
+        let snapshot = self.snapshot();
         let mut result = None;
 
         loop {
@@ -302,10 +353,35 @@ where
             self.next(true);
         }
 
-        self.restore(&original_position, &original_direction);
+        self.restore(&snapshot);
         result // Return the owned value
     }
 
+    /// Captures the position, direction and line start of the iterator.
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            line_start: self.line_start,
+            current: self.current,
+            direction: self.direction,
+            can_change_axis: self.can_change_axis,
+            have_next: self.have_next,
+        }
+    }
+
+    /// Returns the iterator to the state captured by [`snapshot`](Self::snapshot).
+    pub fn restore(&mut self, snapshot: &Snapshot) {
+        self.line_start = snapshot.line_start;
+        self.current = snapshot.current;
+        self.direction = snapshot.direction;
+        self.can_change_axis = snapshot.can_change_axis;
+        self.have_next = snapshot.have_next;
+    }
+
+    /// Returns the grid being iterated over.
+    pub fn grid(&self) -> &Grid<T> {
+        self.grid
+    }
+
     /// Checks if there is a next point available in the iteration.
     ///
     /// # Returns
@@ -366,28 +442,19 @@ where
     pub fn can_move(&self, direction: &Direction) -> bool {
         !self
             .grid
-            .exced_bounds(&self.current, direction, self.offset)
+            .exced_bounds(&self.current, direction, self.reach())
     }
 
-    /// Determines the next point when moving in a linear direction.
+    /// Moves to the start of the next line when moving in a linear direction.
     ///
-    /// Alternates between primary and new line directions if the next step exceeds bounds.
+    /// Only called once the next step along the line exceeds the bounds.
     ///
     /// # Arguments
-    /// * `next_step` - The primary direction of movement.
-    /// * `new_line` - The fallback direction when primary movement exceeds bounds.
+    /// * `new_line` - The direction of the next line.
     ///
     /// # Returns
-    /// * The next point in the iteration.
-    fn handle_one_direction(&mut self, next_step: &Direction, new_line: &Direction) -> Point {
-        if !self
-            .grid
-            .exced_bounds(&self.current, next_step, self.offset + 1)
-        {
-            self.current = self.current.add(&next_step.to_point());
-            return self.current;
-        }
-
+    /// * The first point of the next line, or `Point::EMPTY` if there are no more lines.
+    fn handle_one_direction(&mut self, new_line: &Direction) -> Point {
         if self.grid.exced_bounds(&self.current, new_line, 1) {
             return self.brake();
         }
@@ -417,7 +484,7 @@ where
             if !self.grid.exced_bounds(
                 &next_point.add(&self.line_start),
                 next_step_direction,
-                self.offset,
+                self.chunk,
             ) {
                 self.line_start = self.line_start.add(&next_point);
                 self.current = self.line_start;
@@ -438,7 +505,7 @@ where
         if self.grid.exced_bounds(
             &new_line_point.add(&self.line_start),
             new_line_direction,
-            self.offset - 1,
+            self.chunk - 1,
         ) {
             return self.brake();
         }
@@ -447,13 +514,13 @@ where
         self.current
     }
 
-    fn brake<'b>(&mut self) -> Point {
-        self.have_next = false;
-        Point::EMPTY
+    /// Distance from the current point to the last cell of the chunk after the next step.
+    fn reach(&self) -> i32 {
+        self.stride + self.chunk - 1
     }
 
-    fn restore(&mut self, original_position: &Point, original_direction: &Direction) {
-        self.current = *original_position;
-        self.direction = *original_direction;
+    fn brake(&mut self) -> Point {
+        self.have_next = false;
+        Point::EMPTY
     }
 }
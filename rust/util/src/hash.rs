@@ -0,0 +1,359 @@
+//! Fast non-cryptographic hashing for integer keys.
+//!
+//! The standard library's SipHash protects against denial of service attacks, which is wasted
+//! effort for puzzle state spaces. This is the simple multiply and rotate hash used by the Rust
+//! compiler (FxHash), which is several times faster for small keys such as `u64` states and
+//! points. Use the [`FastMap`] and [`FastSet`] aliases together with [`FastMapBuilder`] helpers.
+//!
+//! Searches over millions of `u64` states can go further with [`U64Map`], which skips hashing
+//! through [`Hasher`] entirely.
+//!
+//! Hashes that are written to files or shown to people use [`fnv1a`] instead, a published
+//! algorithm that gives the same value on every machine and in every version of this crate.
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasherDefault, Hasher};
+
+pub type FastMap<K, V> = HashMap<K, V, BuildHasherDefault<FastHasher>>;
+pub type FastSet<K> = HashSet<K, BuildHasherDefault<FastHasher>>;
+
+/// Constructors mirroring `HashMap::new` and `HashMap::with_capacity` for the fast aliases.
+pub trait FastMapBuilder {
+    fn new() -> Self;
+    fn with_capacity(capacity: usize) -> Self;
+}
+
+impl<K, V> FastMapBuilder for FastMap<K, V> {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_hasher(capacity, BuildHasherDefault::default())
+    }
+}
+
+/// Constructors mirroring `HashSet::new` and `HashSet::with_capacity` for the fast alias.
+pub trait FastSetBuilder {
+    fn new() -> Self;
+    fn with_capacity(capacity: usize) -> Self;
+}
+
+impl<K> FastSetBuilder for FastSet<K> {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_hasher(capacity, BuildHasherDefault::default())
+    }
+}
+
+const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// The 64 bit FNV-1a hash of some bytes.
+///
+/// Processes one byte at a time, so it is much slower than [`FastHasher`] on long inputs, but
+/// its values are stable enough to be stored.
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(FNV_OFFSET, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+    })
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct FastHasher {
+    hash: u64,
+}
+
+impl FastHasher {
+    #[inline]
+    fn add(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(SEED);
+    }
+}
+
+impl Hasher for FastHasher {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            self.add(u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+        for &byte in chunks.remainder() {
+            self.add(byte as u64);
+        }
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.add(i as u64);
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.add(i as u64);
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.add(i as u64);
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.add(i);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.add(i as u64);
+    }
+
+    #[inline]
+    fn write_i32(&mut self, i: i32) {
+        self.add(i as u32 as u64);
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+/// Fraction of slots a [`U64Map`] fills before doubling, unless set with
+/// [`U64Map::with_load_factor`].
+pub const DEFAULT_LOAD_FACTOR: f64 = 0.75;
+/// Smallest number of slots of a [`U64Map`].
+const MIN_CAPACITY: usize = 8;
+/// 2⁶⁴ divided by the golden ratio, spreading consecutive keys over the whole table.
+const GOLDEN: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// Open addressing map specialised for `u64` keys, such as packed search states.
+///
+/// Entries live directly in a single power of two sized table. A key starts looking at the slot
+/// picked by the top bits of a Fibonacci hash and probes linearly from there, so a lookup is
+/// one multiply and usually one or two adjacent slots, with none of the hashing or control byte
+/// overhead of [`FastMap`]. Removal shifts later entries of the same run back instead of leaving
+/// tombstones, so lookups never slow down as the map churns.
+///
+/// The load factor trades memory for probe length. Linear probing degrades quickly when nearly
+/// full, so keep it between one half and seven eighths. Lower values help maps that mostly miss,
+/// like visited sets in searches. Growing rebuilds the whole table, so use
+/// [`with_capacity`](U64Map::with_capacity) when the number of states is roughly known.
+///
+/// # Fields
+/// - `slots`: The table, `None` for empty slots. Its length is a power of two.
+/// - `len`: Number of entries.
+/// - `limit`: Number of entries that makes the next insert double the table.
+/// - `load_factor`: Fraction of slots filled before growing, used to recompute `limit`.
+/// - `shift`: Hash bits to drop, leaving an index into `slots`.
+#[derive(Clone, Debug)]
+pub struct U64Map<V> {
+    slots: Vec<Option<(u64, V)>>,
+    len: usize,
+    limit: usize,
+    load_factor: f64,
+    shift: u32,
+}
+
+impl<V> Default for U64Map<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> U64Map<V> {
+    /// Creates an empty map with the default load factor.
+    pub fn new() -> Self {
+        Self::with_load_factor(0, DEFAULT_LOAD_FACTOR)
+    }
+
+    /// Creates an empty map holding at least `capacity` entries before it grows.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_load_factor(capacity, DEFAULT_LOAD_FACTOR)
+    }
+
+    /// Creates an empty map holding at least `capacity` entries before it grows, filling at most
+    /// `load_factor` of its slots.
+    ///
+    /// # Panics
+    /// Panics if `load_factor` is not strictly between zero and one.
+    pub fn with_load_factor(capacity: usize, load_factor: f64) -> Self {
+        assert!(
+            load_factor > 0.0 && load_factor < 1.0,
+            "Invalid load factor {load_factor}"
+        );
+
+        let slots = ((capacity as f64 / load_factor).ceil() as usize + 1)
+            .max(MIN_CAPACITY)
+            .next_power_of_two();
+        let mut map = U64Map {
+            slots: Vec::new(),
+            len: 0,
+            limit: 0,
+            load_factor,
+            shift: 0,
+        };
+        map.allocate(slots);
+        map
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of entries the map holds before it grows.
+    pub fn capacity(&self) -> usize {
+        self.limit
+    }
+
+    pub fn contains_key(&self, key: u64) -> bool {
+        self.find(key).is_ok()
+    }
+
+    pub fn get(&self, key: u64) -> Option<&V> {
+        let index = self.find(key).ok()?;
+        self.slots[index].as_ref().map(|(_, value)| value)
+    }
+
+    pub fn get_mut(&mut self, key: u64) -> Option<&mut V> {
+        let index = self.find(key).ok()?;
+        self.slots[index].as_mut().map(|(_, value)| value)
+    }
+
+    /// Inserts a value, returning the previous value of the key if there was one.
+    pub fn insert(&mut self, key: u64, value: V) -> Option<V> {
+        match self.find(key) {
+            Ok(index) => self.slots[index]
+                .as_mut()
+                .map(|(_, old)| std::mem::replace(old, value)),
+            Err(index) => {
+                self.occupy(index, key, value);
+                None
+            }
+        }
+    }
+
+    /// Returns the value of the key, inserting the result of `default` first if it is missing.
+    pub fn get_or_insert_with(&mut self, key: u64, default: impl FnOnce() -> V) -> &mut V {
+        let index = match self.find(key) {
+            Ok(index) => index,
+            Err(index) => self.occupy(index, key, default()),
+        };
+        self.slots[index]
+            .as_mut()
+            .map(|(_, value)| value)
+            .expect("Occupied slot")
+    }
+
+    /// Removes a key, returning its value if it was present.
+    pub fn remove(&mut self, key: u64) -> Option<V> {
+        let mut hole = self.find(key).ok()?;
+        let (_, value) = self.slots[hole].take()?;
+        self.len -= 1;
+
+        // Shift back every later entry of the run that may no longer be reachable through the
+        // hole. An entry can move when the hole lies between its home slot and its current slot.
+        let mask = self.slots.len() - 1;
+        let mut index = (hole + 1) & mask;
+        while let Some((next, _)) = &self.slots[index] {
+            let home = self.home(*next);
+            if index.wrapping_sub(home) & mask >= index.wrapping_sub(hole) & mask {
+                self.slots[hole] = self.slots[index].take();
+                hole = index;
+            }
+            index = (index + 1) & mask;
+        }
+
+        Some(value)
+    }
+
+    /// Removes every entry, keeping the allocated slots.
+    pub fn clear(&mut self) {
+        self.slots.iter_mut().for_each(|slot| *slot = None);
+        self.len = 0;
+    }
+
+    /// Visits every entry in table order, which depends on the keys and capacity.
+    pub fn iter(&self) -> impl Iterator<Item = (u64, &V)> {
+        self.slots
+            .iter()
+            .filter_map(|slot| slot.as_ref().map(|(key, value)| (*key, value)))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = u64> + '_ {
+        self.iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// The slot a key starts probing from.
+    #[inline]
+    fn home(&self, key: u64) -> usize {
+        (key.wrapping_mul(GOLDEN) >> self.shift) as usize
+    }
+
+    /// Finds the slot holding the key, or else the empty slot where it belongs.
+    #[inline]
+    fn find(&self, key: u64) -> Result<usize, usize> {
+        let mask = self.slots.len() - 1;
+        let mut index = self.home(key);
+
+        loop {
+            match &self.slots[index] {
+                Some((other, _)) if *other == key => return Ok(index),
+                Some(_) => index = (index + 1) & mask,
+                None => return Err(index),
+            }
+        }
+    }
+
+    /// Stores a new entry in the empty slot found for it, growing first if the map is full.
+    /// Returns the slot the entry ended up in.
+    fn occupy(&mut self, mut index: usize, key: u64, value: V) -> usize {
+        if self.len >= self.limit {
+            self.allocate(self.slots.len() * 2);
+            index = self.find(key).unwrap_err();
+        }
+        self.slots[index] = Some((key, value));
+        self.len += 1;
+        index
+    }
+
+    /// Replaces the table with `size` empty slots, moving over any existing entries.
+    fn allocate(&mut self, size: usize) {
+        let old = std::mem::replace(&mut self.slots, (0..size).map(|_| None).collect());
+        self.shift = u64::BITS - size.trailing_zeros();
+        // Always leave an empty slot so probing terminates.
+        self.limit = ((size as f64 * self.load_factor) as usize).clamp(1, size - 1);
+
+        for (key, value) in old.into_iter().flatten() {
+            let index = self.find(key).unwrap_err();
+            self.slots[index] = Some((key, value));
+        }
+    }
+}
+
+impl<V> FromIterator<(u64, V)> for U64Map<V> {
+    fn from_iter<I: IntoIterator<Item = (u64, V)>>(iter: I) -> Self {
+        let mut map = U64Map::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<V> Extend<(u64, V)> for U64Map<V> {
+    fn extend<I: IntoIterator<Item = (u64, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! Results computed on first use and shared afterwards.
+//!
+//! Each part of a puzzle receives the parsed input by reference, so work needed by both parts
+//! would normally be done twice or moved into `parse`, slowing down a part that does not need
+//! it. Instead `parse` can return a struct with [`Cached`] fields, filled in by whichever part
+//! asks first and reused by the other without cloning.
+//!
+//! ```
+//! use aoc_util::lazy::Cached;
+//!
+//! struct Input {
+//!     numbers: Vec<u32>,
+//!     sorted: Cached<Vec<u32>>,
+//! }
+//!
+//! impl Input {
+//!     fn sorted(&self) -> &[u32] {
+//!         self.sorted.get_or_compute(|| {
+//!             let mut sorted = self.numbers.clone();
+//!             sorted.sort_unstable();
+//!             sorted
+//!         })
+//!     }
+//! }
+//! ```
+use std::cell::OnceCell;
+use std::fmt;
+
+/// A value that is computed at most once.
+pub struct Cached<T> {
+    cell: OnceCell<T>,
+}
+
+impl<T> Cached<T> {
+    pub const fn new() -> Self {
+        Self {
+            cell: OnceCell::new(),
+        }
+    }
+
+    /// Returns the value, computing it with `f` if this is the first call.
+    pub fn get_or_compute(&self, f: impl FnOnce() -> T) -> &T {
+        self.cell.get_or_init(f)
+    }
+
+    /// Returns the value if it has already been computed.
+    pub fn get(&self) -> Option<&T> {
+        self.cell.get()
+    }
+
+    pub fn is_computed(&self) -> bool {
+        self.cell.get().is_some()
+    }
+}
+
+impl<T> Default for Cached<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for Cached<T> {
+    fn clone(&self) -> Self {
+        Self {
+            cell: self.cell.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Cached<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.cell.get() {
+            Some(value) => f.debug_tuple("Cached").field(value).finish(),
+            None => f.write_str("Cached(<pending>)"),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Utility library shared by the Advent of Code solutions.
+//!
+//! Lives in its own crate so the helpers can be built, tested and benchmarked without
+//! recompiling every solution. The `aoc` crate re-exports it as `aoc::util`.
+pub mod ansi;
+pub mod automaton_nd;
+pub mod base;
+pub mod bingo;
+pub mod bits;
+pub mod blockers;
+pub mod bricks;
+pub mod cards;
+pub mod chars;
+pub mod circuit;
+pub mod combat;
+pub mod config;
+pub mod conversions;
+pub mod crt;
+pub mod cycle;
+pub mod debugger;
+pub mod direction;
+pub mod falling;
+pub mod fixed_grid;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod grid_iterator;
+pub mod hash;
+pub mod integer;
+pub mod json;
+pub mod lazy;
+pub mod math;
+pub mod matrix;
+pub mod monkey;
+pub mod morton;
+pub mod octree;
+pub mod parse;
+pub mod pathfinding;
+pub mod perfect;
+pub mod point;
+pub mod point3;
+pub mod point_cloud;
+pub mod progress;
+pub mod rangemap;
+pub mod recurse;
+pub mod reduce;
+pub mod rng;
+pub mod rope;
+pub mod runs;
+pub mod scanner;
+pub mod schedule;
+pub mod sets;
+pub mod shortcut;
+pub mod state;
+pub mod thread;
+pub mod tilt;
+pub mod trie;
+pub mod turtle;
+pub mod viz;
+pub mod workflow;
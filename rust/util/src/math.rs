@@ -0,0 +1,41 @@
+//! Number theory helpers shared by the arithmetic utilities.
+use crate::integer::*;
+
+/// Greatest common divisor using Euclid's algorithm.
+///
+/// Arguments must not be negative. `gcd(0, n)` is `n`.
+pub fn gcd<T: Integer<T>>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple. Arguments must be positive.
+pub fn lcm<T: Integer<T>>(a: T, b: T) -> T {
+    a / gcd(a, b) * b
+}
+
+/// Sums the `length` consecutive integers starting at `start`, with the triangular number
+/// formula instead of a loop.
+///
+/// Either `length` or `2 * start + length - 1` is even, so that one is halved before multiplying
+/// and the product never exceeds the sum.
+pub fn range_sum<T: Integer<T>>(start: T, length: T) -> T {
+    if length == T::ZERO {
+        return T::ZERO;
+    }
+    let two = T::from(2);
+    let ends = start + start + length - T::ONE;
+    if length % two == T::ZERO {
+        length / two * ends
+    } else {
+        ends / two * length
+    }
+}
+
+/// Sums `value * position` over a run of `length` positions starting at `start`, such as a file
+/// of blocks with the same id on a disk.
+pub fn run_checksum<T: Integer<T>>(value: T, start: T, length: T) -> T {
+    value * range_sum(start, length)
+}
@@ -0,0 +1,223 @@
+//! Exact solutions of small linear systems using rational arithmetic.
+//!
+//! Problems like finding the rock that hits every hailstone reduce to a handful of linear
+//! equations with very large integer coefficients. Floating point loses precision at that
+//! scale, so Gaussian elimination is performed on fractions of `i128`, reduced by their
+//! greatest common divisor after every operation. Overflow is detected and reported instead of
+//! producing a wrong answer.
+use crate::math::gcd;
+use std::fmt;
+
+/// A fraction in lowest terms with a positive denominator.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Rational {
+    pub num: i128,
+    pub den: i128,
+}
+
+/// Reasons a system has no single solution.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SolveError {
+    /// Some equations contradict each other.
+    Inconsistent,
+    /// The equations are dependent, leaving infinitely many solutions.
+    Underdetermined,
+    /// An intermediate value did not fit in `i128`.
+    Overflow,
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Inconsistent => write!(f, "System has no solution"),
+            SolveError::Underdetermined => write!(f, "System has infinitely many solutions"),
+            SolveError::Overflow => write!(f, "Arithmetic overflow"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { num: 0, den: 1 };
+
+    /// # Panics
+    /// Panics if `den` is zero.
+    pub fn new(num: i128, den: i128) -> Self {
+        assert!(den != 0, "Zero denominator");
+        let divisor = gcd(num.abs(), den.abs()) * den.signum();
+        Rational {
+            num: num / divisor,
+            den: den / divisor,
+        }
+    }
+
+    pub fn integer(n: i128) -> Self {
+        Rational { num: n, den: 1 }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.num == 0
+    }
+
+    /// Returns the value if it is a whole number.
+    pub fn to_integer(&self) -> Option<i128> {
+        (self.den == 1).then_some(self.num)
+    }
+
+    pub fn add(&self, other: &Rational) -> Option<Rational> {
+        let divisor = gcd(self.den, other.den);
+        let left = self.num.checked_mul(other.den / divisor)?;
+        let right = other.num.checked_mul(self.den / divisor)?;
+        let den = (self.den / divisor).checked_mul(other.den)?;
+        Some(Rational::new(left.checked_add(right)?, den))
+    }
+
+    pub fn sub(&self, other: &Rational) -> Option<Rational> {
+        self.add(&Rational {
+            num: other.num.checked_neg()?,
+            den: other.den,
+        })
+    }
+
+    pub fn mul(&self, other: &Rational) -> Option<Rational> {
+        // Cancel across before multiplying to keep intermediate values small.
+        let a = gcd(self.num.abs(), other.den).max(1);
+        let b = gcd(other.num.abs(), self.den).max(1);
+        let num = (self.num / a).checked_mul(other.num / b)?;
+        let den = (self.den / b).checked_mul(other.den / a)?;
+        Some(Rational::new(num, den))
+    }
+
+    /// # Returns
+    /// * `None` on overflow or division by zero.
+    pub fn div(&self, other: &Rational) -> Option<Rational> {
+        if other.is_zero() {
+            return None;
+        }
+        let inverse = Rational::new(other.den, other.num);
+        self.mul(&inverse)
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+/// Solves `a * x = b` with Gaussian elimination.
+///
+/// # Arguments
+/// * `a` - Coefficients, one row per equation. There may be more equations than unknowns, as
+///   long as they agree.
+/// * `b` - Right hand side, one value per equation.
+///
+/// # Returns
+/// * The unique solution, one value per column of `a`.
+pub fn solve(a: &[Vec<i128>], b: &[i128]) -> Result<Vec<Rational>, SolveError> {
+    let unknowns = a.first().map_or(0, Vec::len);
+    let mut rows: Vec<Vec<Rational>> = a
+        .iter()
+        .zip(b)
+        .map(|(row, &rhs)| {
+            row.iter()
+                .chain(std::iter::once(&rhs))
+                .map(|&n| Rational::integer(n))
+                .collect()
+        })
+        .collect();
+    let overflow = SolveError::Overflow;
+
+    let mut rank = 0;
+    for column in 0..unknowns {
+        let Some(pivot) = (rank..rows.len()).find(|&r| !rows[r][column].is_zero()) else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+        let pivot = rows[rank].clone();
+
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r == rank || row[column].is_zero() {
+                continue;
+            }
+            let factor = row[column].div(&pivot[column]).ok_or(overflow)?;
+            for (cell, above) in row[column..].iter_mut().zip(&pivot[column..]) {
+                let scaled = above.mul(&factor).ok_or(overflow)?;
+                *cell = cell.sub(&scaled).ok_or(overflow)?;
+            }
+        }
+        rank += 1;
+    }
+
+    // Rows below the rank have all coefficients eliminated, so a non zero right hand side
+    // means the equations disagree.
+    if rows[rank..].iter().any(|row| !row[unknowns].is_zero()) {
+        return Err(SolveError::Inconsistent);
+    }
+    if rank < unknowns {
+        return Err(SolveError::Underdetermined);
+    }
+
+    rows[..unknowns]
+        .iter()
+        .enumerate()
+        .map(|(i, row)| row[unknowns].div(&row[i]).ok_or(overflow))
+        .collect()
+}
+
+/// Finds the position and velocity of a rock thrown so that it hits every hailstone.
+///
+/// A rock at `p` with velocity `v` hits hailstone `i` when `(p - p_i) × (v - v_i) = 0`. The
+/// non linear `p × v` term is the same for every hailstone, so subtracting the equations of two
+/// hailstones leaves three linear equations. The first three hailstones give six equations for
+/// the six unknowns.
+///
+/// # Arguments
+/// * `hailstones` - At least three `(position, velocity)` pairs.
+///
+/// # Returns
+/// * The rock's `(position, velocity)`, or an error if it is not uniquely determined by whole
+///   numbers.
+pub fn rock_throw(hailstones: &[([i64; 3], [i64; 3])]) -> Result<([i64; 3], [i64; 3]), SolveError> {
+    if hailstones.len() < 3 {
+        return Err(SolveError::Underdetermined);
+    }
+    let widen = |v: [i64; 3]| v.map(i128::from);
+    let cross = |a: [i128; 3], b: [i128; 3]| {
+        [
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ]
+    };
+    let (p0, v0) = (widen(hailstones[0].0), widen(hailstones[0].1));
+
+    let mut a = Vec::new();
+    let mut b = Vec::new();
+    for &(position, velocity) in &hailstones[1..3] {
+        let (pj, vj) = (widen(position), widen(velocity));
+        let w = [vj[0] - v0[0], vj[1] - v0[1], vj[2] - v0[2]];
+        let u = [pj[0] - p0[0], pj[1] - p0[1], pj[2] - p0[2]];
+        let rhs1 = cross(pj, vj);
+        let rhs0 = cross(p0, v0);
+
+        a.push(vec![0, w[2], -w[1], 0, -u[2], u[1]]);
+        a.push(vec![-w[2], 0, w[0], u[2], 0, -u[0]]);
+        a.push(vec![w[1], -w[0], 0, -u[1], u[0], 0]);
+        b.extend((0..3).map(|i| rhs1[i] - rhs0[i]));
+    }
+
+    let solution = solve(&a, &b)?;
+    let mut whole = solution
+        .iter()
+        .map(|r| r.to_integer().and_then(|n| i64::try_from(n).ok()));
+    let mut next = || whole.next().flatten().ok_or(SolveError::Inconsistent);
+    let position = [next()?, next()?, next()?];
+    let velocity = [next()?, next()?, next()?];
+    Ok((position, velocity))
+}
@@ -0,0 +1,128 @@
+//! Items passed between monkeys, each applying an operation and a divisibility test.
+//!
+//! Worry levels grow without bound when they are multiplied or squared every round. Every test
+//! only asks whether a level is divisible by some divisor, and that answer does not change if
+//! the level is reduced modulo the least common multiple of all divisors. Levels are reduced
+//! after every operation unless a relief step divides them, which would not commute with the
+//! reduction but keeps them small anyway.
+use crate::math::lcm;
+use crate::parse::*;
+
+/// How a monkey changes the worry level of an item it inspects.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Square,
+}
+
+impl Operation {
+    pub fn apply(&self, level: u64) -> u64 {
+        match self {
+            Operation::Add(n) => level + n,
+            Operation::Multiply(n) => level * n,
+            Operation::Square => level * level,
+        }
+    }
+}
+
+/// What happens to the worry level after each inspection.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Relief {
+    /// The level is divided, rounding down.
+    Divide(u64),
+    /// No relief, so levels are kept bounded modulo the common multiple of the divisors.
+    None,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Monkey {
+    pub items: Vec<u64>,
+    pub operation: Operation,
+    pub divisor: u64,
+    /// Receivers when the level is, or is not, divisible by `divisor`.
+    pub if_true: usize,
+    pub if_false: usize,
+}
+
+/// Parses monkey descriptions separated by blank lines.
+///
+/// # Panics
+/// Panics if a block does not describe a monkey.
+pub fn parse(input: &str) -> Vec<Monkey> {
+    input
+        .split("\n\n")
+        .filter(|block| !block.trim().is_empty())
+        .map(|block| {
+            let lines: Vec<&str> = block.lines().map(str::trim).collect();
+            let number = |line: &str| line.unsigned::<u64>();
+            let operation = lines[2].rsplit("= old ").next().unwrap_or_default();
+
+            Monkey {
+                items: lines[1].iter_unsigned().collect(),
+                operation: match operation.split_once(' ') {
+                    Some(("*", "old")) => Operation::Square,
+                    Some(("*", n)) => Operation::Multiply(number(n)),
+                    Some(("+", n)) => Operation::Add(number(n)),
+                    _ => panic!("Unknown operation \"{}\"", lines[2]),
+                },
+                divisor: number(lines[3]),
+                if_true: number(lines[4]) as usize,
+                if_false: number(lines[5]) as usize,
+            }
+        })
+        .collect()
+}
+
+/// Monkeys together with how many items each has inspected.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Troop {
+    pub monkeys: Vec<Monkey>,
+    pub inspections: Vec<u64>,
+    modulus: u64,
+}
+
+impl Troop {
+    pub fn new(monkeys: Vec<Monkey>) -> Self {
+        let modulus = monkeys.iter().map(|monkey| monkey.divisor).fold(1, lcm);
+        let inspections = vec![0; monkeys.len()];
+        Self {
+            monkeys,
+            inspections,
+            modulus,
+        }
+    }
+
+    /// Lets every monkey in turn inspect and throw all of its items.
+    pub fn round(&mut self, relief: Relief) {
+        for index in 0..self.monkeys.len() {
+            let items = std::mem::take(&mut self.monkeys[index].items);
+            self.inspections[index] += items.len() as u64;
+
+            for item in items {
+                let monkey = &self.monkeys[index];
+                let level = match relief {
+                    Relief::Divide(n) => monkey.operation.apply(item) / n,
+                    Relief::None => monkey.operation.apply(item) % self.modulus,
+                };
+                let target = if level % monkey.divisor == 0 {
+                    monkey.if_true
+                } else {
+                    monkey.if_false
+                };
+                self.monkeys[target].items.push(level);
+            }
+        }
+    }
+
+    pub fn rounds(&mut self, rounds: usize, relief: Relief) {
+        (0..rounds).for_each(|_| self.round(relief));
+    }
+
+    /// The product of the two highest inspection counts.
+    pub fn monkey_business(&self) -> u64 {
+        let mut counts = self.inspections.clone();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        counts.iter().take(2).product()
+    }
+}
@@ -0,0 +1,121 @@
+//! Grid stored in Z-order, so cells that are close in 2D are usually close in memory.
+//!
+//! Row-major storage keeps horizontal neighbours together, but the cells above and below are a
+//! whole row apart. On grids much larger than the cache, algorithms that spread out in every
+//! direction, such as flood fills and cellular automata, miss the cache on every vertical step.
+//! Interleaving the bits of `x` and `y` (the Morton code) stores each aligned square block
+//! contiguously instead, at the cost of slower straight scans along a row.
+//!
+//! Whether that pays off depends on the access pattern and the machine. On the `layout`
+//! benchmarks, a depth first flood fill and a row scan of a 512 by 512 grid, row-major [`Grid`]
+//! is still faster, so measure with `cargo bench --bench inner layout` and on the day itself
+//! before switching a day over.
+use crate::conversions::FromChar;
+use crate::grid::Grid;
+use crate::point::Point;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+/// Interleaves the bits of the coordinates, `x` in the even bits and `y` in the odd bits.
+pub fn encode(x: u32, y: u32) -> usize {
+    (spread(x) | (spread(y) << 1)) as usize
+}
+
+/// Splits a Morton code back into its coordinates.
+pub fn decode(index: usize) -> (u32, u32) {
+    let index = index as u64;
+    (compact(index), compact(index >> 1))
+}
+
+/// Moves bit `i` of `value` to bit `2 * i`.
+fn spread(value: u32) -> u64 {
+    let mut value = value as u64;
+    value = (value | (value << 16)) & 0x0000_ffff_0000_ffff;
+    value = (value | (value << 8)) & 0x00ff_00ff_00ff_00ff;
+    value = (value | (value << 4)) & 0x0f0f_0f0f_0f0f_0f0f;
+    value = (value | (value << 2)) & 0x3333_3333_3333_3333;
+    (value | (value << 1)) & 0x5555_5555_5555_5555
+}
+
+/// The inverse of [`spread`], keeping the even bits.
+fn compact(value: u64) -> u32 {
+    let mut value = value & 0x5555_5555_5555_5555;
+    value = (value | (value >> 1)) & 0x3333_3333_3333_3333;
+    value = (value | (value >> 2)) & 0x0f0f_0f0f_0f0f_0f0f;
+    value = (value | (value >> 4)) & 0x00ff_00ff_00ff_00ff;
+    value = (value | (value >> 8)) & 0x0000_ffff_0000_ffff;
+    (value | (value >> 16)) as u32
+}
+
+/// A grid with the same [`Point`] based API as [`Grid`], stored in Morton order.
+///
+/// # Fields
+/// - `width`, `height`: The size of the grid.
+/// - `cells`: Every cell of the smallest power of two square holding the grid, indexed by
+///   [`encode`]. Cells outside of `width` and `height` are padding.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MortonGrid<T> {
+    pub width: i32,
+    pub height: i32,
+    pub cells: Vec<T>,
+}
+
+impl<T: Clone> MortonGrid<T> {
+    /// Creates a grid with every cell set to `value`.
+    pub fn new(width: i32, height: i32, value: T) -> Self {
+        let side = (width.max(height).max(1) as usize).next_power_of_two();
+        Self {
+            width,
+            height,
+            cells: vec![value; side * side],
+        }
+    }
+
+    /// The position of a point in `cells`, or `None` if it is out of bounds.
+    pub fn index(&self, point: &Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| encode(point.x as u32, point.y as u32))
+    }
+
+    /// Retrieves the value at the specified point, or `None` if the point is out of bounds.
+    pub fn get_value(&self, point: &Point) -> Option<T> {
+        self.index(point).map(|index| self.cells[index].clone())
+    }
+
+    /// Sets the value at the specified point.
+    ///
+    /// # Panics
+    /// Panics if the point is out of bounds.
+    pub fn set_value(&mut self, point: &Point, value: T) {
+        let index = self
+            .index(point)
+            .unwrap_or_else(|| panic!("Point {point:?} out of bounds"));
+        self.cells[index] = value;
+    }
+
+    /// Checks if the given point is within the grid boundaries.
+    pub fn contains(&self, point: &Point) -> bool {
+        (0..self.width).contains(&point.x) && (0..self.height).contains(&point.y)
+    }
+
+    /// Returns the number of cells of the grid, not counting padding.
+    pub fn get_size(&self) -> i32 {
+        self.width * self.height
+    }
+}
+
+impl<T> From<&Grid<T>> for MortonGrid<T>
+where
+    T: Default + Clone + Debug + PartialEq,
+    T: FromStr + FromChar,
+    <T as FromStr>::Err: Debug,
+    <T as FromChar>::Err: Debug,
+{
+    fn from(grid: &Grid<T>) -> Self {
+        let mut result = MortonGrid::new(grid.width, grid.height, T::default());
+        for point in grid.points() {
+            result.set_value(&point, grid.cells()[grid.index(&point)].clone());
+        }
+        result
+    }
+}
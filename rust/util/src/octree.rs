@@ -0,0 +1,68 @@
+//! Best-first branch and bound search over 3D integer space.
+//!
+//! Finds the point with the highest score in a huge box, such as the point in range of the most
+//! nanobots, without visiting every point. The box is split into octants recursively. The
+//! `score` of a box must be an upper bound of the score of every point inside it, and exact for
+//! boxes containing a single point. Boxes are explored from the highest bound, so the first
+//! single point reached is optimal.
+//!
+//! Ties are broken by the Manhattan distance to a `target` point, then by volume, so the result
+//! is the optimal point closest to the target.
+use crate::geometry::Cuboid;
+use crate::point3::Point3;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Searches `bounds` for the point with the highest score, closest to `target` on ties.
+///
+/// # Arguments
+/// * `bounds` - The box to search.
+/// * `target` - Point used to break ties between equally scored points, usually the origin.
+/// * `score` - Upper bound of the score of any point in a box, exact for single points.
+///
+/// # Returns
+/// * The best point and its score.
+pub fn best_point<S>(bounds: Cuboid, target: &Point3, mut score: S) -> (Point3, usize)
+where
+    S: FnMut(&Cuboid) -> usize,
+{
+    let mut boxes = vec![bounds];
+    let mut heap = BinaryHeap::new();
+    let key = |cuboid: &Cuboid, score: usize, index: usize| {
+        (
+            score,
+            Reverse(cuboid.distance_to(target)),
+            Reverse(cuboid.volume()),
+            Reverse(index),
+        )
+    };
+    heap.push(key(&bounds, score(&bounds), 0));
+
+    loop {
+        // The heap is never empty, as popping a box either returns or pushes its octants.
+        let (best, _, _, Reverse(index)) = heap.pop().unwrap();
+        let cuboid = boxes[index];
+
+        if cuboid.min == cuboid.max {
+            return (cuboid.min, best);
+        }
+
+        for octant in cuboid.split() {
+            let index = boxes.len();
+            heap.push(key(&octant, score(&octant), index));
+            boxes.push(octant);
+        }
+    }
+}
+
+/// Bounding box of a non-empty set of points.
+///
+/// # Panics
+/// Panics if there are no points.
+pub fn bounding_box<'a>(points: impl IntoIterator<Item = &'a Point3>) -> Cuboid {
+    let mut points = points.into_iter();
+    let first = *points.next().expect("Bounding box of no points");
+    points.fold(Cuboid::new(first, first), |cuboid, point| {
+        Cuboid::new(cuboid.min.min(point), cuboid.max.max(point))
+    })
+}
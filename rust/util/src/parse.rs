@@ -16,7 +16,8 @@
 //! [`iter_unsigned`]: ParseOps::iter_unsigned
 //! [`iter_signed`]: ParseOps::iter_signed
 
-use crate::util::integer::*;
+use crate::direction::Direction;
+use crate::integer::*;
 use std::marker::PhantomData;
 use std::str::Bytes;
 
@@ -104,6 +105,31 @@ impl<T: Signed<T>> Iterator for ParseSigned<'_, T> {
     }
 }
 
+/// Parses a movement script with one `direction amount` instruction per line.
+///
+/// Directions are read with [`Direction::parse_word`], so `R 4`, `U 2`, `forward 5` and `down 3`
+/// are all accepted. The separating space is optional when the direction is a single letter,
+/// as in `R4`. Blank lines are skipped.
+///
+/// # Panics
+/// Panics if a line has an unknown direction or no amount.
+pub fn parse_moves(input: &str) -> Vec<(Direction, i32)> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let split = line
+                .find(|c: char| c.is_ascii_digit() || c == '-' || c.is_whitespace())
+                .unwrap_or(line.len());
+            let (word, amount) = line.split_at(split);
+            let direction = Direction::parse_word(word)
+                .unwrap_or_else(|| panic!("Unknown direction in \"{line}\""));
+            (direction, amount.trim().signed())
+        })
+        .collect()
+}
+
 fn try_unsigned<T: Unsigned<T>>(bytes: &mut Bytes<'_>) -> Option<T> {
     let mut n = loop {
         let byte = bytes.next()?;
@@ -0,0 +1,231 @@
+//! Shortest path searches over implicit graphs.
+//!
+//! Graphs are described by a closure returning the neighbours of a node together with the cost
+//! of moving there, so nodes can be anything hashable: points, `(Point, Direction)` pairs or
+//! packed states. Nodes do not need to implement `Ord`, the priority queue orders entries by
+//! cost and insertion order only.
+//!
+//! [`distance_map`] is the grid specialisation for unit costs, recording the BFS distance from
+//! a start point to every reachable cell.
+//!
+//! [`dijkstra`] finds a single cheapest path. [`dijkstra_all`] additionally tracks every optimal
+//! predecessor of each node, which answers questions about all cheapest paths: how many there
+//! are, and which nodes lie on at least one of them.
+use crate::conversions::FromChar;
+use crate::direction::ORTHOGONAL;
+use crate::grid::Grid;
+use crate::hash::*;
+use crate::point::Point;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::str::FromStr;
+
+/// Computes the number of orthogonal steps from `start` to every cell of the grid.
+///
+/// # Arguments
+/// * `grid` - The grid to walk.
+/// * `start` - The point to measure distances from.
+/// * `passable` - Checks if a cell value can be walked on.
+///
+/// # Returns
+/// * A grid of the same size holding each distance, or `-1` for unreachable cells.
+pub fn distance_map<T, F>(grid: &Grid<T>, start: Point, passable: F) -> Grid<i32>
+where
+    T: Default + Clone + Debug + PartialEq,
+    T: FromStr + FromChar,
+    <T as FromStr>::Err: Debug,
+    <T as FromChar>::Err: Debug,
+    F: Fn(&T) -> bool,
+{
+    let mut distance = grid.same_size_with(-1);
+    let mut queue = VecDeque::from([start]);
+    distance.set_value(&start, 0);
+
+    while let Some(point) = queue.pop_front() {
+        let steps = distance.get_value(&point).unwrap();
+
+        for next in ORTHOGONAL.map(|direction| point.add(&direction.to_point())) {
+            if grid.get_value(&next).is_some_and(|value| passable(&value))
+                && distance.get_value(&next) == Some(-1)
+            {
+                distance.set_value(&next, steps + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    distance
+}
+
+/// Finds the cheapest path from `start` to any node satisfying `goal`.
+///
+/// # Arguments
+/// * `start` - The initial node.
+/// * `neighbours` - Returns `(node, cost)` pairs reachable in one move. Costs must not be
+///   negative.
+/// * `goal` - Checks if a node is a target.
+///
+/// # Returns
+/// * The total cost and the path including both ends, or `None` if no goal is reachable.
+pub fn dijkstra<N, F, I, G>(start: N, mut neighbours: F, goal: G) -> Option<(u64, Vec<N>)>
+where
+    N: Copy + Eq + Hash,
+    F: FnMut(N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+    G: Fn(N) -> bool,
+{
+    let mut cost = FastMap::new();
+    let mut previous: FastMap<N, N> = FastMap::new();
+    let mut queue = Queue::new();
+
+    cost.insert(start, 0);
+    queue.push(0, start);
+
+    while let Some((current_cost, current)) = queue.pop() {
+        if current_cost > cost[&current] {
+            continue;
+        }
+        if goal(current) {
+            let mut path = vec![current];
+            while let Some(&node) = previous.get(path.last().unwrap()) {
+                path.push(node);
+            }
+            path.reverse();
+            return Some((current_cost, path));
+        }
+
+        for (next, step) in neighbours(current) {
+            let next_cost = current_cost + step;
+            if cost.get(&next).is_none_or(|&c| next_cost < c) {
+                cost.insert(next, next_cost);
+                previous.insert(next, current);
+                queue.push(next_cost, next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Every cheapest path from the start to the nearest goals.
+#[derive(Clone, Debug)]
+pub struct AllPaths<N> {
+    /// Cost of the cheapest path.
+    pub cost: u64,
+    /// Number of distinct cheapest paths.
+    pub count: u64,
+    /// Goal nodes reached at the cheapest cost.
+    pub ends: Vec<N>,
+    predecessors: FastMap<N, Vec<N>>,
+}
+
+impl<N> AllPaths<N>
+where
+    N: Copy + Eq + Hash,
+{
+    /// Returns every node that lies on at least one cheapest path, including both ends.
+    pub fn nodes(&self) -> FastSet<N> {
+        let mut seen = FastSet::new();
+        let mut stack = self.ends.clone();
+
+        while let Some(node) = stack.pop() {
+            if seen.insert(node) {
+                if let Some(predecessors) = self.predecessors.get(&node) {
+                    stack.extend(predecessors);
+                }
+            }
+        }
+
+        seen
+    }
+}
+
+/// Like [`dijkstra`], but tracks every cheapest path instead of a single one.
+///
+/// Costs must be strictly positive, otherwise the path count of a node could still change
+/// after it has been expanded.
+pub fn dijkstra_all<N, F, I, G>(start: N, mut neighbours: F, goal: G) -> Option<AllPaths<N>>
+where
+    N: Copy + Eq + Hash,
+    F: FnMut(N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+    G: Fn(N) -> bool,
+{
+    let mut cost = FastMap::new();
+    let mut count: FastMap<N, u64> = FastMap::new();
+    let mut predecessors: FastMap<N, Vec<N>> = FastMap::new();
+    let mut queue = Queue::new();
+    let mut best: Option<u64> = None;
+    let mut ends = Vec::new();
+
+    cost.insert(start, 0);
+    count.insert(start, 1);
+    queue.push(0, start);
+
+    while let Some((current_cost, current)) = queue.pop() {
+        if current_cost > cost[&current] {
+            continue;
+        }
+        if best.is_some_and(|b| current_cost > b) {
+            break;
+        }
+        if goal(current) {
+            best = Some(current_cost);
+            ends.push(current);
+            continue;
+        }
+
+        let current_count = count[&current];
+        for (next, step) in neighbours(current) {
+            let next_cost = current_cost + step;
+            match cost.get(&next) {
+                Some(&c) if next_cost > c => {}
+                Some(&c) if next_cost == c => {
+                    *count.get_mut(&next).unwrap() += current_count;
+                    predecessors.entry(next).or_default().push(current);
+                }
+                _ => {
+                    cost.insert(next, next_cost);
+                    count.insert(next, current_count);
+                    predecessors.insert(next, vec![current]);
+                    queue.push(next_cost, next);
+                }
+            }
+        }
+    }
+
+    best.map(|cost| AllPaths {
+        cost,
+        count: ends.iter().map(|end| count[end]).sum(),
+        ends,
+        predecessors,
+    })
+}
+
+/// Min priority queue of nodes that do not implement `Ord`.
+struct Queue<N> {
+    heap: BinaryHeap<Reverse<(u64, usize)>>,
+    nodes: Vec<N>,
+}
+
+impl<N: Copy> Queue<N> {
+    fn new() -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+            nodes: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, cost: u64, node: N) {
+        self.heap.push(Reverse((cost, self.nodes.len())));
+        self.nodes.push(node);
+    }
+
+    fn pop(&mut self) -> Option<(u64, N)> {
+        self.heap
+            .pop()
+            .map(|Reverse((cost, index))| (cost, self.nodes[index]))
+    }
+}
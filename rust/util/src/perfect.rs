@@ -0,0 +1,228 @@
+//! Perfect hashing of a fixed set of names to dense indices.
+//!
+//! Graph puzzles name their nodes with short labels, such as the wires `x00` and `qnf` or the
+//! valves `AA` and `JK`. Solutions want dense indices instead, so that adjacency and state fit in
+//! vectors and bitmasks, but translating every label through a [`FastMap`] while parsing costs a
+//! hash and a probe per lookup. As the set of names is known once the input has been read,
+//! [`PerfectIndex`] instead searches for a hash function without any collisions on exactly those
+//! names, using the hash and displace scheme:
+//!
+//! * A first hash splits the names into small buckets.
+//! * Buckets are placed largest first. Each gets the smallest displacement that sends all of its
+//!   names to free slots through a second hash.
+//!
+//! A lookup is then two hashes, two array reads and a comparison to reject unknown names, with
+//! no probing. Names up to eight bytes, which covers every label seen so far, are packed into a
+//! single `u64` instead of being hashed byte by byte.
+//!
+//! [`FastMap`]: crate::hash::FastMap
+use crate::hash::{FastHasher, U64Map};
+use crate::rng::Rng;
+use std::cmp::Reverse;
+use std::hash::Hasher;
+
+/// Average number of names sharing a bucket.
+const BUCKET_SIZE: usize = 4;
+/// Seeds tried for a table size before doubling it.
+const ATTEMPTS: usize = 16;
+/// Displacements tried for a bucket before giving up on a seed.
+const MAX_DISPLACEMENT: u32 = 1 << 12;
+/// Marks a slot that no name hashes to.
+const EMPTY: u32 = u32::MAX;
+/// 2⁶⁴ divided by the golden ratio, spreading consecutive displacements apart.
+const GOLDEN: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// Maps each of a fixed set of names to its position in [`keys`](PerfectIndex::keys).
+///
+/// Indices follow the order in which names first appear when building, so the index doubles as
+/// an interner: [`index`](PerfectIndex::index) turns a name into a number and
+/// [`key`](PerfectIndex::key) turns it back.
+///
+/// # Fields
+/// - `keys`: The distinct names, in index order.
+/// - `folds`: Each name packed or hashed into a `u64`, in index order.
+/// - `seed`: Mixed into every hash, picked by the construction search.
+/// - `displacements`: The displacement chosen for each bucket.
+/// - `slots`: The index of the name hashing to each slot, or [`EMPTY`]. Its length is a power of
+///   two, usually the smallest one holding every name.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PerfectIndex {
+    keys: Vec<String>,
+    folds: Vec<u64>,
+    seed: u64,
+    displacements: Vec<u32>,
+    slots: Vec<u32>,
+}
+
+impl PerfectIndex {
+    /// Builds the index of the distinct names, ignoring repeats.
+    ///
+    /// # Panics
+    /// Panics if two distinct names fold to the same `u64`, which only happens for names that
+    /// differ in trailing NUL bytes or, with negligible probability, for names longer than eight
+    /// bytes.
+    pub fn new<I, K>(names: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: AsRef<str>,
+    {
+        let mut keys: Vec<String> = Vec::new();
+        let mut folds: Vec<u64> = Vec::new();
+        let mut seen = U64Map::new();
+
+        for name in names {
+            let name = name.as_ref();
+            let fold = fold(name.as_bytes());
+            match seen.get(fold) {
+                Some(&index) => assert!(
+                    keys[index] == name,
+                    "Names {:?} and {name:?} collide",
+                    keys[index]
+                ),
+                None => {
+                    seen.insert(fold, keys.len());
+                    keys.push(name.to_string());
+                    folds.push(fold);
+                }
+            }
+        }
+
+        let buckets = (keys.len() / BUCKET_SIZE).max(1).next_power_of_two();
+        let mut size = keys.len().max(1).next_power_of_two();
+        let mut rng = Rng::new(keys.len() as u64);
+
+        loop {
+            for _ in 0..ATTEMPTS {
+                let seed = rng.next_u64();
+                if let Some((displacements, slots)) = place(&folds, seed, buckets, size) {
+                    return PerfectIndex {
+                        keys,
+                        folds,
+                        seed,
+                        displacements,
+                        slots,
+                    };
+                }
+            }
+            size *= 2;
+        }
+    }
+
+    /// Finds the index of a name, or `None` if it was not one of the names given to [`new`].
+    ///
+    /// [`new`]: PerfectIndex::new
+    #[inline]
+    pub fn index(&self, name: &str) -> Option<usize> {
+        let bytes = name.as_bytes();
+        let fold = fold(bytes);
+        let hash = mix(fold ^ self.seed);
+        let displacement = self.displacements[bucket(hash, self.displacements.len())];
+        let index = self.slots[slot(hash, displacement, self.slots.len())] as usize;
+
+        let found =
+            self.folds.get(index) == Some(&fold) && (bytes.len() <= 8 || self.keys[index] == name);
+        found.then_some(index)
+    }
+
+    /// Returns the name with the given index.
+    ///
+    /// # Panics
+    /// Panics if the index is not below [`len`](PerfectIndex::len).
+    pub fn key(&self, index: usize) -> &str {
+        &self.keys[index]
+    }
+
+    /// The distinct names in index order.
+    pub fn keys(&self) -> &[String] {
+        &self.keys
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+/// Searches displacements for every bucket with one seed, largest bucket first.
+///
+/// # Returns
+/// * The displacement of each bucket and the filled slots, or `None` if some bucket could not
+///   be placed.
+fn place(folds: &[u64], seed: u64, buckets: usize, size: usize) -> Option<(Vec<u32>, Vec<u32>)> {
+    let mut groups = vec![Vec::new(); buckets];
+    for (index, &fold) in folds.iter().enumerate() {
+        let hash = mix(fold ^ seed);
+        groups[bucket(hash, buckets)].push((index as u32, hash));
+    }
+    let mut order: Vec<usize> = (0..buckets).collect();
+    order.sort_unstable_by_key(|&bucket| Reverse(groups[bucket].len()));
+
+    let mut displacements = vec![0; buckets];
+    let mut slots = vec![EMPTY; size];
+    let mut chosen = Vec::new();
+
+    for bucket in order {
+        let group = &groups[bucket];
+        if group.is_empty() {
+            break;
+        }
+
+        let displacement = (0..MAX_DISPLACEMENT).find(|&displacement| {
+            chosen.clear();
+            group.iter().all(|&(_, hash)| {
+                let slot = slot(hash, displacement, size);
+                let free = slots[slot] == EMPTY && !chosen.contains(&slot);
+                chosen.push(slot);
+                free
+            })
+        })?;
+
+        displacements[bucket] = displacement;
+        for (&(index, _), &slot) in group.iter().zip(&chosen) {
+            slots[slot] = index;
+        }
+    }
+
+    Some((displacements, slots))
+}
+
+/// Packs names of up to eight bytes into a `u64`, hashing longer ones.
+#[inline]
+fn fold(bytes: &[u8]) -> u64 {
+    if bytes.len() <= 8 {
+        // Shifting beats copying into a buffer, which calls `memcpy` for the variable length.
+        bytes
+            .iter()
+            .rev()
+            .fold(0, |packed, &byte| (packed << 8) | byte as u64)
+    } else {
+        let mut hasher = FastHasher::default();
+        hasher.write(bytes);
+        // Keep long names apart from the packed short ones, which never have the top bit set
+        // for ASCII text.
+        hasher.finish() | (1 << 63)
+    }
+}
+
+/// The splitmix64 finaliser, so that every bit of the input affects every bit of the hash.
+#[inline]
+fn mix(mut x: u64) -> u64 {
+    x ^= x >> 30;
+    x = x.wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x ^= x >> 27;
+    x = x.wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+#[inline]
+fn bucket(hash: u64, buckets: usize) -> usize {
+    (hash >> 32) as usize & (buckets - 1)
+}
+
+#[inline]
+fn slot(hash: u64, displacement: u32, size: usize) -> usize {
+    mix(hash.wrapping_add((displacement as u64).wrapping_mul(GOLDEN))) as usize & (size - 1)
+}
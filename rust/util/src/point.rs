@@ -1,3 +1,5 @@
+use crate::math::gcd;
+
 /// Represents a point in 2D space, designed for use with grid structures.
 ///
 /// The `Point` struct encapsulates a coordinate in two-dimensional space with integer precision.
@@ -97,6 +99,67 @@ impl Point {
         self.x != 0 && self.y != 0
     }
 
+    /// Clamps each component to `-1`, `0` or `1`.
+    ///
+    /// Useful for stepping one cell at a time towards another point, including diagonally.
+    ///
+    /// # Returns
+    ///
+    /// A new `Point` with the sign of each coordinate.
+    pub fn signum(&self) -> Self {
+        Self {
+            x: self.x.signum(),
+            y: self.y.signum(),
+        }
+    }
+
+    /// Calculates the Chebyshev distance, where diagonal neighbours are one step away.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The point to measure the distance to.
+    ///
+    /// # Returns
+    ///
+    /// The larger of the absolute differences between the coordinates.
+    pub fn chebyshev(&self, other: &Self) -> i32 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Divides both components by their greatest common divisor.
+    ///
+    /// The result is the smallest integer step in the same direction, so walking from one point
+    /// by the reduced difference visits every grid point on the line to another.
+    ///
+    /// # Returns
+    ///
+    /// The reduced point, or `Point::EMPTY` unchanged.
+    pub fn reduced(&self) -> Self {
+        match gcd(self.x.abs(), self.y.abs()) {
+            0 => *self,
+            divisor => Self::new(self.x / divisor, self.y / divisor),
+        }
+    }
+
+    /// Checks if three points lie on a single line, using the cross product of their differences.
+    pub fn collinear(a: &Self, b: &Self, c: &Self) -> bool {
+        let (ab, ac) = (b.sub(a), c.sub(a));
+        ab.x as i64 * ac.y as i64 == ab.y as i64 * ac.x as i64
+    }
+
+    /// Calculates the Manhattan distance, where only orthogonal steps are allowed.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The point to measure the distance to.
+    ///
+    /// # Returns
+    ///
+    /// The sum of the absolute differences between the coordinates.
+    pub fn manhattan(&self, other: &Self) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
     pub const EMPTY: Self = Self { x: 0, y: 0 };
     pub const LEFT: Self = Self { x: -1, y: 0 };
     pub const UP: Self = Self { x: 0, y: -1 };
@@ -0,0 +1,61 @@
+/// Represents a point in 3D space.
+///
+/// The three dimensional counterpart of [`Point`](crate::point::Point), with the same by
+/// reference arithmetic. Used for bricks, scanners and other volumetric puzzles.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Point3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Point3 {
+    pub fn new(x: i32, y: i32, z: i32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Adds another `Point3` component-wise.
+    pub fn add(&self, other: &Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+
+    /// Subtracts another `Point3` component-wise.
+    pub fn sub(&self, other: &Self) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+
+    /// Calculates the Manhattan distance, the sum of the absolute differences of each axis.
+    pub fn manhattan(&self, other: &Self) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// Component-wise minimum.
+    pub fn min(&self, other: &Self) -> Self {
+        Self::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    /// Component-wise maximum.
+    pub fn max(&self, other: &Self) -> Self {
+        Self::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+
+    pub const EMPTY: Self = Self { x: 0, y: 0, z: 0 };
+    pub const UP: Self = Self { x: 0, y: 0, z: 1 };
+    pub const DOWN: Self = Self { x: 0, y: 0, z: -1 };
+}
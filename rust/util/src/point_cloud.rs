@@ -0,0 +1,170 @@
+//! Aligns 3D point clouds seen from unknown orientations and positions.
+//!
+//! Each scanner reports points relative to itself, facing one of the 24 axis-aligned
+//! orientations. Two clouds match if some rotation and translation maps at least `min_overlap`
+//! points of one onto the other. For every rotation, each pair of points votes for the
+//! translation that would align them, and a translation with enough votes is the answer.
+//!
+//! Trying all rotations for every pair of scanners is slow, so clouds are first compared by
+//! their pairwise squared distances, which do not change under rotation or translation. Clouds
+//! sharing `n` points share at least `n * (n - 1) / 2` distances, so most pairs are rejected
+//! without any voting.
+use crate::hash::*;
+use crate::point3::Point3;
+use std::collections::VecDeque;
+
+/// A rotation matrix with entries `-1`, `0` or `1`, applied as `matrix * point`.
+pub type Rotation = [[i32; 3]; 3];
+
+/// Returns the 24 proper rotations of the axes, starting with the identity.
+///
+/// These are the signed permutation matrices with determinant one. The other 24 signed
+/// permutations are mirror images and are excluded.
+pub fn rotations() -> Vec<Rotation> {
+    const PERMUTATIONS: [[usize; 3]; 6] = [
+        [0, 1, 2],
+        [0, 2, 1],
+        [1, 0, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [2, 1, 0],
+    ];
+    let mut result = Vec::with_capacity(24);
+
+    for permutation in PERMUTATIONS {
+        for signs in 0..8 {
+            let mut matrix = [[0; 3]; 3];
+            for (row, &column) in permutation.iter().enumerate() {
+                matrix[row][column] = if signs & (1 << row) == 0 { 1 } else { -1 };
+            }
+            if determinant(&matrix) == 1 {
+                result.push(matrix);
+            }
+        }
+    }
+
+    result
+}
+
+fn determinant(m: &Rotation) -> i32 {
+    m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+}
+
+/// Applies a rotation to a point.
+pub fn rotate(rotation: &Rotation, point: &Point3) -> Point3 {
+    let [x, y, z] = rotation.map(|row| row[0] * point.x + row[1] * point.y + row[2] * point.z);
+    Point3::new(x, y, z)
+}
+
+/// Maps points of one cloud into the frame of another.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Alignment {
+    pub rotation: Rotation,
+    /// Position of the aligned cloud's origin, such as the scanner, in the reference frame.
+    pub translation: Point3,
+}
+
+impl Alignment {
+    pub fn apply(&self, point: &Point3) -> Point3 {
+        rotate(&self.rotation, point).add(&self.translation)
+    }
+}
+
+/// Finds how to map `cloud` onto `reference` so that at least `min_overlap` points coincide.
+///
+/// # Returns
+/// * The alignment, or `None` if the clouds do not overlap enough.
+pub fn align(reference: &[Point3], cloud: &[Point3], min_overlap: usize) -> Option<Alignment> {
+    let required = min_overlap * min_overlap.saturating_sub(1) / 2;
+    if shared_distances(reference, cloud) < required {
+        return None;
+    }
+
+    let mut votes: FastMap<Point3, usize> = FastMap::new();
+
+    for rotation in rotations() {
+        votes.clear();
+        let rotated: Vec<Point3> = cloud.iter().map(|p| rotate(&rotation, p)).collect();
+
+        for a in reference {
+            for b in &rotated {
+                let translation = a.sub(b);
+                let count = votes.entry(translation).or_insert(0);
+                *count += 1;
+                if *count >= min_overlap {
+                    return Some(Alignment {
+                        rotation,
+                        translation,
+                    });
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Counts the squared pairwise distances present in both clouds, with multiplicity.
+fn shared_distances(a: &[Point3], b: &[Point3]) -> usize {
+    let distances = |cloud: &[Point3]| {
+        let mut counts: FastMap<i64, usize> = FastMap::new();
+        for (i, p) in cloud.iter().enumerate() {
+            for q in &cloud[i + 1..] {
+                let d = p.sub(q);
+                let squared = [d.x, d.y, d.z].map(|v| v as i64 * v as i64).iter().sum();
+                *counts.entry(squared).or_insert(0) += 1;
+            }
+        }
+        counts
+    };
+
+    let first = distances(a);
+    let second = distances(b);
+    first
+        .iter()
+        .map(|(distance, &count)| count.min(second.get(distance).copied().unwrap_or(0)))
+        .sum()
+}
+
+/// Aligns every cloud into the frame of the first one.
+///
+/// # Returns
+/// * The union of all points, and the alignment of each cloud in the first cloud's frame.
+/// * `None` if some cloud cannot be connected to the first through overlapping clouds.
+pub fn merge(
+    clouds: &[Vec<Point3>],
+    min_overlap: usize,
+) -> Option<(FastSet<Point3>, Vec<Alignment>)> {
+    let identity = Alignment {
+        rotation: rotations()[0],
+        translation: Point3::EMPTY,
+    };
+    let mut alignments: Vec<Option<Alignment>> = vec![None; clouds.len()];
+    let mut aligned: Vec<Vec<Point3>> = vec![Vec::new(); clouds.len()];
+    let mut queue = VecDeque::new();
+
+    if let Some(first) = clouds.first() {
+        alignments[0] = Some(identity);
+        aligned[0] = first.clone();
+        queue.push_back(0);
+    }
+
+    while let Some(known) = queue.pop_front() {
+        for other in 0..clouds.len() {
+            if alignments[other].is_some() {
+                continue;
+            }
+            if let Some(alignment) = align(&aligned[known], &clouds[other], min_overlap) {
+                aligned[other] = clouds[other].iter().map(|p| alignment.apply(p)).collect();
+                alignments[other] = Some(alignment);
+                queue.push_back(other);
+            }
+        }
+    }
+
+    let alignments: Option<Vec<Alignment>> = alignments.into_iter().collect();
+    let points = aligned.into_iter().flatten().collect();
+    alignments.map(|alignments| (points, alignments))
+}
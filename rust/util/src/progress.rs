@@ -0,0 +1,37 @@
+//! Progress reporting for long running solutions.
+//!
+//! Brute force solutions announce how much work they have with [`start`] and report finished
+//! pieces of it with [`advance`]. The runner polls [`current`] from another thread and draws a
+//! progress bar, so a slow day shows movement instead of appearing hung. Reporting is a relaxed
+//! atomic add, cheap enough for loops over thousands of candidates but not for the innermost
+//! step of a simulation.
+//!
+//! There is a single counter for the whole process, so days running side by side with
+//! `--parallel` overwrite each other's progress.
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static DONE: AtomicUsize = AtomicUsize::new(0);
+static TOTAL: AtomicUsize = AtomicUsize::new(0);
+
+/// Announces `total` pieces of work, resetting the count of finished ones.
+pub fn start(total: usize) {
+    DONE.store(0, Ordering::Relaxed);
+    TOTAL.store(total, Ordering::Relaxed);
+}
+
+/// Reports `count` more finished pieces of work.
+#[inline]
+pub fn advance(count: usize) {
+    DONE.fetch_add(count, Ordering::Relaxed);
+}
+
+/// Withdraws the announced work. The runner calls this after every solution.
+pub fn finish() {
+    TOTAL.store(0, Ordering::Relaxed);
+}
+
+/// The finished and total pieces of work, or `None` when nothing is announced.
+pub fn current() -> Option<(usize, usize)> {
+    let total = TOTAL.load(Ordering::Relaxed);
+    (total > 0).then(|| (DONE.load(Ordering::Relaxed).min(total), total))
+}
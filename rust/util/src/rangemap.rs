@@ -0,0 +1,124 @@
+//! Maps numbers and whole intervals through layers of piecewise offsets.
+//!
+//! Each layer is a list of rules `source range -> offset`. Values inside a rule's range are
+//! shifted by its offset, values outside every range pass through unchanged. Mapping an
+//! interval splits it at the rule boundaries, so a few intervals can stand in for billions of
+//! individual values as they flow through all layers.
+//!
+//! Intervals are half open, `start..end`, matching [`Range`].
+use crate::parse::*;
+use std::ops::Range;
+
+/// A single layer of non overlapping rules.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Layer {
+    /// `(source, offset)` rules sorted by the start of their source range.
+    rules: Vec<(Range<i64>, i64)>,
+}
+
+impl Layer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rule moving `source` by `offset`. Rules within a layer must not overlap.
+    pub fn add(&mut self, source: Range<i64>, offset: i64) {
+        let index = self
+            .rules
+            .partition_point(|(range, _)| range.start < source.start);
+        self.rules.insert(index, (source, offset));
+    }
+
+    /// Parses `destination source length` lines, ignoring any line without three numbers such as
+    /// a `seed-to-soil map:` header.
+    pub fn parse(block: &str) -> Self {
+        let mut layer = Layer::new();
+        for line in block.lines() {
+            let numbers: Vec<i64> = line.iter_unsigned::<u64>().map(|n| n as i64).collect();
+            if let [destination, source, length] = numbers[..] {
+                layer.add(source..source + length, destination - source);
+            }
+        }
+        layer
+    }
+
+    pub fn map(&self, value: i64) -> i64 {
+        let index = self
+            .rules
+            .partition_point(|(range, _)| range.start <= value);
+        match index.checked_sub(1).map(|i| &self.rules[i]) {
+            Some((range, offset)) if range.contains(&value) => value + offset,
+            _ => value,
+        }
+    }
+
+    /// Maps an interval, splitting it wherever it crosses a rule boundary.
+    ///
+    /// # Returns
+    /// * The mapped pieces, which together hold as many values as the input.
+    pub fn map_range(&self, range: Range<i64>) -> Vec<Range<i64>> {
+        let mut result = Vec::new();
+        let mut start = range.start;
+
+        for (source, offset) in &self.rules {
+            if start >= range.end {
+                break;
+            }
+            if source.end <= start {
+                continue;
+            }
+            // Values before this rule pass through unchanged.
+            if source.start > start {
+                let end = source.start.min(range.end);
+                result.push(start..end);
+                start = end;
+            }
+            let end = source.end.min(range.end);
+            if start < end {
+                result.push(start + offset..end + offset);
+                start = end;
+            }
+        }
+
+        if start < range.end {
+            result.push(start..range.end);
+        }
+        result
+    }
+}
+
+/// A sequence of layers applied one after another.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct PiecewiseMap {
+    pub layers: Vec<Layer>,
+}
+
+impl PiecewiseMap {
+    pub fn new(layers: Vec<Layer>) -> Self {
+        Self { layers }
+    }
+
+    /// Parses layers separated by blank lines, see [`Layer::parse`].
+    pub fn parse(input: &str) -> Self {
+        Self::new(input.split("\n\n").map(Layer::parse).collect())
+    }
+
+    pub fn map(&self, value: i64) -> i64 {
+        self.layers
+            .iter()
+            .fold(value, |value, layer| layer.map(value))
+    }
+
+    /// Maps intervals through every layer.
+    ///
+    /// # Returns
+    /// * Every resulting piece, in no particular order and possibly overlapping.
+    pub fn map_ranges(&self, ranges: Vec<Range<i64>>) -> Vec<Range<i64>> {
+        self.layers.iter().fold(ranges, |ranges, layer| {
+            ranges
+                .into_iter()
+                .flat_map(|range| layer.map_range(range))
+                .collect()
+        })
+    }
+}
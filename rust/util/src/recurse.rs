@@ -0,0 +1,35 @@
+//! Stack-safe recursion using an explicit heap allocated stack.
+//!
+//! Deep recursion on adversarial inputs can overflow the thread stack. [`trampoline`] keeps
+//! the shape of a recursive function: the closure receives the arguments of a single call and
+//! "recurses" by pushing the arguments of sub-calls. Sub-calls are evaluated depth-first in the
+//! order they were pushed, exactly as the equivalent recursive function would evaluate them.
+//!
+//! Results are accumulated in state captured by the closure. Returning
+//! [`ControlFlow::Break`] stops the whole evaluation early, which is the equivalent of
+//! short-circuiting `||` chains of recursive calls.
+use std::ops::ControlFlow;
+
+/// Evaluates a recursive function without growing the call stack.
+///
+/// # Arguments
+/// * `root` - Arguments of the initial call.
+/// * `f` - Body of the recursive function. Push to `calls` to recurse.
+///
+/// # Returns
+/// * The first `Break` value returned by `f`, or `Continue` once every call has completed.
+pub fn trampoline<A, B, F>(root: A, mut f: F) -> ControlFlow<B>
+where
+    F: FnMut(A, &mut Vec<A>) -> ControlFlow<B>,
+{
+    let mut stack = vec![root];
+
+    while let Some(args) = stack.pop() {
+        let start = stack.len();
+        f(args, &mut stack)?;
+        // Reverse the new calls so the first pushed is the first popped.
+        stack[start..].reverse();
+    }
+
+    ControlFlow::Continue(())
+}
@@ -0,0 +1,62 @@
+//! Stack based reduction of sequences where adjacent pairs annihilate each other.
+//!
+//! Each unit is pushed onto a stack, unless it reacts with the unit on top of the stack in
+//! which case both disappear. A single pass is enough because any reaction exposed by removing
+//! a pair happens between the new top of the stack and the next unit.
+//!
+//! The result does not depend on the order in which pairs react. Removing a unit type and then
+//! reducing therefore gives the same result whether it is removed from the original polymer or
+//! from the already reduced one. [`shortest_without_unit`] relies on this to reduce once upfront
+//! and then test each removal against the much shorter base.
+
+/// Reduces the sequence, removing every adjacent pair for which `reacts` returns `true`.
+pub fn reduce<T, I, F>(units: I, reacts: F) -> Vec<T>
+where
+    T: Copy,
+    I: IntoIterator<Item = T>,
+    F: Fn(T, T) -> bool,
+{
+    let mut stack = Vec::new();
+
+    for unit in units {
+        match stack.last() {
+            Some(&top) if reacts(top, unit) => {
+                stack.pop();
+            }
+            _ => stack.push(unit),
+        }
+    }
+
+    stack
+}
+
+/// Checks if two ASCII letters are the same type with opposite polarity, e.g. `a` and `A`.
+#[inline]
+pub fn opposite_polarity(a: u8, b: u8) -> bool {
+    a ^ b == 32 && a.is_ascii_alphabetic()
+}
+
+/// Fully reacts a polymer of ASCII letters.
+pub fn reduce_polymer(polymer: &[u8]) -> Vec<u8> {
+    reduce(polymer.iter().copied(), opposite_polarity)
+}
+
+/// Finds the unit type whose removal, in both polarities, gives the shortest reduced polymer.
+///
+/// # Returns
+/// * The lowercase unit type and the length of the reduced polymer without it, or `None` if the
+///   polymer contains no letters.
+pub fn shortest_without_unit(polymer: &[u8]) -> Option<(u8, usize)> {
+    let base = reduce_polymer(polymer);
+
+    (b'a'..=b'z')
+        .filter(|&unit| base.iter().any(|c| c.to_ascii_lowercase() == unit))
+        .map(|unit| {
+            let filtered = base
+                .iter()
+                .copied()
+                .filter(|c| c.to_ascii_lowercase() != unit);
+            (unit, reduce(filtered, opposite_polarity).len())
+        })
+        .min_by_key(|&(_, len)| len)
+}
@@ -0,0 +1,58 @@
+//! Small, fast, seedable pseudo random number generator.
+//!
+//! Uses the [xorshift64*](https://en.wikipedia.org/wiki/Xorshift#xorshift*) algorithm, which is
+//! more than good enough for generating test data and shuffling, while keeping the crate free
+//! of external dependencies. Not suitable for anything security related.
+use std::ops::RangeInclusive;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Creates a generator with a fixed seed, producing the same sequence on every run.
+    pub fn new(seed: u64) -> Self {
+        // Zero is the only invalid state for xorshift, so mix the seed first.
+        let state = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+        Self { state }
+    }
+
+    /// Creates a generator seeded from the system clock.
+    pub fn from_time() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64);
+        Self::new(nanos)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Returns a value uniformly distributed in the inclusive range.
+    ///
+    /// # Panics
+    /// Panics if the range is empty.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "Empty range {start}..={end}");
+
+        match (end - start).checked_add(1) {
+            Some(span) => start + self.next_u64() % span,
+            None => self.next_u64(),
+        }
+    }
+
+    /// Shuffles the slice in place using the Fisher-Yates algorithm.
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        for i in (1..slice.len()).rev() {
+            let j = self.range(0..=i as u64) as usize;
+            slice.swap(i, j);
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! Rope physics, where the head moves and every following knot is dragged behind it.
+//!
+//! A knot stays put while it touches the knot in front of it, including diagonally. Once the gap
+//! grows larger it moves one step towards the knot in front, clamping each axis with
+//! [`Point::signum`], so that it moves diagonally when not in the same row or column.
+use crate::direction::Direction;
+use crate::point::Point;
+use std::collections::HashSet;
+
+/// A rope made of a fixed number of knots, all starting at the origin.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Rope {
+    knots: Vec<Point>,
+}
+
+impl Rope {
+    /// Creates a rope with `knots` knots, including the head and the tail.
+    ///
+    /// # Panics
+    /// Panics if `knots` is zero.
+    pub fn new(knots: usize) -> Self {
+        assert!(knots > 0, "A rope needs at least one knot");
+        Self {
+            knots: vec![Point::EMPTY; knots],
+        }
+    }
+
+    /// Moves the head by a single step and lets the remaining knots follow.
+    pub fn step(&mut self, direction: &Direction) {
+        self.knots[0] = self.knots[0].add(&direction.to_point());
+
+        for i in 1..self.knots.len() {
+            let leader = self.knots[i - 1];
+            let knot = self.knots[i];
+
+            if knot.chebyshev(&leader) <= 1 {
+                // Once a knot stays put, so do all knots behind it.
+                break;
+            }
+
+            self.knots[i] = knot.add(&leader.sub(&knot).signum());
+        }
+    }
+
+    pub fn head(&self) -> Point {
+        self.knots[0]
+    }
+
+    pub fn tail(&self) -> Point {
+        self.knots[self.knots.len() - 1]
+    }
+
+    pub fn knots(&self) -> &[Point] {
+        &self.knots
+    }
+}
+
+/// Drags a rope of `knots` knots through the moves, recording every point the tail visits.
+///
+/// # Arguments
+/// * `moves` - Pairs of direction and number of steps to move the head.
+/// * `knots` - The number of knots in the rope, including the head.
+///
+/// # Returns
+/// * The set of points visited by the tail, including the origin.
+pub fn tail_visits(moves: &[(Direction, i32)], knots: usize) -> HashSet<Point> {
+    let mut rope = Rope::new(knots);
+    let mut visited = HashSet::from([rope.tail()]);
+
+    for (direction, steps) in moves {
+        for _ in 0..*steps {
+            rope.step(direction);
+            visited.insert(rope.tail());
+        }
+    }
+
+    visited
+}
@@ -0,0 +1,174 @@
+//! Sequences stored as runs of equal values, such as the blocks of files and free space on a disk.
+//!
+//! A disk map like `12345` describes a file of one block, two free blocks, a file of three
+//! blocks and so on. Expanding it into one entry per block costs memory proportional to the
+//! whole disk, while compaction puzzles only ever move whole runs or cut them in two. [`Runs`]
+//! keeps one [`Run`] per stretch instead:
+//!
+//! ```none
+//! 0..111....22222   runs (0, 0, 1) (., 1, 2) (1, 3, 3) (., 6, 4) (2, 10, 5)
+//! ```
+//!
+//! Every run remembers its absolute start, and moving a value only ever swaps it into space of
+//! the same length, so no run ever shifts. Runs are therefore kept in the order they were
+//! created and addressed by a stable handle, the index returned when they were made, which
+//! makes [`split`](Runs::split) and [`relocate`](Runs::relocate) constant time. Order only
+//! matters again for [`merge`](Runs::merge), which sorts the runs by start, while
+//! [`checksum`](Runs::checksum) folds `value * position` over the runs in any order.
+use crate::math::run_checksum;
+
+/// `length` consecutive positions from `start` holding the same value.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Run<T> {
+    pub value: T,
+    pub start: u64,
+    pub length: u64,
+}
+
+impl<T> Run<T> {
+    /// The position just after the run.
+    pub fn end(&self) -> u64 {
+        self.start + self.length
+    }
+}
+
+/// A sequence of values stored as runs, addressed by stable handles.
+///
+/// # Fields
+/// - `runs`: Every run made so far, indexed by handle. Runs can be empty after being split.
+/// - `end`: The position after the last run pushed, where the next one starts.
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Runs<T> {
+    runs: Vec<Run<T>>,
+    end: u64,
+}
+
+impl<T: Clone + PartialEq> Runs<T> {
+    pub fn new() -> Self {
+        Runs {
+            runs: Vec::new(),
+            end: 0,
+        }
+    }
+
+    /// Appends a run after the last one. Empty runs are kept, so handles can follow the
+    /// entries of the input they were read from.
+    ///
+    /// # Returns
+    /// * The handle of the new run.
+    pub fn push(&mut self, value: T, length: u64) -> usize {
+        self.runs.push(Run {
+            value,
+            start: self.end,
+            length,
+        });
+        self.end += length;
+        self.runs.len() - 1
+    }
+
+    /// Returns the run with a handle.
+    ///
+    /// # Panics
+    /// Panics if no run has the handle.
+    pub fn get(&self, handle: usize) -> &Run<T> {
+        &self.runs[handle]
+    }
+
+    /// The number of runs, including empty ones.
+    pub fn len(&self) -> usize {
+        self.runs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.runs.is_empty()
+    }
+
+    /// The length of the whole sequence.
+    pub fn end(&self) -> u64 {
+        self.end
+    }
+
+    /// Iterates over the runs in handle order.
+    pub fn iter(&self) -> impl Iterator<Item = &Run<T>> {
+        self.runs.iter()
+    }
+
+    /// Cuts the first `at` positions off a run into a run of their own.
+    ///
+    /// # Returns
+    /// * The handle of the new run holding the front. The original handle keeps the rest.
+    ///
+    /// # Panics
+    /// Panics if `at` is longer than the run.
+    pub fn split(&mut self, handle: usize, at: u64) -> usize {
+        let run = &mut self.runs[handle];
+        assert!(
+            at <= run.length,
+            "Split at {at} past a run of {}",
+            run.length
+        );
+        let front = Run {
+            value: run.value.clone(),
+            start: run.start,
+            length: at,
+        };
+        run.start += at;
+        run.length -= at;
+
+        self.runs.push(front);
+        self.runs.len() - 1
+    }
+
+    /// Moves the value of one run into the front of another, leaving `empty` where it was.
+    ///
+    /// # Arguments
+    /// * `from` - The run to move.
+    /// * `to` - The run receiving it, at least as long. It keeps whatever is left of it, which
+    ///   may be nothing.
+    /// * `empty` - The value left behind in `from`.
+    ///
+    /// # Returns
+    /// * The handle of the run now holding the moved value.
+    ///
+    /// # Panics
+    /// Panics if `to` is shorter than `from`.
+    pub fn relocate(&mut self, from: usize, to: usize, empty: T) -> usize {
+        let length = self.runs[from].length;
+        let moved = self.split(to, length);
+        let value = std::mem::replace(&mut self.runs[from].value, empty);
+        self.runs[moved].value = value;
+        moved
+    }
+
+    /// Sorts the runs by start, dropping empty ones and joining neighbours with equal values.
+    ///
+    /// Handles made before merging no longer refer to the same runs.
+    pub fn merge(&mut self) {
+        self.runs.retain(|run| run.length > 0);
+        self.runs.sort_unstable_by_key(|run| run.start);
+        self.runs.dedup_by(|next, previous| {
+            let joined = next.value == previous.value;
+            if joined {
+                previous.length += next.length;
+            }
+            joined
+        });
+    }
+
+    /// Sums `weight * position` over every position of the sequence.
+    ///
+    /// # Arguments
+    /// * `weight` - The weight of each position holding a value, or `None` to skip them.
+    pub fn checksum<F>(&self, weight: F) -> u64
+    where
+        F: Fn(&T) -> Option<u64>,
+    {
+        self.runs
+            .iter()
+            .filter_map(|run| {
+                let weight = weight(&run.value)?;
+                Some(run_checksum(weight, run.start, run.length))
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,201 @@
+//! Byte level tokenizing for inputs that hide tokens among noise, such as corrupted memory.
+//!
+//! Character classes are looked up in tables computed at compile time, so every test is a
+//! single indexed load instead of a chain of range comparisons. [`CLASSES`] covers the common
+//! classes, while [`ByteSet`] builds a table for any set of bytes, typically the first bytes
+//! of the tokens a day looks for:
+//!
+//! ```none
+//! const TOKEN_START: ByteSet = ByteSet::new(b"md");
+//!
+//! let mut scanner = Scanner::new("xmul(2,4)");
+//! assert!(scanner.skip_until(&TOKEN_START));
+//! assert!(scanner.eat(b"mul("));
+//! assert_eq!(scanner.number(3), Some(2));
+//! ```
+//!
+//! [`Scanner`] walks the input with these tables. Its methods only consume input when they
+//! succeed, and [`Scanner::attempt`] rewinds a whole failed token, which keeps parsers written
+//! with `?` simple.
+
+/// Class bit of the ASCII digits `0` to `9`.
+pub const DIGIT: u8 = 1 << 0;
+/// Class bit of the ASCII letters.
+pub const ALPHA: u8 = 1 << 1;
+/// Class bit of spaces, tabs and line breaks.
+pub const SPACE: u8 = 1 << 2;
+/// Class bit of the ASCII punctuation characters.
+pub const PUNCT: u8 = 1 << 3;
+
+/// The classes of every byte, as a combination of the class bits.
+pub const CLASSES: [u8; 256] = classes();
+
+const fn classes() -> [u8; 256] {
+    let mut table = [0; 256];
+    let mut byte = 0;
+
+    while byte < 256 {
+        let b = byte as u8;
+        table[byte] = if b.is_ascii_digit() {
+            DIGIT
+        } else if b.is_ascii_alphabetic() {
+            ALPHA
+        } else if b.is_ascii_whitespace() {
+            SPACE
+        } else if b.is_ascii_punctuation() {
+            PUNCT
+        } else {
+            0
+        };
+        byte += 1;
+    }
+
+    table
+}
+
+#[inline]
+pub fn is_digit(byte: u8) -> bool {
+    CLASSES[byte as usize] & DIGIT != 0
+}
+
+#[inline]
+pub fn is_alpha(byte: u8) -> bool {
+    CLASSES[byte as usize] & ALPHA != 0
+}
+
+#[inline]
+pub fn is_space(byte: u8) -> bool {
+    CLASSES[byte as usize] & SPACE != 0
+}
+
+#[inline]
+pub fn is_punct(byte: u8) -> bool {
+    CLASSES[byte as usize] & PUNCT != 0
+}
+
+/// A set of bytes stored as a lookup table, usually built as a constant.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ByteSet([bool; 256]);
+
+impl ByteSet {
+    /// Creates the set of the given bytes.
+    pub const fn new(members: &[u8]) -> Self {
+        let mut table = [false; 256];
+        let mut index = 0;
+
+        while index < members.len() {
+            table[members[index] as usize] = true;
+            index += 1;
+        }
+
+        ByteSet(table)
+    }
+
+    #[inline]
+    pub fn contains(&self, byte: u8) -> bool {
+        self.0[byte as usize]
+    }
+}
+
+/// A cursor over the bytes of an input.
+#[derive(Clone, Copy, Debug)]
+pub struct Scanner<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Scanner {
+            bytes: input.as_bytes(),
+            position: 0,
+        }
+    }
+
+    /// Offset of the next byte from the start of the input.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Checks if every byte has been consumed.
+    pub fn is_done(&self) -> bool {
+        self.position >= self.bytes.len()
+    }
+
+    /// The next byte, without consuming it.
+    #[inline]
+    pub fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    /// Consumes up to `count` bytes.
+    #[inline]
+    pub fn advance(&mut self, count: usize) {
+        self.position = (self.position + count).min(self.bytes.len());
+    }
+
+    /// Skips to the next byte in the set, returning false if the input ends first.
+    #[inline]
+    pub fn skip_until(&mut self, set: &ByteSet) -> bool {
+        match self.rest().iter().position(|&byte| set.contains(byte)) {
+            Some(offset) => {
+                self.position += offset;
+                true
+            }
+            None => {
+                self.position = self.bytes.len();
+                false
+            }
+        }
+    }
+
+    /// Consumes `literal` if the input continues with it.
+    #[inline]
+    pub fn eat(&mut self, literal: &[u8]) -> bool {
+        let found = self.rest().starts_with(literal);
+        if found {
+            self.position += literal.len();
+        }
+        found
+    }
+
+    /// Consumes an unsigned number of one to `max_digits` digits.
+    ///
+    /// # Returns
+    /// * The number, or `None` without consuming anything if the next byte is not a digit.
+    #[inline]
+    pub fn number(&mut self, max_digits: usize) -> Option<u32> {
+        let digits = self
+            .rest()
+            .iter()
+            .take(max_digits)
+            .take_while(|&&byte| is_digit(byte))
+            .count();
+        if digits == 0 {
+            return None;
+        }
+
+        let number = self.rest()[..digits]
+            .iter()
+            .fold(0, |number, &byte| number * 10 + (byte - b'0') as u32);
+        self.position += digits;
+        Some(number)
+    }
+
+    /// Runs a token parser, rewinding to where it started if it fails.
+    #[inline]
+    pub fn attempt<T>(&mut self, parse: impl FnOnce(&mut Self) -> Option<T>) -> Option<T> {
+        let start = self.position;
+        let result = parse(self);
+        if result.is_none() {
+            self.position = start;
+        }
+        result
+    }
+
+    /// The bytes not consumed yet. `position` never passes the end of the input.
+    #[inline]
+    fn rest(&self) -> &'a [u8] {
+        &self.bytes[self.position..]
+    }
+}
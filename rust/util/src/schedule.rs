@@ -0,0 +1,115 @@
+//! Simulates a pool of workers completing tasks with dependencies.
+//!
+//! Tasks are the nodes of a [`Dag`], where an edge `a -> b` means `b` can only start once `a`
+//! has finished. Time advances in whole ticks. At the start of every tick each idle worker picks
+//! the available task with the smallest index, then every busy worker makes one tick of
+//! progress. Tasks finishing in the same tick release their dependents together.
+use crate::graph::Dag;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// State of the simulation passed to the tick hook.
+///
+/// # Fields
+/// - `time`: The tick about to be worked on, starting at zero.
+/// - `workers`: The task each worker is busy with, if any.
+/// - `done`: Tasks finished so far, in completion order.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Tick<'a> {
+    pub time: u64,
+    pub workers: &'a [Option<usize>],
+    pub done: &'a [usize],
+}
+
+/// The outcome of a complete simulation.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Schedule {
+    /// Total number of ticks until the last task finished.
+    pub time: u64,
+    /// Tasks in the order they finished, ties broken by the smallest index.
+    pub order: Vec<usize>,
+}
+
+/// Runs the simulation until every task has finished.
+///
+/// # Arguments
+/// * `dag` - Task dependencies.
+/// * `workers` - Number of tasks that can be worked on simultaneously.
+/// * `duration` - Number of ticks each task takes. Must be at least one.
+///
+/// # Panics
+/// Panics if the dependencies contain a cycle, as the tasks can never all finish.
+pub fn simulate<D>(dag: &Dag, workers: usize, duration: D) -> Schedule
+where
+    D: Fn(usize) -> u64,
+{
+    simulate_with(dag, workers, duration, |_| {})
+}
+
+/// Like [`simulate`], calling `on_tick` with the state at the start of every tick.
+///
+/// Useful for visualizations or for puzzles asking about intermediate states.
+pub fn simulate_with<D, F>(dag: &Dag, workers: usize, duration: D, mut on_tick: F) -> Schedule
+where
+    D: Fn(usize) -> u64,
+    F: FnMut(Tick<'_>),
+{
+    let mut degrees = dag.in_degrees();
+    let mut ready: BinaryHeap<_> = (0..dag.len())
+        .filter(|&task| degrees[task] == 0)
+        .map(Reverse)
+        .collect();
+
+    let mut busy: Vec<Option<(usize, u64)>> = vec![None; workers];
+    let mut assigned: Vec<Option<usize>> = vec![None; workers];
+    let mut order = Vec::with_capacity(dag.len());
+    let mut time = 0;
+
+    loop {
+        for (slot, task) in busy.iter_mut().zip(assigned.iter_mut()) {
+            if slot.is_none() {
+                if let Some(Reverse(next)) = ready.pop() {
+                    *slot = Some((next, duration(next)));
+                    *task = Some(next);
+                }
+            }
+        }
+
+        if busy.iter().all(Option::is_none) {
+            break;
+        }
+
+        on_tick(Tick {
+            time,
+            workers: &assigned,
+            done: &order,
+        });
+        time += 1;
+
+        let mut finished = Vec::new();
+        for (slot, task) in busy.iter_mut().zip(assigned.iter_mut()) {
+            if let Some((current, remaining)) = slot {
+                *remaining = remaining.saturating_sub(1);
+                if *remaining == 0 {
+                    finished.push(*current);
+                    *slot = None;
+                    *task = None;
+                }
+            }
+        }
+
+        finished.sort_unstable();
+        for task in finished {
+            order.push(task);
+            for &(to, _) in dag.neighbours(task) {
+                degrees[to] -= 1;
+                if degrees[to] == 0 {
+                    ready.push(Reverse(to));
+                }
+            }
+        }
+    }
+
+    assert_eq!(order.len(), dag.len(), "Dependencies contain a cycle");
+    Schedule { time, order }
+}
@@ -0,0 +1,215 @@
+//! Set algebra over small sorted collections.
+//!
+//! Many puzzles intersect or merge a handful of tiny sets per input line (ticket fields, badge
+//! groups). Allocating a `HashSet` for each dominates the runtime. Sorted slices support the same
+//! operations with a linear merge and no hashing at all.
+//!
+//! [`SmallSet`] stores up to `N` elements inline without touching the heap, spilling to a vector
+//! only when it grows larger. Elements are kept sorted and unique, so the slice helpers work on
+//! its contents directly.
+use std::cmp::Ordering;
+
+/// Returns the elements present in both sorted slices.
+pub fn intersection<T: Ord + Copy>(a: &[T], b: &[T]) -> Vec<T> {
+    merge(a, b, false, true, false)
+}
+
+/// Returns the elements present in either sorted slice.
+pub fn union<T: Ord + Copy>(a: &[T], b: &[T]) -> Vec<T> {
+    merge(a, b, true, true, true)
+}
+
+/// Returns the elements of `a` that are not in `b`. Both slices must be sorted.
+pub fn difference<T: Ord + Copy>(a: &[T], b: &[T]) -> Vec<T> {
+    merge(a, b, true, false, false)
+}
+
+/// Checks if every element of `a` is in `b`. Both slices must be sorted.
+pub fn is_subset<T: Ord>(a: &[T], b: &[T]) -> bool {
+    let mut b = b.iter();
+    a.iter().all(|x| b.by_ref().any(|y| y == x))
+}
+
+/// Walks both sorted slices in lockstep, keeping elements only in `a`, in both or only in `b`.
+fn merge<T: Ord + Copy>(a: &[T], b: &[T], only_a: bool, both: bool, only_b: bool) -> Vec<T> {
+    let mut result = Vec::with_capacity(a.len().max(b.len()));
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            Ordering::Less => {
+                if only_a {
+                    result.push(a[i]);
+                }
+                i += 1;
+            }
+            Ordering::Greater => {
+                if only_b {
+                    result.push(b[j]);
+                }
+                j += 1;
+            }
+            Ordering::Equal => {
+                if both {
+                    result.push(a[i]);
+                }
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+
+    if only_a {
+        result.extend_from_slice(&a[i..]);
+    }
+    if only_b {
+        result.extend_from_slice(&b[j..]);
+    }
+    result
+}
+
+/// A sorted set storing up to `N` elements inline.
+#[derive(Clone, Debug)]
+pub struct SmallSet<T, const N: usize> {
+    storage: Storage<T, N>,
+}
+
+#[derive(Clone, Debug)]
+enum Storage<T, const N: usize> {
+    Inline([T; N], usize),
+    Heap(Vec<T>),
+}
+
+impl<T: Ord + Copy + Default, const N: usize> SmallSet<T, N> {
+    pub fn new() -> Self {
+        Self {
+            storage: Storage::Inline([T::default(); N], 0),
+        }
+    }
+
+    /// Returns the elements in ascending order.
+    pub fn as_slice(&self) -> &[T] {
+        match &self.storage {
+            Storage::Inline(items, len) => &items[..*len],
+            Storage::Heap(items) => items,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.as_slice().is_empty()
+    }
+
+    /// Checks if the set has spilled to the heap.
+    pub fn spilled(&self) -> bool {
+        matches!(self.storage, Storage::Heap(_))
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        self.as_slice().binary_search(value).is_ok()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Adds a value, returning `false` if it was already present.
+    pub fn insert(&mut self, value: T) -> bool {
+        let Err(index) = self.as_slice().binary_search(&value) else {
+            return false;
+        };
+
+        match &mut self.storage {
+            Storage::Inline(items, len) if *len < N => {
+                items.copy_within(index..*len, index + 1);
+                items[index] = value;
+                *len += 1;
+            }
+            Storage::Inline(items, len) => {
+                let mut heap = items[..*len].to_vec();
+                heap.insert(index, value);
+                self.storage = Storage::Heap(heap);
+            }
+            Storage::Heap(items) => items.insert(index, value),
+        }
+
+        true
+    }
+
+    /// Removes a value, returning `false` if it was not present.
+    pub fn remove(&mut self, value: &T) -> bool {
+        let Ok(index) = self.as_slice().binary_search(value) else {
+            return false;
+        };
+
+        match &mut self.storage {
+            Storage::Inline(items, len) => {
+                items.copy_within(index + 1..*len, index);
+                *len -= 1;
+            }
+            Storage::Heap(items) => {
+                items.remove(index);
+            }
+        }
+
+        true
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        intersection(self.as_slice(), other.as_slice())
+            .into_iter()
+            .collect()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        union(self.as_slice(), other.as_slice())
+            .into_iter()
+            .collect()
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        difference(self.as_slice(), other.as_slice())
+            .into_iter()
+            .collect()
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        is_subset(self.as_slice(), other.as_slice())
+    }
+}
+
+impl<T: Ord + Copy + Default, const N: usize> Default for SmallSet<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Copy + Default, const N: usize> PartialEq for SmallSet<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Ord + Copy + Default, const N: usize> Eq for SmallSet<T, N> {}
+
+impl<T: Ord + Copy + Default, const N: usize> FromIterator<T> for SmallSet<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::new();
+        iter.into_iter().for_each(|value| {
+            set.insert(value);
+        });
+        set
+    }
+}
+
+impl<'a, T: Ord + Copy + Default, const N: usize> IntoIterator for &'a SmallSet<T, N> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
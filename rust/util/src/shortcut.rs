@@ -0,0 +1,72 @@
+//! Evaluates shortcuts that skip through walls on an otherwise fixed path.
+//!
+//! A shortcut lets the walker leave the track at any reachable cell `a` and rejoin it at a cell
+//! `b` at most `radius` steps away in manhattan distance, ignoring walls in between. The length
+//! of the best path using that shortcut is
+//!
+//! ```none
+//!   from_start[a] + manhattan(a, b) + to_end[b]
+//! ```
+//!
+//! so two BFS distance maps, one from each end of the track, are enough to score every
+//! shortcut in constant time. Only pairs within the radius are enumerated, in `O(cells * r²)`.
+use crate::conversions::FromChar;
+use crate::grid::Grid;
+use crate::pathfinding::distance_map;
+use crate::point::Point;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+/// Computes the time saved by every shortcut that improves on the regular path.
+///
+/// # Arguments
+/// * `grid` - The track, where `passable` cells can be walked on.
+/// * `start`, `end` - Both ends of the track.
+/// * `radius` - Maximum manhattan length of a shortcut.
+///
+/// # Returns
+/// * The saving of every distinct `(a, b)` shortcut with a positive saving, in no particular
+///   order. Empty if `end` is not reachable at all.
+pub fn savings<T, F>(grid: &Grid<T>, start: Point, end: Point, radius: i32, passable: F) -> Vec<i32>
+where
+    T: Default + Clone + Debug + PartialEq,
+    T: FromStr + FromChar,
+    <T as FromStr>::Err: Debug,
+    <T as FromChar>::Err: Debug,
+    F: Fn(&T) -> bool,
+{
+    let from_start = distance_map(grid, start, &passable);
+    let to_end = distance_map(grid, end, &passable);
+
+    let Some(honest) = from_start.get_value(&end).filter(|&d| d >= 0) else {
+        return Vec::new();
+    };
+
+    let mut result = Vec::new();
+
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let a = Point::new(x, y);
+            let Some(before) = from_start.get_value(&a).filter(|&d| d >= 0) else {
+                continue;
+            };
+
+            for dy in -radius..=radius {
+                let remaining = radius - dy.abs();
+                for dx in -remaining..=remaining {
+                    let b = a.add(&Point::new(dx, dy));
+                    let Some(after) = to_end.get_value(&b).filter(|&d| d >= 0) else {
+                        continue;
+                    };
+
+                    let saving = honest - (before + dx.abs() + dy.abs() + after);
+                    if saving > 0 {
+                        result.push(saving);
+                    }
+                }
+            }
+        }
+    }
+
+    result
+}
@@ -0,0 +1,142 @@
+//! Compact `u64` encoding of small search states and breadth-first search over them.
+//!
+//! States in key-and-door mazes and similar puzzles combine a few small fields, e.g. a position
+//! and a bitmask of collected keys. Hashing a struct of such fields is the bottleneck of the
+//! search, while hashing (or directly indexing) a single `u64` is cheap. A [`Layout`] describes
+//! how fields are packed into consecutive bit ranges of the key, lowest field first.
+//!
+//! [`bfs`] explores keys using the fast hasher. When every key is known to be smaller than some
+//! bound, [`bfs_dense`] replaces the hash set with a direct-addressed table, which is faster
+//! still at the cost of memory proportional to the bound.
+use crate::hash::*;
+use std::collections::VecDeque;
+
+/// Bit layout of the fields packed into a `u64` key.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Layout {
+    widths: Vec<u32>,
+    shifts: Vec<u32>,
+}
+
+impl Layout {
+    /// Creates a layout from the bit width of every field.
+    ///
+    /// # Panics
+    /// Panics if the fields need more than 64 bits in total.
+    pub fn new(widths: &[u32]) -> Self {
+        let mut shifts = Vec::with_capacity(widths.len());
+        let mut total = 0;
+        for &width in widths {
+            shifts.push(total);
+            total += width;
+        }
+        assert!(
+            total <= 64,
+            "Layout needs {total} bits, only 64 are available"
+        );
+
+        Self {
+            widths: widths.to_vec(),
+            shifts,
+        }
+    }
+
+    /// Returns the total number of bits used, which bounds every key by `1 << bits`.
+    pub fn bits(&self) -> u32 {
+        self.widths.iter().sum()
+    }
+
+    /// Packs one value per field into a key. Values are truncated to their field width.
+    pub fn encode(&self, fields: &[u64]) -> u64 {
+        fields.iter().enumerate().fold(0, |key, (i, &value)| {
+            key | (value & self.mask(i)) << self.shifts[i]
+        })
+    }
+
+    /// Unpacks every field of a key.
+    pub fn decode(&self, key: u64) -> Vec<u64> {
+        (0..self.widths.len()).map(|i| self.get(key, i)).collect()
+    }
+
+    /// Returns a single field of the key.
+    #[inline]
+    pub fn get(&self, key: u64, field: usize) -> u64 {
+        key >> self.shifts[field] & self.mask(field)
+    }
+
+    /// Returns a copy of the key with a single field replaced.
+    #[inline]
+    pub fn set(&self, key: u64, field: usize, value: u64) -> u64 {
+        let mask = self.mask(field) << self.shifts[field];
+        key & !mask | (value << self.shifts[field]) & mask
+    }
+
+    #[inline]
+    fn mask(&self, field: usize) -> u64 {
+        match self.widths[field] {
+            64 => u64::MAX,
+            width => (1 << width) - 1,
+        }
+    }
+}
+
+/// Finds the number of steps from `start` to the nearest key satisfying `goal`.
+///
+/// # Arguments
+/// * `start` - The initial key.
+/// * `neighbours` - Pushes every key reachable in one step from the given key.
+/// * `goal` - Checks if a key is a target.
+///
+/// # Returns
+/// * The distance to the nearest goal, or `None` if no goal is reachable.
+pub fn bfs<N, G>(start: u64, mut neighbours: N, goal: G) -> Option<u32>
+where
+    N: FnMut(u64, &mut Vec<u64>),
+    G: Fn(u64) -> bool,
+{
+    let mut seen = FastSet::new();
+    seen.insert(start);
+    search(start, &mut neighbours, goal, |key| seen.insert(key))
+}
+
+/// Like [`bfs`], tracking visited keys in a table of `size` entries indexed by key.
+///
+/// # Panics
+/// Panics if a key is not smaller than `size`.
+pub fn bfs_dense<N, G>(start: u64, size: usize, mut neighbours: N, goal: G) -> Option<u32>
+where
+    N: FnMut(u64, &mut Vec<u64>),
+    G: Fn(u64) -> bool,
+{
+    let mut seen = vec![false; size];
+    seen[start as usize] = true;
+    search(start, &mut neighbours, goal, |key| {
+        !std::mem::replace(&mut seen[key as usize], true)
+    })
+}
+
+fn search<N, G, V>(start: u64, neighbours: &mut N, goal: G, mut visit: V) -> Option<u32>
+where
+    N: FnMut(u64, &mut Vec<u64>),
+    G: Fn(u64) -> bool,
+    V: FnMut(u64) -> bool,
+{
+    let mut queue = VecDeque::from([(start, 0)]);
+    let mut next = Vec::new();
+
+    while let Some((key, steps)) = queue.pop_front() {
+        if goal(key) {
+            return Some(steps);
+        }
+
+        next.clear();
+        neighbours(key, &mut next);
+        for &candidate in &next {
+            if visit(candidate) {
+                queue.push_back((candidate, steps + 1));
+            }
+        }
+    }
+
+    None
+}
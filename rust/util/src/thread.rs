@@ -0,0 +1,95 @@
+//! Spreading independent work over threads without making the answer depend on scheduling.
+//!
+//! Items are handed out to scoped worker threads one at a time, so uneven items still keep
+//! every thread busy. Results are put back in item order before they are combined, so
+//! [`map_reduce`] folds exactly like a sequential loop would. That keeps answers identical across
+//! runs and thread counts even when `combine` is not associative, such as keeping the first of
+//! several equally good candidates.
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+/// The number of threads the machine can run in parallel, at least one.
+pub fn threads() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// Maps every item on `threads` worker threads, returning the results in item order.
+///
+/// # Arguments
+/// * `items` - The work to spread out.
+/// * `threads` - Number of worker threads, clamped between one and the number of items.
+/// * `init` - Creates the scratch state of a worker, such as its own copy of a grid.
+/// * `map` - Maps one item, with the scratch state of the worker running it.
+pub fn parallel_map_init<T, S, R, I, M>(items: &[T], threads: usize, init: I, map: M) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    I: Fn() -> S + Sync,
+    M: Fn(&mut S, &T) -> R + Sync,
+{
+    let workers = threads.clamp(1, items.len().max(1));
+    let next = AtomicUsize::new(0);
+
+    let mut indexed: Vec<(usize, R)> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut state = init();
+                    let mut results = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(index) else {
+                            break;
+                        };
+                        results.push((index, map(&mut state, item)));
+                    }
+                    results
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    indexed.sort_unstable_by_key(|&(index, _)| index);
+    indexed.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Maps every item on `threads` worker threads, see [`parallel_map_init`].
+pub fn parallel_map<T, R, M>(items: &[T], threads: usize, map: M) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    M: Fn(&T) -> R + Sync,
+{
+    parallel_map_init(items, threads, || (), |_, item| map(item))
+}
+
+/// Maps every item in parallel, then folds the results in item order.
+///
+/// # Returns
+/// * `combine(...combine(combine(identity, map(items[0])), map(items[1]))..., map(items[n - 1]))`
+pub fn map_reduce<T, R, M, C>(items: &[T], threads: usize, map: M, identity: R, combine: C) -> R
+where
+    T: Sync,
+    R: Send,
+    M: Fn(&T) -> R + Sync,
+    C: Fn(R, R) -> R,
+{
+    parallel_map(items, threads, map)
+        .into_iter()
+        .fold(identity, combine)
+}
+
+/// Sums the mapped items, computed in parallel.
+pub fn parallel_sum<T, R, M>(items: &[T], threads: usize, map: M) -> R
+where
+    T: Sync,
+    R: Send + std::iter::Sum<R>,
+    M: Fn(&T) -> R + Sync,
+{
+    parallel_map(items, threads, map).into_iter().sum()
+}
@@ -0,0 +1,116 @@
+//! Tilting a grid so that its movable cells slide until they are blocked, as rolling rocks do on
+//! a tilted platform.
+//!
+//! Fixed obstacles never move, so they are indexed once in [`Blockers`] and every tilt looks up
+//! where each movable cell would stop on its own. Cells stopping in front of the same obstacle
+//! pile up behind one another, so a count per stopping point is all that is needed to place
+//! them, without stepping through the grid cell by cell:
+//!
+//! ```none
+//! O.#.             OO#O
+//! ..O.   tilt up   ..O.
+//! .O.O             ....
+//! ```
+//!
+//! [`spin`] tilts in each orthogonal direction in turn, and [`spin_cycles`] skips ahead through
+//! a billion of those once the positions start repeating, see [`nth_with_cycle`].
+use crate::blockers::Blockers;
+use crate::conversions::FromChar;
+use crate::cycle::nth_with_cycle;
+use crate::direction::Direction;
+use crate::grid::Grid;
+use crate::point::Point;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::str::FromStr;
+
+/// The directions of one spin cycle: north, west, south and east.
+pub const SPIN: [Direction; 4] = [
+    Direction::Up,
+    Direction::Left,
+    Direction::Down,
+    Direction::Right,
+];
+
+/// Slides every movable cell as far as it goes in an orthogonal direction.
+///
+/// # Arguments
+/// * `grid` - The grid to tilt in place.
+/// * `blockers` - The fixed obstacles of the grid.
+/// * `direction` - Where the movable cells slide to.
+/// * `movable` - The value of the cells that slide.
+/// * `empty` - The value left behind where a movable cell was.
+///
+/// # Panics
+/// Panics if the direction is diagonal or `Stop`.
+pub fn tilt<T>(
+    grid: &mut Grid<T>,
+    blockers: &Blockers,
+    direction: &Direction,
+    movable: &T,
+    empty: &T,
+) where
+    T: Default + Clone + Debug + PartialEq,
+    T: FromStr + FromChar,
+    <T as FromStr>::Err: Debug,
+    <T as FromChar>::Err: Debug,
+{
+    let step = direction.to_point();
+    let moving: Vec<_> = grid
+        .points()
+        .filter(|point| grid.get_value(point).as_ref() == Some(movable))
+        .collect();
+    let mut piled = vec![0; grid.cells().len()];
+
+    for point in &moving {
+        grid.set_value(point, empty.clone());
+    }
+    for point in &moving {
+        let stop = blockers.slide(point, direction);
+        let below = &mut piled[grid.index(&stop)];
+        let target = Point::new(stop.x - step.x * *below, stop.y - step.y * *below);
+        *below += 1;
+        grid.set_value(&target, movable.clone());
+    }
+}
+
+/// Tilts the grid once in each direction of [`SPIN`].
+pub fn spin<T>(grid: &mut Grid<T>, blockers: &Blockers, movable: &T, empty: &T)
+where
+    T: Default + Clone + Debug + PartialEq,
+    T: FromStr + FromChar,
+    <T as FromStr>::Err: Debug,
+    <T as FromChar>::Err: Debug,
+{
+    for direction in &SPIN {
+        tilt(grid, blockers, direction, movable, empty);
+    }
+}
+
+/// Returns the grid after `n` spin cycles.
+///
+/// The movable cells settle into a loop of positions after a while, so only the cycles up to
+/// the first repetition are simulated.
+pub fn spin_cycles<T>(
+    grid: &Grid<T>,
+    blockers: &Blockers,
+    movable: &T,
+    empty: &T,
+    n: usize,
+) -> Grid<T>
+where
+    T: Default + Clone + Debug + Eq + Hash,
+    T: FromStr + FromChar,
+    <T as FromStr>::Err: Debug,
+    <T as FromChar>::Err: Debug,
+{
+    nth_with_cycle(
+        grid.clone(),
+        |grid| {
+            let mut next = grid.clone();
+            spin(&mut next, blockers, movable, empty);
+            next
+        },
+        n,
+    )
+}
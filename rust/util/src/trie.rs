@@ -0,0 +1,125 @@
+//! Prefix tree over byte strings.
+//!
+//! Nodes are stored in a single vector and refer to their children by index, which avoids a
+//! heap allocation per node. Children are kept in a small list searched linearly, as puzzle
+//! alphabets are tiny and this beats hashing at such sizes.
+//!
+//! Besides membership and prefix queries, [`Trie::count_compositions`] counts the ways a string
+//! can be built by concatenating patterns, e.g. arranging towels into a design. It fills a
+//! table from the end of the string backwards, the iterative form of memoized recursion.
+
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+struct Node {
+    children: Vec<(u8, usize)>,
+    terminal: bool,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Trie {
+    nodes: Vec<Node>,
+}
+
+impl Default for Trie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Trie {
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![Node::default()],
+        }
+    }
+
+    /// Builds a trie containing every pattern.
+    pub fn from_patterns<'a, I>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = &'a [u8]>,
+    {
+        let mut trie = Self::new();
+        patterns
+            .into_iter()
+            .for_each(|pattern| trie.insert(pattern));
+        trie
+    }
+
+    pub fn insert(&mut self, pattern: &[u8]) {
+        let mut node = 0;
+
+        for &byte in pattern {
+            node = match self.child(node, byte) {
+                Some(child) => child,
+                None => {
+                    let child = self.nodes.len();
+                    self.nodes.push(Node::default());
+                    self.nodes[node].children.push((byte, child));
+                    child
+                }
+            };
+        }
+
+        self.nodes[node].terminal = true;
+    }
+
+    /// Checks if the exact pattern was inserted.
+    pub fn contains(&self, pattern: &[u8]) -> bool {
+        let mut node = 0;
+        for &byte in pattern {
+            match self.child(node, byte) {
+                Some(child) => node = child,
+                None => return false,
+            }
+        }
+        self.nodes[node].terminal
+    }
+
+    /// Returns the lengths of every pattern that is a prefix of `text`, shortest first.
+    pub fn prefixes<'a>(&'a self, text: &'a [u8]) -> impl Iterator<Item = usize> + 'a {
+        let mut node = Some(0);
+
+        // Length zero is only a match if the empty pattern was inserted.
+        (0..=text.len()).filter_map(move |len| {
+            let current = node?;
+            node = text.get(len).and_then(|&byte| self.child(current, byte));
+            self.nodes[current].terminal.then_some(len)
+        })
+    }
+
+    /// Returns the length of the shortest pattern that is a prefix of `text`.
+    pub fn shortest_prefix(&self, text: &[u8]) -> Option<usize> {
+        self.prefixes(text).next()
+    }
+
+    /// Returns the length of the longest pattern that is a prefix of `text`.
+    pub fn longest_prefix(&self, text: &[u8]) -> Option<usize> {
+        self.prefixes(text).last()
+    }
+
+    /// Counts the ways `text` can be split into a sequence of patterns.
+    ///
+    /// Zero means the text cannot be built at all. The empty text can always be built in
+    /// exactly one way.
+    pub fn count_compositions(&self, text: &[u8]) -> u64 {
+        let mut ways = vec![0u64; text.len() + 1];
+        ways[text.len()] = 1;
+
+        for start in (0..text.len()).rev() {
+            ways[start] = self
+                .prefixes(&text[start..])
+                .filter(|&len| len > 0)
+                .map(|len| ways[start + len])
+                .sum();
+        }
+
+        ways[0]
+    }
+
+    fn child(&self, node: usize, byte: u8) -> Option<usize> {
+        self.nodes[node]
+            .children
+            .iter()
+            .find(|&&(b, _)| b == byte)
+            .map(|&(_, child)| child)
+    }
+}
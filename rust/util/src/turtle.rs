@@ -0,0 +1,112 @@
+//! Turtle graphics style walker that remembers where it has been.
+//!
+//! Wire and walking instruction puzzles describe a path as a list of turns and moves, either
+//! relative to the current heading (`L2, R3`) or in absolute directions (`R8, U5`). The turtle
+//! executes both forms, recording every visited point in order and one segment per move, which
+//! can then be intersected with another path.
+use crate::direction::Direction;
+use crate::point::Point;
+
+/// A single instruction for the turtle.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Command {
+    TurnLeft,
+    TurnRight,
+    /// Moves the given number of steps along the current heading.
+    Forward(i32),
+    /// Faces the direction, then moves the given number of steps.
+    Walk(Direction, i32),
+}
+
+/// A walker with a position, a heading and the path taken so far.
+///
+/// # Fields
+/// - `position`: The current position.
+/// - `heading`: The direction `Forward` moves in. Must be orthogonal to be turned.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Turtle {
+    pub position: Point,
+    pub heading: Direction,
+    path: Vec<Point>,
+    segments: Vec<(Point, Point)>,
+}
+
+impl Turtle {
+    pub fn new(position: Point, heading: Direction) -> Self {
+        Self {
+            position,
+            heading,
+            path: vec![position],
+            segments: Vec::new(),
+        }
+    }
+
+    pub fn execute(&mut self, command: Command) {
+        match command {
+            Command::TurnLeft => self.turn_left(),
+            Command::TurnRight => self.turn_right(),
+            Command::Forward(steps) => self.forward(steps),
+            Command::Walk(direction, steps) => self.walk(direction, steps),
+        }
+    }
+
+    pub fn turn_left(&mut self) {
+        self.heading = self.heading.turn_left();
+    }
+
+    pub fn turn_right(&mut self) {
+        self.heading = self.heading.turn_right();
+    }
+
+    /// Moves along the current heading one step at a time, recording each point.
+    /// Zero steps are ignored, and a negative number of steps walks backwards.
+    pub fn forward(&mut self, steps: i32) {
+        if steps == 0 {
+            return;
+        }
+
+        let start = self.position;
+        let step = Point::from(self.heading);
+        let step = if steps < 0 {
+            Point::EMPTY.sub(&step)
+        } else {
+            step
+        };
+
+        for _ in 0..steps.abs() {
+            self.position = self.position.add(&step);
+            self.path.push(self.position);
+        }
+        self.segments.push((start, self.position));
+    }
+
+    /// Faces `direction`, then moves `steps` steps.
+    pub fn walk(&mut self, direction: Direction, steps: i32) {
+        self.heading = direction;
+        self.forward(steps);
+    }
+
+    /// Walks a list of absolute moves, such as those returned by
+    /// [`parse_moves`](crate::parse::parse_moves).
+    pub fn walk_all(&mut self, moves: &[(Direction, i32)]) {
+        moves
+            .iter()
+            .for_each(|&(direction, steps)| self.walk(direction, steps));
+    }
+
+    /// Every visited point in order, starting with the initial position. Points visited more
+    /// than once appear more than once.
+    pub fn path(&self) -> &[Point] {
+        &self.path
+    }
+
+    /// The `(start, end)` of every move that changed the position.
+    pub fn segments(&self) -> &[(Point, Point)] {
+        &self.segments
+    }
+
+    /// The number of steps taken so far.
+    pub fn distance(&self) -> usize {
+        self.path.len() - 1
+    }
+}
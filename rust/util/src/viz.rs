@@ -0,0 +1,332 @@
+//! Keeping visualisations of long simulations small enough to watch.
+//!
+//! Some puzzles simulate millions of steps on grids far larger than a terminal. Two tools keep
+//! their visualisations usable:
+//!
+//! * [`Downsampler`] keeps at most a fixed number of frames without knowing the number of steps
+//!   up front. Every time the budget fills up, every second frame is dropped and the sampling
+//!   stride doubles, so the kept frames stay evenly spaced over the whole run. Frames that would
+//!   be skipped are never rendered.
+//! * [`Region`] is a bounding box of the cells that change between frames. Cropping every frame
+//!   to it, see [`focus`], hides the parts of the grid that never move.
+//!
+//! Frames are treated as plain text, one character per cell, so cropping is only meaningful for
+//! renders without ANSI escape codes.
+//!
+//! Numeric grids, such as BFS distance maps or visit counts, read better as colors than as
+//! digits. [`heatmap`] renders them with 24 bit ANSI background colors, scaled so the smallest
+//! value is dark blue and the largest dark red, followed by a legend. [`ppm`] writes the same
+//! colors as an image for export.
+use crate::ansi::RESET;
+use crate::grid::Grid;
+use crate::point::Point;
+
+/// Evenly spaced sampling of a stream of frames with a fixed budget.
+///
+/// # Fields
+/// - `max_frames`: The most frames ever kept, at least two.
+/// - `stride`: Only every `stride`th offered frame is currently kept.
+/// - `offered`: Number of frames offered so far.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Downsampler<T> {
+    max_frames: usize,
+    stride: usize,
+    offered: usize,
+    frames: Vec<T>,
+}
+
+impl<T> Downsampler<T> {
+    /// Creates an empty sampler keeping at most `max_frames` frames.
+    ///
+    /// # Panics
+    /// Panics if `max_frames` is less than two.
+    pub const fn new(max_frames: usize) -> Self {
+        assert!(max_frames >= 2, "Frame budget must be at least two");
+        Downsampler {
+            max_frames,
+            stride: 1,
+            offered: 0,
+            frames: Vec::new(),
+        }
+    }
+
+    /// Offers the next frame, rendering it only if it is kept.
+    ///
+    /// # Returns
+    /// * `true` if the frame was rendered and kept.
+    pub fn offer<F>(&mut self, render: F) -> bool
+    where
+        F: FnOnce() -> T,
+    {
+        let index = self.offered;
+        self.offered += 1;
+        if index % self.stride != 0 {
+            return false;
+        }
+
+        self.frames.push(render());
+        if self.frames.len() == self.max_frames {
+            // Keep the frames at even multiples of the stride, then sample half as often.
+            let mut index = 0;
+            self.frames.retain(|_| {
+                index += 1;
+                index % 2 == 1
+            });
+            self.stride *= 2;
+        }
+        true
+    }
+
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    pub fn offered(&self) -> usize {
+        self.offered
+    }
+
+    pub fn frames(&self) -> &[T] {
+        &self.frames
+    }
+
+    /// Returns the kept frames, starting over with an empty sampler.
+    pub fn take(&mut self) -> Vec<T> {
+        self.stride = 1;
+        self.offered = 0;
+        std::mem::take(&mut self.frames)
+    }
+}
+
+/// An inclusive rectangle of cells.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Region {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Region {
+    /// The smallest region containing every point, `None` if there are none.
+    pub fn from_points<I>(points: I) -> Option<Region>
+    where
+        I: IntoIterator<Item = Point>,
+    {
+        points.into_iter().fold(None, |region, point| {
+            let single = Region {
+                min: point,
+                max: point,
+            };
+            Some(region.map_or(single, |region: Region| region.union(&single)))
+        })
+    }
+
+    /// The region of the cells that differ between two grids of the same size.
+    pub fn changed<T: PartialEq>(before: &Grid<T>, after: &Grid<T>) -> Option<Region> {
+        Region::from_points(before.rows().zip(after.rows()).enumerate().flat_map(
+            |(y, (left, right))| {
+                left.iter()
+                    .zip(right)
+                    .enumerate()
+                    .filter(|(_, (a, b))| a != b)
+                    .map(move |(x, _)| Point::new(x as i32, y as i32))
+            },
+        ))
+    }
+
+    /// The region of the characters that differ between two text frames.
+    ///
+    /// Characters missing from the shorter frame count as changed.
+    pub fn changed_text(before: &str, after: &str) -> Option<Region> {
+        let rows = before.lines().count().max(after.lines().count());
+        let mut before_lines = before.lines();
+        let mut after_lines = after.lines();
+
+        Region::from_points((0..rows).flat_map(|y| {
+            let left: Vec<char> = before_lines.next().unwrap_or_default().chars().collect();
+            let right: Vec<char> = after_lines.next().unwrap_or_default().chars().collect();
+            (0..left.len().max(right.len()))
+                .filter(move |&x| left.get(x) != right.get(x))
+                .map(move |x| Point::new(x as i32, y as i32))
+                .collect::<Vec<_>>()
+        }))
+    }
+
+    pub fn union(&self, other: &Region) -> Region {
+        Region {
+            min: Point::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+            max: Point::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y)),
+        }
+    }
+
+    /// Grows the region by `margin` cells on every side, without going below zero.
+    pub fn expand(&self, margin: i32) -> Region {
+        Region {
+            min: Point::new((self.min.x - margin).max(0), (self.min.y - margin).max(0)),
+            max: Point::new(self.max.x + margin, self.max.y + margin),
+        }
+    }
+
+    pub fn width(&self) -> i32 {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> i32 {
+        self.max.y - self.min.y + 1
+    }
+
+    /// Cuts the region out of a text frame, one line per row.
+    ///
+    /// Parts of the region beyond the end of the frame are left out rather than padded.
+    pub fn crop(&self, frame: &str) -> String {
+        let mut result = String::new();
+
+        for line in frame
+            .lines()
+            .skip(self.min.y as usize)
+            .take(self.height() as usize)
+        {
+            result.extend(
+                line.chars()
+                    .skip(self.min.x as usize)
+                    .take(self.width() as usize),
+            );
+            result.push('\n');
+        }
+
+        result
+    }
+}
+
+/// Crops every frame to the cells that change anywhere in the sequence.
+///
+/// # Arguments
+/// * `frames` - Text frames in the order they were rendered.
+/// * `margin` - Unchanged cells to keep around the changing area for context.
+///
+/// # Returns
+/// * The cropped frames, or the frames unchanged if nothing ever changes.
+pub fn focus(frames: &[String], margin: i32) -> Vec<String> {
+    let region = frames
+        .windows(2)
+        .filter_map(|pair| Region::changed_text(&pair[0], &pair[1]))
+        .reduce(|a, b| a.union(&b));
+
+    match region {
+        Some(region) => {
+            let region = region.expand(margin);
+            frames.iter().map(|frame| region.crop(frame)).collect()
+        }
+        None => frames.to_vec(),
+    }
+}
+
+/// Color stops of the heatmap gradient, from the smallest to the largest value.
+const GRADIENT: [[u8; 3]; 5] = [
+    [0, 0, 128],
+    [0, 160, 255],
+    [0, 200, 0],
+    [255, 220, 0],
+    [160, 0, 0],
+];
+
+/// Swatches in the heatmap legend.
+const LEGEND_STEPS: usize = 10;
+
+/// Linear mapping of the values of a grid onto the heatmap gradient.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Scale {
+    pub min: u32,
+    pub max: u32,
+}
+
+impl Scale {
+    /// The scale spanning the smallest to the largest value of the grid.
+    pub fn of(grid: &Grid<u32>) -> Scale {
+        let values = || grid.cells().iter().copied();
+        Scale {
+            min: values().min().unwrap_or(0),
+            max: values().max().unwrap_or(0),
+        }
+    }
+
+    /// Position of a value between `min` and `max`, from `0.0` to `1.0`.
+    ///
+    /// Values outside of the scale are clamped. A scale of a single value maps it to `0.0`.
+    pub fn fraction(&self, value: u32) -> f64 {
+        if self.max <= self.min {
+            return 0.0;
+        }
+        let value = value.clamp(self.min, self.max);
+        (value - self.min) as f64 / (self.max - self.min) as f64
+    }
+
+    /// The RGB color of a value.
+    pub fn color(&self, value: u32) -> [u8; 3] {
+        gradient(self.fraction(value))
+    }
+}
+
+/// Interpolates the heatmap gradient at `t`, clamped to `0.0..=1.0`.
+pub fn gradient(t: f64) -> [u8; 3] {
+    let position = t.clamp(0.0, 1.0) * (GRADIENT.len() - 1) as f64;
+    let index = (position as usize).min(GRADIENT.len() - 2);
+    let local = position - index as f64;
+    let (from, to) = (GRADIENT[index], GRADIENT[index + 1]);
+
+    std::array::from_fn(|channel| {
+        let (a, b) = (from[channel] as f64, to[channel] as f64);
+        (a + (b - a) * local).round() as u8
+    })
+}
+
+/// Renders a numeric grid as colored cells, followed by a legend of the scale.
+///
+/// Each cell is two spaces wide so cells look roughly square in a terminal.
+///
+/// # Returns
+/// * A string with one line per row and a final legend line, containing ANSI escape codes.
+pub fn heatmap(grid: &Grid<u32>) -> String {
+    let scale = Scale::of(grid);
+    let mut result = String::new();
+
+    for row in grid.rows() {
+        for &value in row {
+            result.push_str(&background(scale.color(value)));
+            result.push_str("  ");
+        }
+        result.push_str(RESET);
+        result.push('\n');
+    }
+
+    result.push_str(&legend(&scale));
+    result
+}
+
+/// A strip of evenly spaced colors of the scale, labelled with its bounds.
+pub fn legend(scale: &Scale) -> String {
+    let mut result = format!("{} ", scale.min);
+    for step in 0..LEGEND_STEPS {
+        let t = step as f64 / (LEGEND_STEPS - 1) as f64;
+        result.push_str(&background(gradient(t)));
+        result.push_str("  ");
+    }
+    result.push_str(&format!("{RESET} {}\n", scale.max));
+    result
+}
+
+/// Encodes a numeric grid as a binary PPM image with one pixel per cell.
+pub fn ppm(grid: &Grid<u32>) -> Vec<u8> {
+    let scale = Scale::of(grid);
+    let mut result = format!("P6\n{} {}\n255\n", grid.width, grid.height).into_bytes();
+
+    for row in grid.rows() {
+        for &value in row {
+            result.extend(scale.color(value));
+        }
+    }
+
+    result
+}
+
+fn background([r, g, b]: [u8; 3]) -> String {
+    format!("\x1b[48;2;{r};{g};{b}m")
+}
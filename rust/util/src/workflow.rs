@@ -0,0 +1,227 @@
+//! Chains of conditional rules compiled into a binary decision tree.
+//!
+//! Workflows look like `px{a<2006:qkq,m>2090:A,rfg}`: each rule tests one variable against a
+//! constant and jumps to another workflow, `A` to accept or `R` to reject, with the last entry
+//! as the fallback. Starting from one workflow and inlining every jump gives a tree where each
+//! node asks a single question, `value < threshold`. Both comparison operators become that
+//! question, with `x > n` written as `x < n + 1` and the branches swapped.
+//!
+//! The tree can be evaluated for concrete values, or for whole boxes of values at once by
+//! splitting each box at the thresholds, which counts every accepted combination without
+//! enumerating them.
+use crate::geometry::Hyperbox;
+use crate::hash::*;
+use crate::parse::*;
+use std::ops::Range;
+
+/// A node of the decision tree.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Node {
+    Accept,
+    Reject,
+    /// Follows `then` when `values[variable] < below`, otherwise `otherwise`.
+    Test {
+        variable: usize,
+        below: u64,
+        then: Box<Node>,
+        otherwise: Box<Node>,
+    },
+}
+
+impl Node {
+    pub fn evaluate(&self, values: &[u64]) -> bool {
+        match self {
+            Node::Accept => true,
+            Node::Reject => false,
+            Node::Test {
+                variable,
+                below,
+                then,
+                otherwise,
+            } => {
+                if values[*variable] < *below {
+                    then.evaluate(values)
+                } else {
+                    otherwise.evaluate(values)
+                }
+            }
+        }
+    }
+
+    /// Counts the combinations inside `bounds` that are accepted.
+    ///
+    /// # Arguments
+    /// * `bounds` - Half open range of values for each variable.
+    pub fn count(&self, bounds: &[Range<u64>]) -> u64 {
+        match self {
+            Node::Accept => bounds
+                .iter()
+                .map(|r| r.end.saturating_sub(r.start))
+                .product(),
+            Node::Reject => 0,
+            Node::Test {
+                variable,
+                below,
+                then,
+                otherwise,
+            } => {
+                let range = &bounds[*variable];
+                let split = (*below).clamp(range.start, range.end.max(range.start));
+                let mut low = bounds.to_vec();
+                let mut high = bounds.to_vec();
+                low[*variable] = range.start..split;
+                high[*variable] = split..range.end;
+                then.count(&low) + otherwise.count(&high)
+            }
+        }
+    }
+
+    /// Splits `bounds` into the disjoint boxes of accepted combinations, one per accepting leaf.
+    pub fn accepted_boxes<const N: usize>(&self, bounds: Hyperbox<N>) -> Vec<Hyperbox<N>> {
+        if bounds.is_empty() {
+            return Vec::new();
+        }
+
+        match self {
+            Node::Accept => vec![bounds],
+            Node::Reject => Vec::new(),
+            Node::Test {
+                variable,
+                below,
+                then,
+                otherwise,
+            } => {
+                let split = (*below as i64).clamp(bounds.min[*variable], bounds.max[*variable]);
+                let (mut low, mut high) = (bounds, bounds);
+                low.max[*variable] = split;
+                high.min[*variable] = split;
+
+                let mut result = then.accepted_boxes(low);
+                result.extend(otherwise.accepted_boxes(high));
+                result
+            }
+        }
+    }
+}
+
+/// Where a rule sends the values.
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum Target {
+    Accept,
+    Reject,
+    Workflow(String),
+}
+
+/// `(variable, below, then on true)` plus the target.
+type Rule = (Option<(usize, u64, bool)>, Target);
+
+/// Named workflows over a fixed set of single letter variables.
+#[derive(Clone, Debug, Default)]
+pub struct Workflows {
+    variables: Vec<char>,
+    workflows: FastMap<String, Vec<Rule>>,
+}
+
+impl Workflows {
+    /// Parses one workflow per line until the first blank line.
+    ///
+    /// # Arguments
+    /// * `input` - Lines such as `px{a<2006:qkq,m>2090:A,rfg}`.
+    /// * `variables` - The variable names in index order, such as `"xmas"`.
+    ///
+    /// # Panics
+    /// Panics if a rule uses an unknown variable or operator.
+    pub fn parse(input: &str, variables: &str) -> Self {
+        let variables: Vec<char> = variables.chars().collect();
+        let mut workflows = FastMap::new();
+
+        for line in input.lines().take_while(|line| !line.trim().is_empty()) {
+            let (name, body) = line.trim().split_once('{').expect("Missing workflow body");
+            let rules = body
+                .trim_end_matches('}')
+                .split(',')
+                .map(|rule| match rule.split_once(':') {
+                    Some((condition, target)) => {
+                        let mut chars = condition.chars();
+                        let variable = chars.next().unwrap_or_default();
+                        let index = variables
+                            .iter()
+                            .position(|&v| v == variable)
+                            .unwrap_or_else(|| panic!("Unknown variable in \"{rule}\""));
+                        let value: u64 = condition.unsigned();
+                        let test = match chars.next() {
+                            Some('<') => (index, value, true),
+                            Some('>') => (index, value + 1, false),
+                            _ => panic!("Unknown operator in \"{rule}\""),
+                        };
+                        (Some(test), target_of(target))
+                    }
+                    None => (None, target_of(rule)),
+                })
+                .collect();
+            workflows.insert(name.to_string(), rules);
+        }
+
+        Self {
+            variables,
+            workflows,
+        }
+    }
+
+    pub fn variables(&self) -> &[char] {
+        &self.variables
+    }
+
+    /// Builds the decision tree starting at the named workflow.
+    ///
+    /// # Panics
+    /// Panics if a rule jumps to a missing workflow or the workflows form a loop.
+    pub fn tree(&self, start: &str) -> Node {
+        self.build(start, 0, &mut Vec::new())
+    }
+
+    fn build(&self, name: &str, index: usize, path: &mut Vec<String>) -> Node {
+        if index == 0 {
+            assert!(!path.iter().any(|p| p == name), "Workflow {name} loops");
+        }
+        let rules = self
+            .workflows
+            .get(name)
+            .unwrap_or_else(|| panic!("Missing workflow {name}"));
+        let (test, target) = &rules[index];
+
+        path.push(name.to_string());
+        let target = match target {
+            Target::Accept => Node::Accept,
+            Target::Reject => Node::Reject,
+            Target::Workflow(next) => self.build(next, 0, path),
+        };
+        let node = match test {
+            None => target,
+            Some((variable, below, when_below)) => {
+                let rest = self.build(name, index + 1, path);
+                let (then, otherwise) = if *when_below {
+                    (target, rest)
+                } else {
+                    (rest, target)
+                };
+                Node::Test {
+                    variable: *variable,
+                    below: *below,
+                    then: Box::new(then),
+                    otherwise: Box::new(otherwise),
+                }
+            }
+        };
+        path.pop();
+        node
+    }
+}
+
+fn target_of(name: &str) -> Target {
+    match name {
+        "A" => Target::Accept,
+        "R" => Target::Reject,
+        _ => Target::Workflow(name.to_string()),
+    }
+}